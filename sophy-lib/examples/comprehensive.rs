@@ -2,6 +2,7 @@
 //!
 //! This example demonstrates various uses of the Sophy mathematical library.
 
+use sophy::base::fmt::Table;
 use sophy::base::numbers::{EPSILON, EULER, PHI, PI};
 use sophy::methods::raphson::raphson;
 
@@ -34,10 +35,12 @@ fn main() {
 
     // Example 4: Mathematical constants
     println!("📊 Mathematical Constants:");
-    println!("   π = {:.12}", PI);
-    println!("   e = {:.12}", EULER);
-    println!("   φ (Golden Ratio) = {:.12}", PHI);
-    println!("   Machine Epsilon = {:.2e}\n", EPSILON);
+    let mut constants = Table::new("Constant", "Value").precision(12);
+    constants.push("π", PI);
+    constants.push("e", EULER);
+    constants.push("φ (Golden Ratio)", PHI);
+    println!("{constants}");
+    println!("Machine Epsilon = {:.2e}\n", EPSILON);
 
     // Example 5: Golden ratio property verification
     println!("🔍 Golden Ratio Property: φ² = φ + 1");
@@ -0,0 +1,638 @@
+//! # Complex Numbers
+//!
+//! A minimal double-precision complex number type used by the complex-valued
+//! numerical methods and special functions.
+
+use crate::base::numbers::PI;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A complex number z = re + im·i with `f64` components
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+///
+/// let z = Complex64::new(3.0, 4.0);
+/// assert_eq!(z.norm(), 5.0);
+///
+/// // i² = -1
+/// assert_eq!(Complex64::I * Complex64::I, Complex64::new(-1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex64 {
+    /// Real part
+    pub re: f64,
+    /// Imaginary part
+    pub im: f64,
+}
+
+impl Complex64 {
+    /// The additive identity 0 + 0i
+    pub const ZERO: Complex64 = Complex64::new(0.0, 0.0);
+
+    /// The multiplicative identity 1 + 0i
+    pub const ONE: Complex64 = Complex64::new(1.0, 0.0);
+
+    /// The imaginary unit i
+    pub const I: Complex64 = Complex64::new(0.0, 1.0);
+
+    /// Creates a complex number from its real and imaginary parts
+    pub const fn new(re: f64, im: f64) -> Self {
+        Complex64 { re, im }
+    }
+
+    /// Creates a complex number r·(cos θ + i·sin θ) from its modulus and argument
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let z = Complex64::from_polar(1.0, FRAC_PI_2);
+    /// assert!((z - Complex64::I).norm() < 1e-15);
+    /// ```
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Complex64::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// Complex conjugate re - im·i
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// assert_eq!(Complex64::new(1.0, 2.0).conj(), Complex64::new(1.0, -2.0));
+    /// ```
+    pub fn conj(self) -> Self {
+        Complex64::new(self.re, -self.im)
+    }
+
+    /// Squared modulus re² + im²
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Modulus |z|, computed without intermediate overflow
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// assert_eq!(Complex64::new(-6.0, 8.0).norm(), 10.0);
+    /// ```
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Principal argument of z in (-π, π]
+    ///
+    /// The negative real axis, including -x - 0i, has argument π.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use std::f64::consts::PI;
+    ///
+    /// assert_eq!(Complex64::new(-1.0, 0.0).arg(), PI);
+    /// assert_eq!(Complex64::new(-1.0, -0.0).arg(), PI);
+    /// ```
+    pub fn arg(self) -> f64 {
+        // atan2 gives -π for a negative zero imaginary part
+        if self.im == 0.0 && self.re < 0.0 {
+            PI
+        } else {
+            self.im.atan2(self.re)
+        }
+    }
+
+    /// Polar form (|z|, arg z), the inverse of [`Complex64::from_polar`]
+    pub fn to_polar(self) -> (f64, f64) {
+        (self.norm(), self.arg())
+    }
+
+    /// Principal value of zⁿ for a real exponent, by De Moivre's formula
+    /// |z|ⁿ·(cos nθ + i·sin nθ) with θ = arg z
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// // Principal square root of -4
+    /// let z = Complex64::new(-4.0, 0.0).powf(0.5);
+    /// assert!((z - Complex64::new(0.0, 2.0)).norm() < 1e-15);
+    /// ```
+    pub fn powf(self, n: f64) -> Self {
+        let (r, theta) = self.to_polar();
+        Complex64::from_polar(r.powf(n), n * theta)
+    }
+
+    /// Integer power zⁿ by De Moivre's formula
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// let z = Complex64::I.powi(2);
+    /// assert!((z - Complex64::new(-1.0, 0.0)).norm() < 1e-15);
+    /// ```
+    pub fn powi(self, n: i32) -> Self {
+        let (r, theta) = self.to_polar();
+        Complex64::from_polar(r.powi(n), n as f64 * theta)
+    }
+
+    /// Complex exponential e^z = e^re · (cos(im) + i·sin(im))
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use std::f64::consts::PI;
+    ///
+    /// // Euler's identity: e^(iπ) = -1
+    /// let z = Complex64::new(0.0, PI).exp();
+    /// assert!((z - Complex64::new(-1.0, 0.0)).norm() < 1e-15);
+    /// ```
+    pub fn exp(self) -> Self {
+        let r = self.re.exp();
+        Complex64::new(r * self.im.cos(), r * self.im.sin())
+    }
+
+    /// Principal natural logarithm ln|z| + i·arg z, with imaginary part in (-π, π]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use std::f64::consts::PI;
+    ///
+    /// let z = Complex64::new(-1.0, 0.0).ln();
+    /// assert_eq!(z, Complex64::new(0.0, PI));
+    /// ```
+    pub fn ln(self) -> Self {
+        Complex64::new(self.norm().ln(), self.arg())
+    }
+
+    /// Complex sine sin(re)·cosh(im) + i·cos(re)·sinh(im)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// // sin(iy) = i·sinh(y)
+    /// let z = Complex64::new(0.0, 1.0).sin();
+    /// assert!((z - Complex64::new(0.0, 1.0_f64.sinh())).norm() < 1e-15);
+    /// ```
+    pub fn sin(self) -> Self {
+        Complex64::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    /// Complex cosine cos(re)·cosh(im) - i·sin(re)·sinh(im)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// // cos(iy) = cosh(y)
+    /// let z = Complex64::new(0.0, 1.0).cos();
+    /// assert!((z - Complex64::new(1.0_f64.cosh(), 0.0)).norm() < 1e-15);
+    /// ```
+    pub fn cos(self) -> Self {
+        Complex64::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+}
+
+impl Add for Complex64 {
+    type Output = Complex64;
+
+    fn add(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex64 {
+    type Output = Complex64;
+
+    fn sub(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex64 {
+    type Output = Complex64;
+
+    fn mul(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex64 {
+    type Output = Complex64;
+
+    /// Division using Smith's algorithm to avoid overflow in the denominator
+    fn div(self, rhs: Complex64) -> Complex64 {
+        if rhs.re.abs() >= rhs.im.abs() {
+            let ratio = rhs.im / rhs.re;
+            let denom = rhs.re + rhs.im * ratio;
+            Complex64::new(
+                (self.re + self.im * ratio) / denom,
+                (self.im - self.re * ratio) / denom,
+            )
+        } else {
+            let ratio = rhs.re / rhs.im;
+            let denom = rhs.re * ratio + rhs.im;
+            Complex64::new(
+                (self.re * ratio + self.im) / denom,
+                (self.im * ratio - self.re) / denom,
+            )
+        }
+    }
+}
+
+impl Neg for Complex64 {
+    type Output = Complex64;
+
+    fn neg(self) -> Complex64 {
+        Complex64::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for Complex64 {
+    fn add_assign(&mut self, rhs: Complex64) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Complex64 {
+    fn sub_assign(&mut self, rhs: Complex64) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Complex64 {
+    fn mul_assign(&mut self, rhs: Complex64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Complex64 {
+    fn div_assign(&mut self, rhs: Complex64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<f64> for Complex64 {
+    type Output = Complex64;
+
+    fn mul(self, rhs: f64) -> Complex64 {
+        Complex64::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl Mul<Complex64> for f64 {
+    type Output = Complex64;
+
+    fn mul(self, rhs: Complex64) -> Complex64 {
+        rhs * self
+    }
+}
+
+impl Div<f64> for Complex64 {
+    type Output = Complex64;
+
+    fn div(self, rhs: f64) -> Complex64 {
+        Complex64::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+impl Div<Complex64> for f64 {
+    type Output = Complex64;
+
+    /// The scalar times the reciprocal 1/z = z̄/|z|², with Smith's algorithm
+    fn div(self, rhs: Complex64) -> Complex64 {
+        Complex64::new(self, 0.0) / rhs
+    }
+}
+
+impl MulAssign<f64> for Complex64 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Complex64 {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl fmt::Display for Complex64 {
+    /// Formats as `a+bi` or `a-bi`, applying any requested precision to both parts
+    ///
+    /// Without a precision the shortest representation that parses back to the
+    /// same value is used, so the output round-trips through [`FromStr`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        match f.precision() {
+            Some(p) => write!(f, "{:.p$}{sign}{:.p$}i", self.re, self.im.abs()),
+            None => write!(f, "{}{sign}{}i", self.re, self.im.abs()),
+        }
+    }
+}
+
+/// The error returned when a string is not a valid complex number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError {
+    _private: (),
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid complex number literal")
+    }
+}
+
+impl std::error::Error for ParseComplexError {}
+
+impl FromStr for Complex64 {
+    type Err = ParseComplexError;
+
+    /// Parses `a`, `bi`, `i`, `a+bi` and `a-bi`, where `a` and `b` are any `f64`
+    /// literals, including exponents such as `1e-3`
+    ///
+    /// Surrounding whitespace is ignored; whitespace inside the number is not
+    /// allowed. A bare `i` or a sign before `i` stands for a unit coefficient.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    ///
+    /// assert_eq!("3+4i".parse(), Ok(Complex64::new(3.0, 4.0)));
+    /// assert_eq!("-i".parse(), Ok(Complex64::new(0.0, -1.0)));
+    /// assert!("3+4j".parse::<Complex64>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = ParseComplexError { _private: () };
+        let s = s.trim();
+
+        let Some(body) = s.strip_suffix('i') else {
+            return s
+                .parse()
+                .map(|re| Complex64::new(re, 0.0))
+                .map_err(|_| error);
+        };
+
+        // The sign that starts the imaginary part, skipping exponent signs
+        let bytes = body.as_bytes();
+        let split = (1..bytes.len())
+            .rev()
+            .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'));
+
+        let (re, im) = match split {
+            Some(k) => (body[..k].parse().map_err(|_| error.clone())?, &body[k..]),
+            None => (0.0, body),
+        };
+        let im = match im {
+            "" | "+" => 1.0,
+            "-" => -1.0,
+            _ => im.parse().map_err(|_| error)?,
+        };
+
+        Ok(Complex64::new(re, im))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = Complex64::new(1.0, 2.0);
+        let b = Complex64::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex64::new(4.0, 1.0));
+        assert_eq!(a - b, Complex64::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex64::new(5.0, 5.0));
+        assert_eq!(-a, Complex64::new(-1.0, -2.0));
+    }
+
+    #[test]
+    fn test_complex_division() {
+        let a = Complex64::new(1.0, 2.0);
+        let b = Complex64::new(3.0, -1.0);
+
+        let q = a / b;
+        let back = q * b;
+        assert!((back - a).norm() < 1e-15);
+
+        // Large components do not overflow the denominator
+        let big = Complex64::new(1e300, 1e300);
+        let q = big / big;
+        assert!((q - Complex64::ONE).norm() < 1e-15);
+    }
+
+    #[test]
+    fn test_complex_assign_ops() {
+        let a = Complex64::new(1.0, 2.0);
+        let b = Complex64::new(3.0, -1.0);
+
+        let mut z = a;
+        z += b;
+        assert_eq!(z, a + b);
+        z -= b;
+        assert_eq!(z, a);
+        z *= b;
+        assert_eq!(z, a * b);
+        z /= b;
+        assert!((z - a).norm() < 1e-15);
+
+        // Accumulating a power series in place
+        let mut sum = Complex64::ZERO;
+        let mut term = Complex64::ONE;
+        for _ in 0..20 {
+            sum += term;
+            term *= Complex64::new(0.0, 0.5);
+        }
+        let expected = Complex64::ONE / (Complex64::ONE - Complex64::new(0.0, 0.5));
+        assert!((sum - expected).norm() < 1e-6);
+    }
+
+    #[test]
+    fn test_complex_scalar_ops() {
+        let z = Complex64::new(1.0, 1.0);
+        assert_eq!(2.0 * z, Complex64::new(2.0, 2.0));
+        assert_eq!(z * 2.0, 2.0 * z);
+        assert_eq!(Complex64::new(3.0, -6.0) / 3.0, Complex64::new(1.0, -2.0));
+
+        // 1/i = -i
+        assert_eq!(1.0 / Complex64::I, Complex64::new(0.0, -1.0));
+        assert!((2.0 / z - Complex64::new(1.0, -1.0)).norm() < 1e-15);
+
+        let mut w = Complex64::new(2.0, 4.0);
+        w *= 0.5;
+        assert_eq!(w, Complex64::new(1.0, 2.0));
+        w /= 2.0;
+        assert_eq!(w, Complex64::new(0.5, 1.0));
+    }
+
+    #[test]
+    fn test_complex_norm_and_conj() {
+        let z = Complex64::new(3.0, -4.0);
+        assert_eq!(z.norm(), 5.0);
+        assert_eq!(z.norm_sqr(), 25.0);
+        assert_eq!(z * z.conj(), Complex64::new(25.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_exp() {
+        assert_eq!(Complex64::ZERO.exp(), Complex64::ONE);
+
+        // e^(a+b) = e^a · e^b
+        let a = Complex64::new(0.3, -1.2);
+        let b = Complex64::new(-0.7, 2.5);
+        assert!(((a + b).exp() - a.exp() * b.exp()).norm() < 1e-15);
+
+        // Real arguments agree with f64::exp
+        assert_eq!(Complex64::new(2.0, 0.0).exp().re, 2.0_f64.exp());
+    }
+
+    #[test]
+    fn test_complex_ln_and_sin() {
+        // Computed with mpmath at 30 digits
+        let ln = Complex64::new(-3.0, 4.0).ln();
+        assert!(
+            (ln - Complex64::new(1.609_437_912_434_100_4, 2.214_297_435_588_181)).norm() < 1e-15
+        );
+        let sin = Complex64::new(1.0, 2.0).sin();
+        assert!(
+            (sin - Complex64::new(3.165_778_513_216_168, 1.959_601_041_421_606)).norm() < 1e-14
+        );
+
+        // ln is the inverse of exp on the principal strip
+        let z = Complex64::new(0.4, -2.9);
+        assert!((z.exp().ln() - z).norm() < 1e-15);
+    }
+
+    #[test]
+    fn test_complex_polar_round_trip() {
+        let z = Complex64::from_polar(1.0, PI / 2.0);
+        assert!((z - Complex64::I).norm() < 1e-15);
+
+        let w = Complex64::new(-3.0, 4.0);
+        let (r, theta) = w.to_polar();
+        assert_eq!(r, 5.0);
+        assert!((Complex64::from_polar(r, theta) - w).norm() < 1e-14);
+    }
+
+    #[test]
+    fn test_complex_arg_principal_range() {
+        assert_eq!(Complex64::new(-1.0, 0.0).arg(), PI);
+        assert_eq!(Complex64::new(-1.0, -0.0).arg(), PI);
+        assert_eq!(Complex64::new(0.0, -2.0).arg(), -PI / 2.0);
+        assert_eq!(Complex64::ONE.arg(), 0.0);
+        assert!((Complex64::new(-1.0, -1e-300).arg() + PI).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_complex_powers() {
+        assert!((Complex64::I.powi(2) - Complex64::new(-1.0, 0.0)).norm() < 1e-15);
+        assert_eq!(Complex64::new(2.0, 0.0).powi(0), Complex64::ONE);
+
+        // Integer powers agree with repeated multiplication
+        let z = Complex64::new(0.6, -1.3);
+        assert!((z.powi(3) - z * z * z).norm() < 1e-14);
+        assert!((z.powi(-2) * z * z - Complex64::ONE).norm() < 1e-14);
+        assert!((z.powf(3.0) - z.powi(3)).norm() < 1e-14);
+    }
+
+    #[test]
+    fn test_complex_roots_of_unity() {
+        let n = 7;
+        let root = Complex64::new(-1.0, 0.0).powf(2.0 / n as f64);
+
+        for k in 1..=n {
+            let w = root.powi(k);
+            assert!((w.norm() - 1.0).abs() < 1e-15);
+            // Every power is itself an n-th root of unity
+            assert!((w.powi(n) - Complex64::ONE).norm() < 1e-13);
+        }
+        assert!((root.powi(n) - Complex64::ONE).norm() < 1e-14);
+    }
+
+    #[test]
+    fn test_complex_display() {
+        assert_eq!(Complex64::new(3.0, 4.0).to_string(), "3+4i");
+        assert_eq!(Complex64::new(-2.5, -3.0).to_string(), "-2.5-3i");
+        assert_eq!(Complex64::new(1.0, 0.0).to_string(), "1+0i");
+        assert_eq!(format!("{:.2}", Complex64::new(PI, -1.0)), "3.14-1.00i");
+    }
+
+    #[test]
+    fn test_complex_parse_forms() {
+        let cases = [
+            ("3+4i", Complex64::new(3.0, 4.0)),
+            ("-2-3i", Complex64::new(-2.0, -3.0)),
+            ("5", Complex64::new(5.0, 0.0)),
+            ("2i", Complex64::new(0.0, 2.0)),
+            ("i", Complex64::I),
+            ("-i", Complex64::new(0.0, -1.0)),
+            ("1.5-i", Complex64::new(1.5, -1.0)),
+            ("  +0.25i ", Complex64::new(0.0, 0.25)),
+            ("1e-3+2.5E+2i", Complex64::new(1e-3, 250.0)),
+            ("-1e5i", Complex64::new(0.0, -1e5)),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(text.parse::<Complex64>(), Ok(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_complex_display_round_trip() {
+        for z in [
+            Complex64::new(0.1, -0.2),
+            Complex64::new(-1e-300, 6.02e23),
+            Complex64::new(PI, 1.0 / 3.0),
+            Complex64::new(0.0, -0.0),
+        ] {
+            let parsed: Complex64 = z.to_string().parse().unwrap();
+            assert_eq!(parsed, z);
+            assert_eq!(parsed.im.is_sign_negative(), z.im.is_sign_negative());
+        }
+    }
+
+    #[test]
+    fn test_complex_parse_rejects_garbage() {
+        for text in [
+            "3+4j", "", "i3", "3+-4i", "1+2i+3i", "3 + 4i", "abc", "+", "ii",
+        ] {
+            let result = text.parse::<Complex64>();
+            assert!(result.is_err(), "{text:?} parsed as {result:?}");
+        }
+        assert_eq!(
+            "x".parse::<Complex64>().unwrap_err().to_string(),
+            "invalid complex number literal"
+        );
+    }
+}
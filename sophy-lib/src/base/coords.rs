@@ -0,0 +1,194 @@
+//! # Coordinate Transforms
+//!
+//! Conversions between Cartesian coordinates and polar (2D) or spherical (3D)
+//! coordinates.
+//!
+//! Angles are in radians. Azimuthal angles are principal values in (-π, π],
+//! measured counterclockwise from the positive x axis. Spherical coordinates
+//! follow the physics (ISO 80000-2) convention `(r, θ, φ)`: θ is the polar angle
+//! from the positive z axis in [0, π] and φ is the azimuth.
+//!
+//! Where an angle is undefined — at the origin, or the azimuth of a point on the
+//! z axis — it is reported as 0.
+
+use crate::base::numbers::PI;
+
+/// Principal value of the angle of (x, y), in (-π, π], with 0 at the origin
+///
+/// `atan2` returns -π for points on the negative x axis with y = -0.0, and
+/// ±0 or ±π at the origin depending on the signs of the zeros; both are
+/// normalized here.
+fn principal_angle(y: f64, x: f64) -> f64 {
+    if x == 0.0 && y == 0.0 {
+        return 0.0;
+    }
+    let angle = y.atan2(x);
+    if angle == -PI { PI } else { angle }
+}
+
+/// Converts Cartesian (x, y) to polar (r, θ)
+///
+/// `r = √(x² + y²)` is computed without intermediate overflow, and θ is the
+/// principal value in (-π, π]. The angle of the origin is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::coords::cartesian_to_polar;
+/// use std::f64::consts::{FRAC_PI_4, PI, SQRT_2};
+///
+/// let (r, theta) = cartesian_to_polar(1.0, 1.0);
+/// assert!((r - SQRT_2).abs() < 1e-15);
+/// assert!((theta - FRAC_PI_4).abs() < 1e-15);
+///
+/// assert_eq!(cartesian_to_polar(-2.0, 0.0), (2.0, PI));
+/// assert_eq!(cartesian_to_polar(0.0, 0.0), (0.0, 0.0));
+/// ```
+pub fn cartesian_to_polar(x: f64, y: f64) -> (f64, f64) {
+    (x.hypot(y), principal_angle(y, x))
+}
+
+/// Converts polar (r, θ) to Cartesian (x, y)
+///
+/// Any θ is accepted, and a negative `r` gives the point reflected through the
+/// origin.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::coords::polar_to_cartesian;
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// let (x, y) = polar_to_cartesian(2.0, FRAC_PI_2);
+/// assert!(x.abs() < 1e-15);
+/// assert_eq!(y, 2.0);
+/// ```
+pub fn polar_to_cartesian(r: f64, theta: f64) -> (f64, f64) {
+    let (sin, cos) = theta.sin_cos();
+    (r * cos, r * sin)
+}
+
+/// Converts Cartesian (x, y, z) to spherical (r, θ, φ)
+///
+/// θ ∈ [0, π] is the polar angle from the positive z axis and φ ∈ (-π, π] the
+/// azimuth. θ is computed as `atan2(√(x² + y²), z)` rather than `acos(z/r)`,
+/// which keeps full accuracy near the poles. On the z axis φ is 0, and at the
+/// origin both angles are 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::coords::cartesian_to_spherical;
+/// use std::f64::consts::{FRAC_PI_2, PI};
+///
+/// assert_eq!(cartesian_to_spherical(0.0, 3.0, 0.0), (3.0, FRAC_PI_2, FRAC_PI_2));
+/// assert_eq!(cartesian_to_spherical(0.0, 0.0, -1.0), (1.0, PI, 0.0));
+/// ```
+pub fn cartesian_to_spherical(x: f64, y: f64, z: f64) -> (f64, f64, f64) {
+    let rho = x.hypot(y);
+    let r = rho.hypot(z);
+    let theta = if r == 0.0 { 0.0 } else { rho.atan2(z) };
+    (r, theta, principal_angle(y, x))
+}
+
+/// Converts spherical (r, θ, φ) to Cartesian (x, y, z)
+///
+/// θ is the polar angle from the positive z axis and φ the azimuth; any angles
+/// are accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::coords::spherical_to_cartesian;
+///
+/// let (x, y, z) = spherical_to_cartesian(2.0, 0.0, 1.3);
+/// assert_eq!((x, y, z), (0.0, 0.0, 2.0));
+/// ```
+pub fn spherical_to_cartesian(r: f64, theta: f64, phi: f64) -> (f64, f64, f64) {
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    (
+        r * sin_theta * cos_phi,
+        r * sin_theta * sin_phi,
+        r * cos_theta,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POINTS: [(f64, f64, f64); 8] = [
+        (1.0, 1.0, 1.0),
+        (-3.5, 0.25, 2.0),
+        (0.1, -7.0, -0.4),
+        (-1e-3, -2e-3, 5.0),
+        (1e8, 3e8, -2e8),
+        (-1.0, 0.0, 0.0),
+        (0.0, -2.5, 1.0),
+        (4.0, 1e-12, -1e-12),
+    ];
+
+    #[test]
+    fn test_polar_known_values() {
+        let (r, theta) = cartesian_to_polar(1.0, 1.0);
+        assert!((r - 2.0_f64.sqrt()).abs() < 1e-15);
+        assert!((theta - PI / 4.0).abs() < 1e-15);
+
+        assert_eq!(cartesian_to_polar(0.0, -2.0), (2.0, -PI / 2.0));
+        assert_eq!(cartesian_to_polar(3.0, 4.0).0, 5.0);
+        // No overflow in the radius
+        assert_eq!(cartesian_to_polar(3e300, 4e300).0, 5e300);
+    }
+
+    #[test]
+    fn test_polar_angle_is_principal_value() {
+        // Both signed zeros on the negative x axis give +π, never -π
+        assert_eq!(cartesian_to_polar(-1.0, 0.0).1, PI);
+        assert_eq!(cartesian_to_polar(-1.0, -0.0).1, PI);
+        assert!(cartesian_to_polar(-1.0, -1e-10).1 < 0.0);
+
+        // The origin's angle is 0 whatever the signs of its zeros
+        for (x, y) in [(0.0, 0.0), (-0.0, 0.0), (0.0, -0.0), (-0.0, -0.0)] {
+            assert_eq!(cartesian_to_polar(x, y), (0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        for &(x, y, _) in &POINTS {
+            let (r, theta) = cartesian_to_polar(x, y);
+            assert!(-PI < theta && theta <= PI);
+            let (x2, y2) = polar_to_cartesian(r, theta);
+            let scale = r.max(f64::MIN_POSITIVE);
+            assert!((x2 - x).abs() <= 4.0 * f64::EPSILON * scale, "({x}, {y})");
+            assert!((y2 - y).abs() <= 4.0 * f64::EPSILON * scale, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn test_spherical_known_values() {
+        let (r, theta, phi) = cartesian_to_spherical(1.0, 1.0, 1.0);
+        assert!((r - 3.0_f64.sqrt()).abs() < 1e-15);
+        // θ = arccos(1/√3)
+        assert!((theta - 0.955_316_618_124_509_2).abs() < 1e-15);
+        assert!((phi - PI / 4.0).abs() < 1e-15);
+
+        assert_eq!(cartesian_to_spherical(0.0, 0.0, 2.0), (2.0, 0.0, 0.0));
+        assert_eq!(cartesian_to_spherical(-1.0, -0.0, 0.0), (1.0, PI / 2.0, PI));
+        assert_eq!(cartesian_to_spherical(0.0, 0.0, 0.0), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        for &(x, y, z) in &POINTS {
+            let (r, theta, phi) = cartesian_to_spherical(x, y, z);
+            assert!((0.0..=PI).contains(&theta));
+            assert!(-PI < phi && phi <= PI);
+            let (x2, y2, z2) = spherical_to_cartesian(r, theta, phi);
+            for (a, b) in [(x, x2), (y, y2), (z, z2)] {
+                assert!((a - b).abs() <= 4.0 * f64::EPSILON * r, "({x}, {y}, {z})");
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! # Floating-Point Comparison
+//!
+//! Tolerance-based equality tests for `f64`, so that accuracy checks state their
+//! intent (relative, absolute or in units in the last place) instead of comparing
+//! against an ad hoc threshold. [`assert_all_close`] applies the same test to
+//! whole vectors, for use in test suites.
+
+/// Whether `a` and `b` are close in a relative or an absolute sense
+///
+/// Uses the semantics of Python's `math.isclose`:
+///
+/// |a - b| ≤ max(rel_tol · max(|a|, |b|), abs_tol)
+///
+/// The relative tolerance scales with the magnitude of the values, which is what
+/// matters for large numbers. Near zero every relative tolerance becomes
+/// meaningless, so an absolute tolerance is needed there.
+///
+/// Infinities are only close to themselves and NaN is never close to anything.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::float::approx_eq;
+///
+/// assert!(approx_eq(1e20, 1e20 + 1e6, 1e-12, 0.0));
+/// assert!(approx_eq(1e-17, 0.0, 1e-12, 1e-15));
+/// assert!(!approx_eq(1e-17, 0.0, 1e-12, 0.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if either tolerance is negative.
+pub fn approx_eq(a: f64, b: f64, rel_tol: f64, abs_tol: f64) -> bool {
+    if rel_tol < 0.0 || abs_tol < 0.0 {
+        panic!("Tolerances must be non-negative");
+    }
+
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+
+    let diff = (a - b).abs();
+    diff <= rel_tol * a.abs().max(b.abs()) || diff <= abs_tol
+}
+
+/// Whether `a` and `b` are at most `max_ulps` representable values apart
+///
+/// Counting units in the last place (ULPs) measures the distance between two
+/// floats independently of their magnitude: adjacent floats are 1 ULP apart,
+/// whether they are near 1e-300 or near 1e300. `+0.0` and `-0.0` are equal, values
+/// of opposite sign are otherwise never equal, and NaN is never equal to anything.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::float::ulps_eq;
+///
+/// let next = f64::from_bits(1.0_f64.to_bits() + 1);
+/// assert!(ulps_eq(1.0, next, 1));
+/// assert!(!ulps_eq(1.0, next, 0));
+///
+/// // 0.1 + 0.2 misses 0.3 by one ULP
+/// assert!(ulps_eq(0.1 + 0.2, 0.3, 1));
+/// ```
+pub fn ulps_eq(a: f64, b: f64, max_ulps: u32) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return false;
+    }
+    if a == b {
+        return true;
+    }
+    if a.is_sign_negative() != b.is_sign_negative() {
+        return false;
+    }
+
+    // For floats of the same sign the bit patterns are ordered like the values
+    a.to_bits().abs_diff(b.to_bits()) <= u64::from(max_ulps)
+}
+
+/// Asserts that two vectors are element-wise close in the sense of [`approx_eq`]
+///
+/// Intended for tests: on failure the panic message names the first offending
+/// index together with both values and their absolute and relative error, which
+/// a plain `assert!` over an iterator cannot report. The panic location is the
+/// caller's line.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::float::assert_all_close;
+///
+/// let computed = [0.1 + 0.2, 1e-17, 2.0_f64.sqrt().powi(2)];
+/// assert_all_close(&computed, &[0.3, 0.0, 2.0], 1e-15, 1e-15);
+/// ```
+///
+/// ```rust,should_panic
+/// use sophy::base::float::assert_all_close;
+///
+/// // Panics with "Element 1 is not close: actual 2.5, expected 2, ..."
+/// assert_all_close(&[1.0, 2.5], &[1.0, 2.0], 1e-12, 0.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the lengths differ, if any element is not close, or if either
+/// tolerance is negative.
+#[track_caller]
+pub fn assert_all_close(actual: &[f64], expected: &[f64], rel_tol: f64, abs_tol: f64) {
+    if actual.len() != expected.len() {
+        panic!(
+            "Length mismatch: actual has {} elements, expected has {}",
+            actual.len(),
+            expected.len()
+        );
+    }
+
+    for (i, (&a, &e)) in actual.iter().zip(expected).enumerate() {
+        if !approx_eq(a, e, rel_tol, abs_tol) {
+            let error = (a - e).abs();
+            let relative = error / e.abs();
+            panic!(
+                "Element {i} is not close: actual {a}, expected {e}, \
+                 absolute error {error:e}, relative error {relative:e} \
+                 (rel_tol {rel_tol:e}, abs_tol {abs_tol:e})"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_eq_near_zero() {
+        // Relative tolerance alone cannot match anything to zero
+        assert!(!approx_eq(1e-20, 0.0, 1e-9, 0.0));
+        assert!(approx_eq(1e-20, 0.0, 1e-9, 1e-18));
+        assert!(approx_eq(-3e-13, 2e-13, 0.0, 1e-12));
+        assert!(!approx_eq(-3e-12, 2e-12, 0.0, 1e-12));
+    }
+
+    #[test]
+    fn test_approx_eq_large_values() {
+        assert!(approx_eq(1e300, 1e300 * (1.0 + 1e-13), 1e-12, 0.0));
+        assert!(!approx_eq(1e300, 1.01e300, 1e-12, 0.0));
+
+        // An absolute tolerance is useless at this scale
+        assert!(!approx_eq(1e15, 1e15 + 2.0, 0.0, 1.0));
+        assert!(approx_eq(1e15, 1e15 + 2.0, 1e-14, 0.0));
+    }
+
+    #[test]
+    fn test_approx_eq_special_values() {
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY, 0.0, 0.0));
+        assert!(!approx_eq(f64::INFINITY, f64::MAX, 1.0, 1.0));
+        assert!(!approx_eq(f64::NAN, f64::NAN, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_ulps_eq_adjacent_floats() {
+        for x in [1e-300_f64, 0.1, 1.0, 12345.678, 1e300] {
+            let next = f64::from_bits(x.to_bits() + 1);
+            let prev = f64::from_bits(x.to_bits() - 1);
+            assert!(ulps_eq(x, next, 1));
+            assert!(ulps_eq(prev, x, 1));
+            assert!(!ulps_eq(prev, next, 1));
+            assert!(ulps_eq(prev, next, 2));
+        }
+    }
+
+    #[test]
+    fn test_ulps_eq_signs_and_nan() {
+        assert!(ulps_eq(0.0, -0.0, 0));
+        assert!(!ulps_eq(f64::MIN_POSITIVE, -f64::MIN_POSITIVE, 1000));
+        assert!(!ulps_eq(f64::NAN, f64::NAN, u32::MAX));
+
+        let neg = -2.5_f64;
+        assert!(ulps_eq(neg, f64::from_bits(neg.to_bits() + 3), 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Tolerances must be non-negative")]
+    fn test_approx_eq_negative_tolerance() {
+        approx_eq(1.0, 1.0, -1e-9, 0.0);
+    }
+
+    #[test]
+    fn test_assert_all_close_passes() {
+        assert_all_close(&[], &[], 0.0, 0.0);
+        assert_all_close(&[1.0, -2.0, 1e300], &[1.0, -2.0, 1e300], 0.0, 0.0);
+        assert_all_close(&[1.0 + 1e-13, 1e-20], &[1.0, 0.0], 1e-12, 1e-18);
+        assert_all_close(&[f64::INFINITY], &[f64::INFINITY], 0.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Element 2 is not close: actual 3.1, expected 3")]
+    fn test_assert_all_close_names_index() {
+        assert_all_close(&[1.0, 2.0, 3.1, 4.0], &[1.0, 2.0, 3.0, 4.0], 1e-9, 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Element 0 is not close")]
+    fn test_assert_all_close_nan() {
+        assert_all_close(&[f64::NAN], &[f64::NAN], 1.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Length mismatch: actual has 2 elements, expected has 3")]
+    fn test_assert_all_close_length_mismatch() {
+        assert_all_close(&[1.0, 2.0], &[1.0, 2.0, 3.0], 1e-9, 0.0);
+    }
+}
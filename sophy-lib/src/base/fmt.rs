@@ -0,0 +1,172 @@
+//! # Formatted Tables
+//!
+//! A small two-column table of labelled numbers, rendered as aligned plain text
+//! through [`Display`](std::fmt::Display). Useful for reporting results in
+//! examples, command-line tools and test output without hand-written padding.
+
+use std::fmt;
+
+/// A table of `(label, value)` rows rendered with aligned columns
+///
+/// Labels are left-aligned and values right-aligned with a fixed number of
+/// decimal places, set by [`precision`](Table::precision) (6 by default). A
+/// header row and a separator line precede the rows. Column widths are measured
+/// in characters, so labels with symbols such as `π` or `√2` line up.
+///
+/// A table without rows renders as the empty string, header included, so that
+/// printing an empty report produces no output.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::fmt::Table;
+///
+/// let mut table = Table::new("x", "√x").precision(3);
+/// table.push("2", 2.0_f64.sqrt());
+/// table.push("100", 10.0);
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "x   |     √x\n\
+///      ----+-------\n\
+///      2   |  1.414\n\
+///      100 | 10.000"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    headers: (String, String),
+    rows: Vec<(String, f64)>,
+    precision: usize,
+}
+
+impl Table {
+    /// Creates an empty table with the given column headers
+    pub fn new(label_header: impl Into<String>, value_header: impl Into<String>) -> Self {
+        Table {
+            headers: (label_header.into(), value_header.into()),
+            rows: Vec::new(),
+            precision: 6,
+        }
+    }
+
+    /// Sets the number of decimal places shown for the values
+    pub fn precision(mut self, digits: usize) -> Self {
+        self.precision = digits;
+        self
+    }
+
+    /// Appends a row
+    pub fn push(&mut self, label: impl Into<String>, value: f64) {
+        self.rows.push((label.into(), value));
+    }
+
+    /// The rows in insertion order
+    pub fn rows(&self) -> &[(String, f64)] {
+        &self.rows
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether the table has no rows
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+
+        let precision = self.precision;
+        let values: Vec<String> = self
+            .rows
+            .iter()
+            .map(|(_, value)| format!("{value:.precision$}"))
+            .collect();
+
+        let label_width = self
+            .rows
+            .iter()
+            .map(|(label, _)| label.chars().count())
+            .chain([self.headers.0.chars().count()])
+            .max()
+            .unwrap_or(0);
+        let value_width = values
+            .iter()
+            .map(|value| value.chars().count())
+            .chain([self.headers.1.chars().count()])
+            .max()
+            .unwrap_or(0);
+
+        write!(
+            f,
+            "{:<label_width$} | {:>value_width$}",
+            self.headers.0, self.headers.1
+        )?;
+        write!(
+            f,
+            "\n{}-+-{}",
+            "-".repeat(label_width),
+            "-".repeat(value_width)
+        )?;
+        for ((label, _), value) in self.rows.iter().zip(&values) {
+            write!(f, "\n{label:<label_width$} | {value:>value_width$}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rows_are_aligned() {
+        let mut table = Table::new("constant", "value").precision(4);
+        table.push("π", std::f64::consts::PI);
+        table.push("e", std::f64::consts::E);
+        table.push("-1000/3", -1000.0 / 3.0);
+
+        let rendered = table.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "constant |     value",
+                "---------+----------",
+                "π        |    3.1416",
+                "e        |    2.7183",
+                "-1000/3  | -333.3333",
+            ]
+        );
+        // Every line has the same width and a separator in the same column
+        assert!(lines.iter().all(|line| line.chars().count() == 20));
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.chars().nth(9) == Some('|') || line.chars().nth(9) == Some('+'))
+        );
+    }
+
+    #[test]
+    fn test_default_precision() {
+        let mut table = Table::new("n", "1/n");
+        table.push("3", 1.0 / 3.0);
+        assert!(table.to_string().ends_with("3 | 0.333333"));
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.rows()[0].0, "3");
+    }
+
+    #[test]
+    fn test_empty_table_renders_nothing() {
+        let table = Table::new("label", "value");
+        assert!(table.is_empty());
+        assert_eq!(table.to_string(), "");
+    }
+}
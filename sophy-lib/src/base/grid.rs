@@ -0,0 +1,192 @@
+//! # Sampling Grids
+//!
+//! Rectangular meshes for sampling functions of two variables, as needed for
+//! double integrals, surface plots and interpolation tables.
+
+use crate::base::numbers::linspace;
+
+/// A rectangular grid given by its x- and y-axis samples
+///
+/// The grid nodes are all pairs `(x[i], y[j])`. Meshes produced by
+/// [`evaluate`](Grid2D::evaluate) are indexed the same way, `values[i][j]` being
+/// the value at `(x[i], y[j])`. Consecutive samples along each axis bound the
+/// grid cells, whose centers are given by
+/// [`cell_centers`](Grid2D::cell_centers).
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::grid::Grid2D;
+///
+/// let grid = Grid2D::uniform((0.0, 1.0), 3, (0.0, 2.0), 2);
+/// let mesh = grid.evaluate(|x, y| x * y);
+///
+/// assert_eq!(grid.shape(), (3, 2));
+/// assert_eq!(mesh, vec![vec![0.0, 0.0], vec![0.0, 1.0], vec![0.0, 2.0]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid2D {
+    x: Vec<f64>,
+    y: Vec<f64>,
+}
+
+impl Grid2D {
+    /// Creates a grid from explicit axis samples
+    ///
+    /// The samples need not be evenly spaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either axis is empty.
+    pub fn new(x: Vec<f64>, y: Vec<f64>) -> Self {
+        if x.is_empty() || y.is_empty() {
+            panic!("Grid axes must not be empty");
+        }
+        Grid2D { x, y }
+    }
+
+    /// Creates a grid of `nx` × `ny` evenly spaced nodes spanning the given
+    /// ranges, both endpoints included
+    ///
+    /// The axes are built with [`linspace`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::grid::Grid2D;
+    ///
+    /// let grid = Grid2D::uniform((0.0, 1.0), 5, (-1.0, 1.0), 3);
+    /// assert_eq!(grid.x(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert_eq!(grid.y(), &[-1.0, 0.0, 1.0]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nx` or `ny` is zero.
+    pub fn uniform(x_range: (f64, f64), nx: usize, y_range: (f64, f64), ny: usize) -> Self {
+        Grid2D::new(
+            linspace(x_range.0, x_range.1, nx),
+            linspace(y_range.0, y_range.1, ny),
+        )
+    }
+
+    /// The x-axis samples
+    pub fn x(&self) -> &[f64] {
+        &self.x
+    }
+
+    /// The y-axis samples
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// Number of nodes along each axis, as `(x.len(), y.len())`
+    pub fn shape(&self) -> (usize, usize) {
+        (self.x.len(), self.y.len())
+    }
+
+    /// Evaluates `f` at every node, returning the mesh `values[i][j] = f(x[i], y[j])`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::grid::Grid2D;
+    ///
+    /// let grid = Grid2D::new(vec![1.0, 2.0], vec![10.0, 20.0, 30.0]);
+    /// let mesh = grid.evaluate(|x, y| x + y);
+    ///
+    /// assert_eq!(mesh[1], vec![12.0, 22.0, 32.0]);
+    /// ```
+    pub fn evaluate(&self, f: impl Fn(f64, f64) -> f64) -> Vec<Vec<f64>> {
+        self.x
+            .iter()
+            .map(|&x| self.y.iter().map(|&y| f(x, y)).collect())
+            .collect()
+    }
+
+    /// Iterates over the nodes `(x[i], y[j])` in the order of the mesh, with `j`
+    /// varying fastest
+    pub fn points(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.x
+            .iter()
+            .flat_map(move |&x| self.y.iter().map(move |&y| (x, y)))
+    }
+
+    /// Iterates over the centers of the `(nx - 1)(ny - 1)` cells between
+    /// consecutive samples, in the same order as [`points`](Grid2D::points)
+    ///
+    /// A grid with a single sample along either axis has no cells.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::grid::Grid2D;
+    ///
+    /// let grid = Grid2D::new(vec![0.0, 1.0, 3.0], vec![0.0, 2.0]);
+    /// let centers: Vec<(f64, f64)> = grid.cell_centers().collect();
+    ///
+    /// assert_eq!(centers, vec![(0.5, 1.0), (2.0, 1.0)]);
+    /// ```
+    pub fn cell_centers(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.x.windows(2).flat_map(move |xs| {
+            let xc = 0.5 * (xs[0] + xs[1]);
+            self.y.windows(2).map(move |ys| (xc, 0.5 * (ys[0] + ys[1])))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_unit_square_corners() {
+        let grid = Grid2D::uniform((0.0, 1.0), 11, (0.0, 1.0), 6);
+        let mesh = grid.evaluate(|x, y| x + y);
+
+        assert_eq!(mesh[0][0], 0.0);
+        assert_eq!(mesh[10][0], 1.0);
+        assert_eq!(mesh[0][5], 1.0);
+        assert_eq!(mesh[10][5], 2.0);
+    }
+
+    #[test]
+    fn test_mesh_dimensions_match_axes() {
+        let grid = Grid2D::new(vec![0.0, 0.5, 2.0, 3.0], vec![-1.0, 1.0]);
+        let mesh = grid.evaluate(|x, y| x * y);
+
+        assert_eq!(grid.shape(), (4, 2));
+        assert_eq!(mesh.len(), 4);
+        assert!(mesh.iter().all(|row| row.len() == 2));
+        assert_eq!(grid.points().count(), 8);
+    }
+
+    #[test]
+    fn test_points_follow_mesh_order() {
+        let grid = Grid2D::uniform((0.0, 2.0), 3, (0.0, 1.0), 2);
+        let mesh = grid.evaluate(|x, y| 10.0 * x + y);
+        let flattened: Vec<f64> = mesh.into_iter().flatten().collect();
+        let from_points: Vec<f64> = grid.points().map(|(x, y)| 10.0 * x + y).collect();
+
+        assert_eq!(flattened, from_points);
+    }
+
+    #[test]
+    fn test_cell_centers() {
+        let grid = Grid2D::uniform((0.0, 1.0), 5, (0.0, 1.0), 3);
+        let centers: Vec<(f64, f64)> = grid.cell_centers().collect();
+
+        assert_eq!(centers.len(), 4 * 2);
+        assert_eq!(centers[0], (0.125, 0.25));
+        assert_eq!(centers[7], (0.875, 0.75));
+
+        let line = Grid2D::new(vec![0.0, 1.0], vec![0.5]);
+        assert_eq!(line.cell_centers().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Grid axes must not be empty")]
+    fn test_empty_axis() {
+        Grid2D::uniform((0.0, 1.0), 0, (0.0, 1.0), 4);
+    }
+}
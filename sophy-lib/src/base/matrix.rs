@@ -0,0 +1,1316 @@
+//! # Dense Matrices and Linear Systems
+//!
+//! A row-major dense matrix type together with direct solvers for linear systems.
+//!
+//! ## Available Solvers
+//!
+//! - [`Matrix::solve`]: General systems via LU decomposition with partial pivoting, O(n³)
+//! - [`solve_tridiagonal`]: Tridiagonal systems via the Thomas algorithm, O(n)
+//!
+//! ## Iterative Solvers
+//!
+//! - [`jacobi_solve`]: Jacobi iteration, O(n²) per sweep
+//! - [`gauss_seidel_solve`]: Gauss-Seidel iteration, usually about twice as fast
+//!
+//! Both converge for strictly diagonally dominant matrices and are useful for
+//! large systems where most of the work of a direct solve would be wasted.
+//!
+//! ## Eigenvalues
+//!
+//! - [`Matrix::eigenvalues`]: All (possibly complex) eigenvalues of a general square matrix
+//! - [`Matrix::jacobi_eigen`]: All eigenvalues and eigenvectors of a symmetric matrix
+//! - [`power_iteration`]: The dominant eigenvalue and its eigenvector
+
+use crate::base::complex::Complex64;
+use std::ops::{Index, IndexMut};
+
+/// A dense `rows × cols` matrix of `f64` stored in row-major order
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::matrix::Matrix;
+///
+/// let a = Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0]]);
+///
+/// assert_eq!(a[(1, 0)], 3.0);
+/// assert_eq!(a.mul_vec(&[1.0, 1.0]), vec![3.0, 7.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Creates a `rows × cols` matrix filled with zeros
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    /// Creates the `n × n` identity matrix
+    pub fn identity(n: usize) -> Self {
+        let mut m = Matrix::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = 1.0;
+        }
+        m
+    }
+
+    /// Creates a matrix from a slice of equally long rows
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rows do not all have the same length.
+    pub fn from_rows(rows: &[Vec<f64>]) -> Self {
+        let cols = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != cols) {
+            panic!("All matrix rows must have the same length");
+        }
+
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.concat(),
+        }
+    }
+
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `true` if the matrix is square
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Matrix-vector product A·x
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x.len()` differs from the number of columns.
+    pub fn mul_vec(&self, x: &[f64]) -> Vec<f64> {
+        if x.len() != self.cols {
+            panic!("Vector length must match the number of matrix columns");
+        }
+
+        self.data
+            .chunks(self.cols.max(1))
+            .take(self.rows)
+            .map(|row| row.iter().zip(x).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+
+    /// Solves the square system A·x = b by LU decomposition with partial pivoting
+    ///
+    /// Returns `None` if the matrix is singular to working precision.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::matrix::Matrix;
+    ///
+    /// // 2x + y = 5, x + 3y = 10  →  x = 1, y = 3
+    /// let a = Matrix::from_rows(&[vec![2.0, 1.0], vec![1.0, 3.0]]);
+    /// let x = a.solve(&[5.0, 10.0]).unwrap();
+    ///
+    /// assert!((x[0] - 1.0).abs() < 1e-14);
+    /// assert!((x[1] - 3.0).abs() < 1e-14);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square or `b` has the wrong length.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if !self.is_square() {
+            panic!("Linear solve requires a square matrix");
+        }
+        if b.len() != self.rows {
+            panic!("Right-hand side length must match the matrix size");
+        }
+
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut x = b.to_vec();
+
+        let scale = self.data.iter().fold(0.0_f64, |m, v| m.max(v.abs()));
+        let threshold = scale * n as f64 * f64::EPSILON;
+
+        for k in 0..n {
+            // Partial pivoting: bring the largest remaining entry of column k up
+            let pivot_row = (k..n)
+                .max_by(|&i, &j| lu[(i, k)].abs().total_cmp(&lu[(j, k)].abs()))
+                .unwrap();
+            if lu[(pivot_row, k)].abs() <= threshold {
+                return None;
+            }
+
+            if pivot_row != k {
+                for j in 0..n {
+                    lu.data.swap(k * n + j, pivot_row * n + j);
+                }
+                x.swap(k, pivot_row);
+            }
+
+            for i in (k + 1)..n {
+                let factor = lu[(i, k)] / lu[(k, k)];
+                lu[(i, k)] = factor;
+                for j in (k + 1)..n {
+                    lu[(i, j)] -= factor * lu[(k, j)];
+                }
+                x[i] -= factor * x[k];
+            }
+        }
+
+        // Back substitution with the upper triangle
+        for i in (0..n).rev() {
+            let sum: f64 = ((i + 1)..n).map(|j| lu[(i, j)] * x[j]).sum();
+            x[i] = (x[i] - sum) / lu[(i, i)];
+        }
+
+        Some(x)
+    }
+
+    /// Computes all eigenvalues of a general square matrix
+    ///
+    /// The matrix is balanced, reduced to upper Hessenberg form by stabilised
+    /// elementary similarity transformations, and then deflated with the Francis
+    /// double-shift QR iteration. Complex eigenvalues of a real matrix come out as
+    /// conjugate pairs.
+    ///
+    /// Returns `None` if the QR iteration fails to converge (more than 30
+    /// iterations for a single eigenvalue), which is very rare in practice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::matrix::Matrix;
+    ///
+    /// // A rotation by 90° has eigenvalues ±i
+    /// let rotation = Matrix::from_rows(&[vec![0.0, -1.0], vec![1.0, 0.0]]);
+    /// let eigenvalues = rotation.eigenvalues().unwrap();
+    ///
+    /// for lambda in eigenvalues {
+    ///     assert!(lambda.re.abs() < 1e-15);
+    ///     assert!((lambda.im.abs() - 1.0).abs() < 1e-15);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn eigenvalues(&self) -> Option<Vec<Complex64>> {
+        if !self.is_square() {
+            panic!("Eigenvalues require a square matrix");
+        }
+
+        // The classic balance/elmhes/hqr routines are written with 1-based indices;
+        // keeping that layout makes the index arithmetic easy to audit
+        let n = self.rows;
+        let mut a = vec![vec![0.0; n + 1]; n + 1];
+        for (row, src) in a.iter_mut().skip(1).zip(self.data.chunks(n)) {
+            row[1..].copy_from_slice(src);
+        }
+
+        balance(&mut a, n);
+        reduce_to_hessenberg(&mut a, n);
+        hessenberg_qr(&mut a, n)
+    }
+
+    /// Computes all eigenvalues and eigenvectors of a symmetric matrix with the
+    /// cyclic Jacobi method
+    ///
+    /// Each sweep visits every off-diagonal pair (p, q) and applies the plane
+    /// rotation J that zeroes aₚq, A ← Jᵀ·A·J, accumulating V ← V·J. Later rotations
+    /// partly refill earlier zeros, but the off-diagonal norm still shrinks
+    /// quadratically once it is small, so a handful of sweeps suffice. The method is
+    /// slower than QR for large matrices but computes small eigenvalues to high
+    /// relative accuracy.
+    ///
+    /// # Arguments
+    ///
+    /// * `tol` - The iteration stops once the off-diagonal Frobenius norm is at
+    ///   most `tol` times the Frobenius norm of the matrix. The same relative
+    ///   tolerance decides whether the input counts as symmetric.
+    /// * `max_sweeps` - Maximum number of sweeps over all off-diagonal pairs.
+    ///
+    /// # Returns
+    ///
+    /// The eigenvalues in ascending order and the orthogonal matrix V whose column
+    /// `k` is the unit eigenvector of eigenvalue `k`, so that A·V = V·diag(λ).
+    /// Returns `None` if the matrix is not symmetric or the off-diagonal norm does
+    /// not fall below the tolerance within `max_sweeps` sweeps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::matrix::Matrix;
+    ///
+    /// let a = Matrix::from_rows(&[vec![2.0, 1.0], vec![1.0, 2.0]]);
+    /// let (eigenvalues, vectors) = a.jacobi_eigen(1e-14, 10).unwrap();
+    ///
+    /// assert!((eigenvalues[0] - 1.0).abs() < 1e-14);
+    /// assert!((eigenvalues[1] - 3.0).abs() < 1e-14);
+    ///
+    /// // The eigenvector of 3 is (1, 1)/√2
+    /// assert!((vectors[(0, 1)].abs() - 0.5_f64.sqrt()).abs() < 1e-14);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is not square.
+    pub fn jacobi_eigen(&self, tol: f64, max_sweeps: usize) -> Option<(Vec<f64>, Matrix)> {
+        if !self.is_square() {
+            panic!("Eigenvalues require a square matrix");
+        }
+
+        let n = self.rows;
+        let norm = self.data.iter().map(|x| x * x).sum::<f64>().sqrt();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if (self[(i, j)] - self[(j, i)]).abs() > tol * norm {
+                    return None;
+                }
+            }
+        }
+
+        let mut a = self.clone();
+        let mut v = Matrix::identity(n);
+        let off_diagonal = |a: &Matrix| {
+            let mut sum = 0.0;
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    sum += 2.0 * a[(i, j)] * a[(i, j)];
+                }
+            }
+            sum.sqrt()
+        };
+
+        let mut converged = off_diagonal(&a) <= tol * norm;
+        for _ in 0..max_sweeps {
+            if converged {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let apq = a[(p, q)];
+                    if apq == 0.0 {
+                        continue;
+                    }
+
+                    // tan φ of the rotation angle, the smaller root of
+                    // t² + 2θt - 1 = 0, which keeps |φ| ≤ π/4
+                    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * apq);
+                    let t = 1.0_f64.copysign(theta) / (theta.abs() + theta.hypot(1.0));
+                    let c = 1.0 / t.hypot(1.0);
+                    let s = t * c;
+
+                    // A ← A·J and V ← V·J, mixing columns p and q
+                    for k in 0..n {
+                        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+
+                        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                    // A ← Jᵀ·A, mixing rows p and q
+                    for k in 0..n {
+                        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                }
+            }
+
+            converged = off_diagonal(&a) <= tol * norm;
+        }
+        if !converged {
+            return None;
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[(i, i)].total_cmp(&a[(j, j)]));
+
+        let eigenvalues = order.iter().map(|&i| a[(i, i)]).collect();
+        let mut vectors = Matrix::zeros(n, n);
+        for (column, &source) in order.iter().enumerate() {
+            for k in 0..n {
+                vectors[(k, column)] = v[(k, source)];
+            }
+        }
+
+        Some((eigenvalues, vectors))
+    }
+}
+
+/// Balances a matrix by diagonal similarity scaling with powers of two, which
+/// reduces the norm and improves the accuracy of the computed eigenvalues
+#[allow(clippy::needless_range_loop)]
+fn balance(a: &mut [Vec<f64>], n: usize) {
+    const RADIX: f64 = 2.0;
+    let sqrdx = RADIX * RADIX;
+
+    let mut done = false;
+    while !done {
+        done = true;
+        for i in 1..=n {
+            let mut c = 0.0;
+            let mut r = 0.0;
+            for j in 1..=n {
+                if j != i {
+                    c += a[j][i].abs();
+                    r += a[i][j].abs();
+                }
+            }
+
+            if c != 0.0 && r != 0.0 {
+                let s = c + r;
+                let mut f = 1.0;
+
+                let g = r / RADIX;
+                while c < g {
+                    f *= RADIX;
+                    c *= sqrdx;
+                }
+                let g = r * RADIX;
+                while c > g {
+                    f /= RADIX;
+                    c /= sqrdx;
+                }
+
+                if (c + r) / f < 0.95 * s {
+                    done = false;
+                    for j in 1..=n {
+                        a[i][j] /= f;
+                        a[j][i] *= f;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Reduces a matrix to upper Hessenberg form by Gaussian elimination with pivoting
+#[allow(clippy::needless_range_loop)]
+fn reduce_to_hessenberg(a: &mut [Vec<f64>], n: usize) {
+    for m in 2..n {
+        let mut x: f64 = 0.0;
+        let mut pivot = m;
+        for j in m..=n {
+            if a[j][m - 1].abs() > x.abs() {
+                x = a[j][m - 1];
+                pivot = j;
+            }
+        }
+
+        if pivot != m {
+            for j in (m - 1)..=n {
+                let tmp = a[pivot][j];
+                a[pivot][j] = a[m][j];
+                a[m][j] = tmp;
+            }
+            for row in a.iter_mut().skip(1) {
+                row.swap(pivot, m);
+            }
+        }
+
+        if x != 0.0 {
+            for i in (m + 1)..=n {
+                let mut y = a[i][m - 1];
+                if y != 0.0 {
+                    y /= x;
+                    a[i][m - 1] = 0.0;
+                    for j in m..=n {
+                        a[i][j] -= y * a[m][j];
+                    }
+                    for row in a.iter_mut().skip(1) {
+                        row[m] += y * row[i];
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Eigenvalues of an upper Hessenberg matrix by the Francis double-shift QR algorithm
+#[allow(clippy::needless_range_loop)]
+fn hessenberg_qr(a: &mut [Vec<f64>], n: usize) -> Option<Vec<Complex64>> {
+    let mut wr = vec![0.0; n + 1];
+    let mut wi = vec![0.0; n + 1];
+
+    let mut anorm = 0.0;
+    for i in 1..=n {
+        for j in i.saturating_sub(1).max(1)..=n {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    let mut t = 0.0;
+    let (mut p, mut q, mut r): (f64, f64, f64);
+    let (mut x, mut y, mut z, mut w);
+
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            // Look for a single small subdiagonal element
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+
+            x = a[nn][nn];
+            if l == nn {
+                // One root found
+                wr[nn] = x + t;
+                wi[nn] = 0.0;
+                nn -= 1;
+            } else {
+                y = a[nn - 1][nn - 1];
+                w = a[nn][nn - 1] * a[nn - 1][nn];
+                if l == nn - 1 {
+                    // Two roots found
+                    p = 0.5 * (y - x);
+                    q = p * p + w;
+                    z = q.abs().sqrt();
+                    x += t;
+                    if q >= 0.0 {
+                        z = p + z.copysign(p);
+                        wr[nn - 1] = x + z;
+                        wr[nn] = x + z;
+                        if z != 0.0 {
+                            wr[nn] = x - w / z;
+                        }
+                        wi[nn - 1] = 0.0;
+                        wi[nn] = 0.0;
+                    } else {
+                        wr[nn - 1] = x + p;
+                        wr[nn] = x + p;
+                        wi[nn - 1] = -z;
+                        wi[nn] = z;
+                    }
+                    nn = nn.saturating_sub(2);
+                } else {
+                    if its == 30 {
+                        return None;
+                    }
+                    if its == 10 || its == 20 {
+                        // Exceptional shift
+                        t += x;
+                        for i in 1..=nn {
+                            a[i][i] -= x;
+                        }
+                        let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                        x = 0.75 * s;
+                        y = x;
+                        w = -0.4375 * s * s;
+                    }
+                    its += 1;
+
+                    // Form the shift and look for two consecutive small subdiagonal elements
+                    let mut m = nn - 2;
+                    loop {
+                        z = a[m][m];
+                        r = x - z;
+                        let s = y - z;
+                        p = (r * s - w) / a[m + 1][m] + a[m][m + 1];
+                        q = a[m + 1][m + 1] - z - r - s;
+                        r = a[m + 2][m + 1];
+                        let s = p.abs() + q.abs() + r.abs();
+                        p /= s;
+                        q /= s;
+                        r /= s;
+                        if m == l {
+                            break;
+                        }
+                        let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                        let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                        if u + v == v {
+                            break;
+                        }
+                        m -= 1;
+                    }
+
+                    for i in (m + 2)..=nn {
+                        a[i][i - 2] = 0.0;
+                        if i != m + 2 {
+                            a[i][i - 3] = 0.0;
+                        }
+                    }
+
+                    // Double QR step on rows l..nn and columns m..nn
+                    for k in m..nn {
+                        if k != m {
+                            p = a[k][k - 1];
+                            q = a[k + 1][k - 1];
+                            r = if k != nn - 1 { a[k + 2][k - 1] } else { 0.0 };
+                            x = p.abs() + q.abs() + r.abs();
+                            if x != 0.0 {
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+                        }
+
+                        let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                        if s != 0.0 {
+                            if k == m {
+                                if l != m {
+                                    a[k][k - 1] = -a[k][k - 1];
+                                }
+                            } else {
+                                a[k][k - 1] = -s * x;
+                            }
+                            p += s;
+                            x = p / s;
+                            y = q / s;
+                            z = r / s;
+                            q /= p;
+                            r /= p;
+
+                            for j in k..=nn {
+                                let mut pj = a[k][j] + q * a[k + 1][j];
+                                if k != nn - 1 {
+                                    pj += r * a[k + 2][j];
+                                    a[k + 2][j] -= pj * z;
+                                }
+                                a[k + 1][j] -= pj * y;
+                                a[k][j] -= pj * x;
+                            }
+
+                            let mmin = nn.min(k + 3);
+                            for row in a.iter_mut().take(mmin + 1).skip(l) {
+                                let mut pi = x * row[k] + y * row[k + 1];
+                                if k != nn - 1 {
+                                    pi += z * row[k + 2];
+                                    row[k + 2] -= pi * r;
+                                }
+                                row[k + 1] -= pi * q;
+                                row[k] -= pi;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if nn == 0 || l + 1 >= nn {
+                break;
+            }
+        }
+    }
+
+    Some((1..=n).map(|i| Complex64::new(wr[i], wi[i])).collect())
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+/// Solves a tridiagonal linear system with the Thomas algorithm
+///
+/// The system has the form
+///
+/// ```text
+/// diag[0]·x[0] + sup[0]·x[1]                               = rhs[0]
+/// sub[i-1]·x[i-1] + diag[i]·x[i] + sup[i]·x[i+1]           = rhs[i]
+/// sub[n-2]·x[n-2] + diag[n-1]·x[n-1]                       = rhs[n-1]
+/// ```
+///
+/// This is Gaussian elimination specialised to three diagonals, running in O(n)
+/// time and memory instead of the O(n³) of a dense solve. No pivoting is
+/// performed, which is stable for diagonally dominant or symmetric positive
+/// definite systems such as those produced by cubic splines and finite differences.
+///
+/// ## Arguments
+///
+/// * `sub` - Sub-diagonal, length `n - 1`
+/// * `diag` - Main diagonal, length `n`
+/// * `sup` - Super-diagonal, length `n - 1`
+/// * `rhs` - Right-hand side, length `n`
+///
+/// ## Returns
+///
+/// The solution vector, or `None` if a zero pivot is encountered.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::matrix::solve_tridiagonal;
+///
+/// // [2 1 0] [x₀]   [4]
+/// // [1 2 1] [x₁] = [8]
+/// // [0 1 2] [x₂]   [8]
+/// let x = solve_tridiagonal(&[1.0, 1.0], &[2.0, 2.0, 2.0], &[1.0, 1.0], &[4.0, 8.0, 8.0]).unwrap();
+///
+/// assert!((x[0] - 1.0).abs() < 1e-14);
+/// assert!((x[1] - 2.0).abs() < 1e-14);
+/// assert!((x[2] - 3.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the diagonal lengths are inconsistent.
+pub fn solve_tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64], rhs: &[f64]) -> Option<Vec<f64>> {
+    let n = diag.len();
+    if rhs.len() != n || sub.len() + 1 != n.max(1) || sup.len() + 1 != n.max(1) {
+        panic!(
+            "Tridiagonal system requires diagonals of lengths n - 1, n, n - 1 and rhs of length n"
+        );
+    }
+    if n == 0 {
+        return Some(Vec::new());
+    }
+
+    // Forward sweep: eliminate the sub-diagonal
+    let mut c = vec![0.0; n];
+    let mut d = vec![0.0; n];
+
+    if diag[0] == 0.0 {
+        return None;
+    }
+    c[0] = if n > 1 { sup[0] / diag[0] } else { 0.0 };
+    d[0] = rhs[0] / diag[0];
+
+    for i in 1..n {
+        let pivot = diag[i] - sub[i - 1] * c[i - 1];
+        if pivot == 0.0 {
+            return None;
+        }
+        if i < n - 1 {
+            c[i] = sup[i] / pivot;
+        }
+        d[i] = (rhs[i] - sub[i - 1] * d[i - 1]) / pivot;
+    }
+
+    // Back substitution
+    for i in (0..n - 1).rev() {
+        d[i] -= c[i] * d[i + 1];
+    }
+
+    Some(d)
+}
+
+/// Solves A·x = b with the Jacobi iteration
+///
+/// Starting from x = 0, every sweep solves equation i for xᵢ using the values of
+/// the previous sweep:
+///
+/// ```text
+/// xᵢ ← (bᵢ - Σⱼ≠ᵢ aᵢⱼ·xⱼ) / aᵢᵢ
+/// ```
+///
+/// ## Convergence
+///
+/// The iteration converges for any starting point if and only if the spectral
+/// radius of D⁻¹(A - D) is below one, where D is the diagonal of A. This holds
+/// in particular when A is strictly diagonally dominant by rows,
+/// |aᵢᵢ| > Σⱼ≠ᵢ |aᵢⱼ|. For other matrices it may diverge.
+///
+/// ## Arguments
+///
+/// * `a` - Square coefficient matrix with a nonzero diagonal.
+/// * `b` - Right-hand side.
+/// * `tol` - The iteration stops once no component changes by more than `tol`
+///   in a sweep.
+/// * `max_iter` - Maximum number of sweeps.
+///
+/// ## Returns
+///
+/// The solution, or `None` if a diagonal entry is zero, the iterates stop being
+/// finite, or `max_iter` sweeps pass without convergence.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::matrix::{jacobi_solve, Matrix};
+///
+/// let a = Matrix::from_rows(&[vec![4.0, 1.0], vec![2.0, 5.0]]);
+/// let x = jacobi_solve(&a, &[6.0, 12.0], 1e-12, 100).unwrap();
+///
+/// assert!((x[0] - 1.0).abs() < 1e-11);
+/// assert!((x[1] - 2.0).abs() < 1e-11);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the matrix is not square or `b` has the wrong length.
+pub fn jacobi_solve(a: &Matrix, b: &[f64], tol: f64, max_iter: usize) -> Option<Vec<f64>> {
+    check_iterative_system(a, b)?;
+
+    let n = a.rows;
+    let mut x = vec![0.0; n];
+    let mut next = vec![0.0; n];
+
+    for _ in 0..max_iter {
+        let mut change: f64 = 0.0;
+        for i in 0..n {
+            let row = &a.data[i * n..(i + 1) * n];
+            let off_diagonal: f64 = (0..n).filter(|&j| j != i).map(|j| row[j] * x[j]).sum();
+            next[i] = (b[i] - off_diagonal) / row[i];
+            change = change.max((next[i] - x[i]).abs());
+        }
+        std::mem::swap(&mut x, &mut next);
+
+        if !change.is_finite() {
+            return None;
+        }
+        if change <= tol {
+            return Some(x);
+        }
+    }
+
+    None
+}
+
+/// Solves A·x = b with the Gauss-Seidel iteration
+///
+/// Like [`jacobi_solve`], but each updated component is used immediately by the
+/// equations that follow it in the same sweep:
+///
+/// ```text
+/// xᵢ ← (bᵢ - Σⱼ<ᵢ aᵢⱼ·xⱼ(new) - Σⱼ>ᵢ aᵢⱼ·xⱼ(old)) / aᵢᵢ
+/// ```
+///
+/// This needs no second vector and typically converges in about half as many
+/// sweeps.
+///
+/// ## Convergence
+///
+/// Gauss-Seidel converges for strictly diagonally dominant matrices and, unlike
+/// Jacobi, for every symmetric positive definite matrix. Otherwise it may
+/// diverge.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::matrix::{gauss_seidel_solve, Matrix};
+///
+/// let a = Matrix::from_rows(&[vec![4.0, 1.0], vec![2.0, 5.0]]);
+/// let x = gauss_seidel_solve(&a, &[6.0, 12.0], 1e-12, 100).unwrap();
+///
+/// assert!((x[0] - 1.0).abs() < 1e-11);
+/// assert!((x[1] - 2.0).abs() < 1e-11);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the matrix is not square or `b` has the wrong length.
+pub fn gauss_seidel_solve(a: &Matrix, b: &[f64], tol: f64, max_iter: usize) -> Option<Vec<f64>> {
+    check_iterative_system(a, b)?;
+
+    let n = a.rows;
+    let mut x = vec![0.0; n];
+
+    for _ in 0..max_iter {
+        let mut change: f64 = 0.0;
+        for i in 0..n {
+            let row = &a.data[i * n..(i + 1) * n];
+            let off_diagonal: f64 = (0..n).filter(|&j| j != i).map(|j| row[j] * x[j]).sum();
+            let updated = (b[i] - off_diagonal) / row[i];
+            change = change.max((updated - x[i]).abs());
+            x[i] = updated;
+        }
+
+        if !change.is_finite() {
+            return None;
+        }
+        if change <= tol {
+            return Some(x);
+        }
+    }
+
+    None
+}
+
+/// Shape checks shared by the iterative solvers; `None` for a zero diagonal entry
+fn check_iterative_system(a: &Matrix, b: &[f64]) -> Option<()> {
+    if !a.is_square() {
+        panic!("Linear solve requires a square matrix");
+    }
+    if b.len() != a.rows {
+        panic!("Right-hand side length must match the matrix size");
+    }
+
+    (0..a.rows).all(|i| a[(i, i)] != 0.0).then_some(())
+}
+
+/// Finds the dominant eigenvalue of a square matrix and its eigenvector by
+/// power iteration
+///
+/// Repeatedly applies v ← A·v / ‖A·v‖, estimating the eigenvalue with the
+/// Rayleigh quotient λ = vᵀ·A·v. The component of v along the eigenvector of the
+/// largest-magnitude eigenvalue grows fastest, so v converges to it at the rate
+/// |λ₂/λ₁| per step, where λ₂ is the second-largest eigenvalue in magnitude.
+///
+/// The eigenvector is returned with unit Euclidean norm, oriented so that its
+/// largest-magnitude component is positive.
+///
+/// ## Convergence
+///
+/// The iteration requires a unique eigenvalue of largest magnitude; it fails
+/// when that eigenvalue is complex or when λ and -λ are both dominant. The start
+/// vector has distinct positive components, so it is almost never orthogonal to
+/// the dominant eigenvector.
+///
+/// ## Arguments
+///
+/// * `a` - Square matrix.
+/// * `tol` - The iteration stops once the residual ‖A·v - λ·v‖ is at most
+///   `tol·|λ|`.
+/// * `max_iter` - Maximum number of matrix-vector products.
+///
+/// ## Returns
+///
+/// `(λ, v)`, or `None` if the residual does not fall below the tolerance within
+/// `max_iter` steps or the matrix is empty.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::matrix::{power_iteration, Matrix};
+///
+/// // Eigenvalues 3 and 1, with eigenvectors (1, 1) and (1, -1)
+/// let a = Matrix::from_rows(&[vec![2.0, 1.0], vec![1.0, 2.0]]);
+/// let (lambda, v) = power_iteration(&a, 1e-12, 100).unwrap();
+///
+/// assert!((lambda - 3.0).abs() < 1e-12);
+/// assert!((v[0] - v[1]).abs() < 1e-11);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the matrix is not square.
+pub fn power_iteration(a: &Matrix, tol: f64, max_iter: usize) -> Option<(f64, Vec<f64>)> {
+    if !a.is_square() {
+        panic!("Power iteration requires a square matrix");
+    }
+
+    let n = a.rows;
+    if n == 0 {
+        return None;
+    }
+
+    let mut v: Vec<f64> = (0..n).map(|i| 1.0 + i as f64 / n as f64).collect();
+    normalize_orientation(&mut v);
+
+    for _ in 0..max_iter {
+        let w = a.mul_vec(&v);
+        let lambda: f64 = v.iter().zip(&w).map(|(x, y)| x * y).sum();
+
+        let residual = w
+            .iter()
+            .zip(&v)
+            .map(|(y, x)| (y - lambda * x).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        if residual <= tol * lambda.abs() {
+            return Some((lambda, v));
+        }
+        if !residual.is_finite() {
+            return None;
+        }
+
+        // A·v = 0 makes v an eigenvector for 0 and leaves no direction to follow
+        if w.iter().all(|&y| y == 0.0) {
+            return Some((0.0, v));
+        }
+        v = w;
+        normalize_orientation(&mut v);
+    }
+
+    None
+}
+
+/// Scales `v` to unit length with its largest-magnitude component positive
+fn normalize_orientation(v: &mut [f64]) {
+    let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let largest = v
+        .iter()
+        .fold(0.0_f64, |m, &x| if x.abs() > m.abs() { x } else { m });
+    let scale = norm.copysign(largest);
+    for x in v.iter_mut() {
+        *x /= scale;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_solve() {
+        let a = Matrix::from_rows(&[
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ]);
+        let x = a.solve(&[8.0, -11.0, -3.0]).unwrap();
+
+        let expected = [2.0, 3.0, -1.0];
+        for i in 0..3 {
+            assert!((x[i] - expected[i]).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_matrix_solve_needs_pivoting() {
+        // A zero in the top-left corner requires a row swap
+        let a = Matrix::from_rows(&[vec![0.0, 1.0], vec![1.0, 0.0]]);
+        let x = a.solve(&[2.0, 3.0]).unwrap();
+        assert_eq!(x, vec![3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_matrix_solve_singular() {
+        let a = Matrix::from_rows(&[vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert!(a.solve(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_identity_and_mul_vec() {
+        let i = Matrix::identity(3);
+        assert_eq!(i.mul_vec(&[1.0, -2.0, 5.0]), vec![1.0, -2.0, 5.0]);
+        assert_eq!(i.rows(), 3);
+        assert!(i.is_square());
+    }
+
+    #[test]
+    #[should_panic(expected = "All matrix rows must have the same length")]
+    fn test_from_rows_ragged() {
+        Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0]]);
+    }
+
+    fn sorted_real_parts(eigenvalues: &[Complex64]) -> Vec<f64> {
+        let mut re: Vec<f64> = eigenvalues.iter().map(|z| z.re).collect();
+        re.sort_by(f64::total_cmp);
+        re
+    }
+
+    #[test]
+    fn test_eigenvalues_triangular() {
+        let a = Matrix::from_rows(&[
+            vec![2.0, 1.0, 4.0],
+            vec![0.0, -3.0, 5.0],
+            vec![0.0, 0.0, 7.0],
+        ]);
+        let eigenvalues = a.eigenvalues().unwrap();
+
+        assert!(eigenvalues.iter().all(|z| z.im == 0.0));
+        let re = sorted_real_parts(&eigenvalues);
+        for (actual, expected) in re.iter().zip([-3.0, 2.0, 7.0]) {
+            assert!((actual - expected).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_symmetric() {
+        // Eigenvalues of the 1D Laplacian: 2 - 2cos(kπ/(n+1))
+        let n = 6;
+        let mut a = Matrix::zeros(n, n);
+        for i in 0..n {
+            a[(i, i)] = 2.0;
+            if i + 1 < n {
+                a[(i, i + 1)] = -1.0;
+                a[(i + 1, i)] = -1.0;
+            }
+        }
+
+        let re = sorted_real_parts(&a.eigenvalues().unwrap());
+        for (k, actual) in re.iter().enumerate() {
+            let expected =
+                2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos();
+            assert!((actual - expected).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_companion_matrix() {
+        // Companion matrix of z³ - 1: the cube roots of unity
+        let a = Matrix::from_rows(&[
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+        ]);
+        let eigenvalues = a.eigenvalues().unwrap();
+
+        assert_eq!(eigenvalues.len(), 3);
+        for z in &eigenvalues {
+            assert!(((*z * *z * *z) - Complex64::ONE).norm() < 1e-13);
+        }
+        let imag_sum: f64 = eigenvalues.iter().map(|z| z.im).sum();
+        assert!(imag_sum.abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_known_solution() {
+        // -x[i-1] + 4x[i] - x[i+1] with solution x = [1, 2, 3, 4, 5]
+        let sub = [-1.0; 4];
+        let diag = [4.0; 5];
+        let sup = [-1.0; 4];
+        let rhs = [2.0, 4.0, 6.0, 8.0, 16.0];
+
+        let x = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+        for (i, xi) in x.iter().enumerate() {
+            assert!((xi - (i + 1) as f64).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_matches_lu() {
+        let sub = [1.0, -2.0, 0.5, 3.0];
+        let diag = [5.0, 6.0, 7.0, 4.0, 8.0];
+        let sup = [2.0, 1.0, -1.0, 0.5];
+        let rhs = [1.0, -2.0, 3.0, 0.0, 4.0];
+
+        let mut dense = Matrix::zeros(5, 5);
+        for i in 0..5 {
+            dense[(i, i)] = diag[i];
+            if i > 0 {
+                dense[(i, i - 1)] = sub[i - 1];
+            }
+            if i < 4 {
+                dense[(i, i + 1)] = sup[i];
+            }
+        }
+
+        let thomas = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+        let lu = dense.solve(&rhs).unwrap();
+        for i in 0..5 {
+            assert!((thomas[i] - lu[i]).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_zero_pivot() {
+        assert!(solve_tridiagonal(&[1.0], &[0.0, 1.0], &[1.0], &[1.0, 1.0]).is_none());
+        assert!(solve_tridiagonal(&[1.0], &[1.0, 1.0], &[1.0], &[1.0, 1.0]).is_none());
+    }
+
+    #[test]
+    fn test_solve_tridiagonal_single_equation() {
+        assert_eq!(solve_tridiagonal(&[], &[2.0], &[], &[3.0]), Some(vec![1.5]));
+    }
+
+    /// A strictly diagonally dominant 4 × 4 system
+    fn dominant_system() -> (Matrix, Vec<f64>) {
+        let a = Matrix::from_rows(&[
+            vec![10.0, -1.0, 2.0, 0.0],
+            vec![-1.0, 11.0, -1.0, 3.0],
+            vec![2.0, -1.0, 10.0, -1.0],
+            vec![0.0, 3.0, -1.0, 8.0],
+        ]);
+        (a, vec![6.0, 25.0, -11.0, 15.0])
+    }
+
+    #[test]
+    fn test_iterative_solvers_match_lu() {
+        let (a, b) = dominant_system();
+        let direct = a.solve(&b).unwrap();
+
+        let jacobi = jacobi_solve(&a, &b, 1e-13, 200).unwrap();
+        let seidel = gauss_seidel_solve(&a, &b, 1e-13, 200).unwrap();
+        for i in 0..4 {
+            assert!((jacobi[i] - direct[i]).abs() < 1e-12);
+            assert!((seidel[i] - direct[i]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gauss_seidel_needs_fewer_sweeps() {
+        let (a, b) = dominant_system();
+        let jacobi_sweeps = (1..100).find(|&k| jacobi_solve(&a, &b, 1e-12, k).is_some());
+        let seidel_sweeps = (1..100).find(|&k| gauss_seidel_solve(&a, &b, 1e-12, k).is_some());
+
+        assert!(seidel_sweeps.unwrap() < jacobi_sweeps.unwrap());
+    }
+
+    #[test]
+    fn test_iterative_solvers_detect_divergence() {
+        // Far from diagonally dominant: the iteration matrix has spectral radius 3
+        let a = Matrix::from_rows(&[vec![1.0, 3.0], vec![3.0, 1.0]]);
+        assert_eq!(jacobi_solve(&a, &[1.0, 2.0], 1e-10, 1_000), None);
+        assert_eq!(gauss_seidel_solve(&a, &[1.0, 2.0], 1e-10, 1_000), None);
+
+        // The direct solver has no trouble with it
+        assert!(a.solve(&[1.0, 2.0]).is_some());
+    }
+
+    #[test]
+    fn test_iterative_solvers_zero_diagonal() {
+        let a = Matrix::from_rows(&[vec![0.0, 1.0], vec![1.0, 0.0]]);
+        assert_eq!(jacobi_solve(&a, &[1.0, 1.0], 1e-10, 100), None);
+        assert_eq!(gauss_seidel_solve(&a, &[1.0, 1.0], 1e-10, 100), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Right-hand side length must match the matrix size")]
+    fn test_jacobi_solve_wrong_rhs_length() {
+        jacobi_solve(&Matrix::identity(3), &[1.0, 2.0], 1e-10, 10);
+    }
+
+    #[test]
+    fn test_power_iteration_symmetric_spectrum() {
+        // Symmetric with eigenvalues 2 - √2, 2 and 2 + √2
+        let a = Matrix::from_rows(&[
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]);
+        let (lambda, v) = power_iteration(&a, 1e-12, 1_000).unwrap();
+        assert!((lambda - (2.0 + 2.0_f64.sqrt())).abs() < 1e-11);
+
+        // Eigenvector (1, -√2, 1)/2, up to sign
+        let expected = [0.5, -0.5 * 2.0_f64.sqrt(), 0.5];
+        let sign = v[0].signum();
+        for i in 0..3 {
+            assert!((sign * v[i] - expected[i]).abs() < 1e-10);
+        }
+
+        let av = a.mul_vec(&v);
+        for i in 0..3 {
+            assert!((av[i] - lambda * v[i]).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_power_iteration_negative_dominant_eigenvalue() {
+        // Eigenvalues -4 and 1; the orientation rule keeps the iterates from flipping
+        let a = Matrix::from_rows(&[vec![-4.0, 0.0], vec![0.0, 1.0]]);
+        let (lambda, v) = power_iteration(&a, 1e-12, 200).unwrap();
+        assert!((lambda + 4.0).abs() < 1e-12);
+        assert!((v[0] - 1.0).abs() < 1e-12);
+        assert!(v[1].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_power_iteration_fails_without_dominant_eigenvalue() {
+        // Rotation by 90°: eigenvalues ±i have equal magnitude
+        let a = Matrix::from_rows(&[vec![0.0, -1.0], vec![1.0, 0.0]]);
+        assert_eq!(power_iteration(&a, 1e-10, 500), None);
+
+        assert_eq!(power_iteration(&Matrix::zeros(0, 0), 1e-10, 10), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Power iteration requires a square matrix")]
+    fn test_power_iteration_not_square() {
+        power_iteration(&Matrix::zeros(2, 3), 1e-10, 10);
+    }
+
+    #[test]
+    fn test_jacobi_eigen_diagonal() {
+        let a = Matrix::from_rows(&[
+            vec![3.0, 0.0, 0.0],
+            vec![0.0, -1.0, 0.0],
+            vec![0.0, 0.0, 2.0],
+        ]);
+        let (eigenvalues, vectors) = a.jacobi_eigen(1e-14, 10).unwrap();
+
+        assert_eq!(eigenvalues, [-1.0, 2.0, 3.0]);
+        // Columns are the permuted unit vectors
+        assert_eq!(vectors[(1, 0)], 1.0);
+        assert_eq!(vectors[(2, 1)], 1.0);
+        assert_eq!(vectors[(0, 2)], 1.0);
+    }
+
+    #[test]
+    fn test_jacobi_eigen_two_by_two_analytic() {
+        // [[a, b], [b, d]] has eigenvalues (a + d)/2 ± √(((a - d)/2)² + b²)
+        let (a, b, d) = (4.0_f64, 1.5_f64, -1.0_f64);
+        let m = Matrix::from_rows(&[vec![a, b], vec![b, d]]);
+        let (eigenvalues, vectors) = m.jacobi_eigen(1e-15, 10).unwrap();
+
+        let mean = 0.5 * (a + d);
+        let radius = (0.5 * (a - d)).hypot(b);
+        assert!((eigenvalues[0] - (mean - radius)).abs() < 1e-14);
+        assert!((eigenvalues[1] - (mean + radius)).abs() < 1e-14);
+
+        // The eigenvector of λ is proportional to (b, λ - a)
+        for k in 0..2 {
+            let (x, y) = (b, eigenvalues[k] - a);
+            let length = x.hypot(y);
+            let dot = (vectors[(0, k)] * x + vectors[(1, k)] * y) / length;
+            assert!((dot.abs() - 1.0).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_jacobi_eigen_decomposition() {
+        let a = Matrix::from_rows(&[
+            vec![4.0, -2.0, 1.0, 0.5],
+            vec![-2.0, 3.0, 0.0, 1.0],
+            vec![1.0, 0.0, 2.0, -1.5],
+            vec![0.5, 1.0, -1.5, 1.0],
+        ]);
+        let (eigenvalues, vectors) = a.jacobi_eigen(1e-15, 50).unwrap();
+
+        // A·V = V·diag(λ) and Vᵀ·V = I
+        for k in 0..4 {
+            let column: Vec<f64> = (0..4).map(|i| vectors[(i, k)]).collect();
+            let image = a.mul_vec(&column);
+            for i in 0..4 {
+                assert!((image[i] - eigenvalues[k] * column[i]).abs() < 1e-13);
+            }
+            for j in 0..4 {
+                let dot: f64 = (0..4).map(|i| vectors[(i, j)] * vectors[(i, k)]).sum();
+                let expected = if j == k { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-14);
+            }
+        }
+
+        // Agrees with the general eigenvalue solver
+        let mut general: Vec<f64> = a.eigenvalues().unwrap().iter().map(|z| z.re).collect();
+        general.sort_by(f64::total_cmp);
+        for (x, y) in eigenvalues.iter().zip(&general) {
+            assert!((x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_jacobi_eigen_rejects_asymmetric() {
+        let a = Matrix::from_rows(&[vec![1.0, 2.0], vec![0.0, 1.0]]);
+        assert_eq!(a.jacobi_eigen(1e-12, 10), None);
+
+        // Too few sweeps to converge
+        let b = Matrix::from_rows(&[
+            vec![4.0, -2.0, 1.0],
+            vec![-2.0, 3.0, 0.7],
+            vec![1.0, 0.7, 2.0],
+        ]);
+        assert_eq!(b.jacobi_eigen(1e-15, 1), None);
+    }
+}
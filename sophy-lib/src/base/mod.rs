@@ -7,24 +7,64 @@
 //!
 //! ### Number Operations
 //! - [`numbers`]: Core number manipulation and conversion utilities
+//! - [`float`]: Relative/absolute and ULP-based floating-point comparison
+//!
+//! ### Coordinates
+//! - [`coords`]: Conversions between Cartesian, polar and spherical coordinates
+//! - [`grid`]: The [`Grid2D`](grid::Grid2D) mesh for sampling functions of two variables
+//!
+//! ### Modular Arithmetic
+//! - [`modular`]: The [`Mod`](modular::Mod) residue type with automatic reduction
+//!
+//! ### Rational Numbers
+//! - [`rational`]: The exact [`Rational`](rational::Rational) fraction type
+//!
+//! ### Complex Numbers
+//! - [`complex`]: The [`Complex64`](complex::Complex64) type and its arithmetic
+//!
+//! ### Polynomials
+//! - [`poly`]: The [`Polynomial`](poly::Polynomial) type, including construction from roots
+//!
+//! ### Linear Algebra
+//! - [`matrix`]: Dense [`Matrix`](matrix::Matrix) type, LU solver and tridiagonal solver
+//!
+//! ### Reporting
+//! - [`fmt`]: The aligned two-column [`Table`](fmt::Table) of labelled values
+//!
+//! ### Random Numbers
+//! - [`rng`]: The seedable [`Xoshiro256`](rng::Xoshiro256) generator, shuffling and sampling
+//!
+//! ### Statistics
+//! - [`stats`]: Mean absolute, root-mean-square and maximum errors between arrays,
+//!   robust trimmed-mean and median-absolute-deviation estimates, stable
+//!   log-sum-exp and softmax, principal component analysis, and autocorrelation
+//!   and Durbin-Watson diagnostics
 //!
 //! ## Usage Examples
 //!
 //! ```rust
 //! use sophy::base::numbers;
 //!
-//! // Example usage (when functions are implemented):
-//! // let factorial = numbers::factorial(5);
-//! // let gcd = numbers::gcd(48, 18);
+//! assert_eq!(numbers::factorial(5), 120);
+//! assert_eq!(numbers::gcd(48, 18), 6);
+//! assert_eq!(numbers::binomial(5, 2), 10);
 //! ```
 //!
 //! ## Future Additions
 //!
 //! Planned utilities include:
-//! - Prime number operations
-//! - Greatest Common Divisor (GCD) and Least Common Multiple (LCM)
-//! - Factorization algorithms
+//! - Least Common Multiple (LCM)
 //! - Base conversion utilities
-//! - Modular arithmetic operations
 
+pub mod complex;
+pub mod coords;
+pub mod float;
+pub mod fmt;
+pub mod grid;
+pub mod matrix;
+pub mod modular;
 pub mod numbers;
+pub mod poly;
+pub mod rational;
+pub mod rng;
+pub mod stats;
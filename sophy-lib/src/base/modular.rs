@@ -0,0 +1,208 @@
+//! # Modular Arithmetic
+//!
+//! The [`Mod`] type carries a residue together with its modulus, so that sums,
+//! differences, products and powers are reduced automatically and residues of
+//! different moduli cannot be mixed by accident.
+
+use crate::base::numbers::{mod_inverse, mod_pow};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A residue modulo `modulus`, always stored reduced to `0..modulus`
+///
+/// Products are formed with 128-bit intermediates, so any modulus up to
+/// `u64::MAX` is supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::modular::Mod;
+///
+/// let a = Mod::new(3, 7);
+/// let b = Mod::new(5, 7);
+///
+/// assert_eq!(a + b, Mod::new(1, 7));
+/// assert_eq!(a - b, Mod::new(5, 7));
+/// assert_eq!(a * b, Mod::new(1, 7));
+/// assert_eq!(a.pow(2), Mod::new(2, 7));
+/// assert_eq!(a.inverse(), Some(b));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mod {
+    value: u64,
+    modulus: u64,
+}
+
+impl Mod {
+    /// Creates the residue of `value` modulo `modulus`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn new(value: u64, modulus: u64) -> Self {
+        if modulus == 0 {
+            panic!("Modulus must be positive");
+        }
+        Mod {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// The reduced value, in `0..modulus`
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// The modulus
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Raises the residue to the power `exp` by binary exponentiation
+    pub fn pow(self, exp: u64) -> Self {
+        Mod {
+            value: mod_pow(self.value, exp, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+
+    /// Multiplicative inverse, or `None` if the value and the modulus are not
+    /// coprime
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.value, self.modulus).map(|value| Mod {
+            value,
+            modulus: self.modulus,
+        })
+    }
+
+    /// The common modulus of two operands
+    fn shared_modulus(self, other: Mod) -> u64 {
+        if self.modulus != other.modulus {
+            panic!("Modular operands must share the same modulus");
+        }
+        self.modulus
+    }
+}
+
+impl Add for Mod {
+    type Output = Mod;
+
+    /// # Panics
+    ///
+    /// Panics if the moduli differ.
+    fn add(self, other: Mod) -> Mod {
+        let m = self.shared_modulus(other);
+        // Both values are below m, so the sum fits in a u128 and is below 2m
+        let sum = self.value as u128 + other.value as u128;
+        Mod {
+            value: (sum % m as u128) as u64,
+            modulus: m,
+        }
+    }
+}
+
+impl Sub for Mod {
+    type Output = Mod;
+
+    /// # Panics
+    ///
+    /// Panics if the moduli differ.
+    fn sub(self, other: Mod) -> Mod {
+        let m = self.shared_modulus(other);
+        let value = if self.value >= other.value {
+            self.value - other.value
+        } else {
+            m - (other.value - self.value)
+        };
+        Mod { value, modulus: m }
+    }
+}
+
+impl Mul for Mod {
+    type Output = Mod;
+
+    /// # Panics
+    ///
+    /// Panics if the moduli differ.
+    fn mul(self, other: Mod) -> Mod {
+        let m = self.shared_modulus(other);
+        let product = self.value as u128 * other.value as u128 % m as u128;
+        Mod {
+            value: product as u64,
+            modulus: m,
+        }
+    }
+}
+
+impl Neg for Mod {
+    type Output = Mod;
+
+    fn neg(self) -> Mod {
+        Mod::new(0, self.modulus) - self
+    }
+}
+
+impl fmt::Display for Mod {
+    /// Formats as `value (mod modulus)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod_arithmetic() {
+        assert_eq!(Mod::new(3, 7) + Mod::new(5, 7), Mod::new(1, 7));
+        assert_eq!(Mod::new(3, 7) - Mod::new(5, 7), Mod::new(5, 7));
+        assert_eq!(Mod::new(3, 7) * Mod::new(5, 7), Mod::new(1, 7));
+        assert_eq!(-Mod::new(3, 7), Mod::new(4, 7));
+        assert_eq!(-Mod::new(0, 7), Mod::new(0, 7));
+        assert_eq!(Mod::new(3, 7).pow(2), Mod::new(2, 7));
+        assert_eq!(Mod::new(10, 7).value(), 3);
+    }
+
+    #[test]
+    fn test_mod_large_modulus() {
+        let m = u64::MAX;
+        let a = Mod::new(m - 1, m);
+        assert_eq!((a + a).value(), m - 2);
+        assert_eq!((a * a).value(), 1);
+        assert_eq!((Mod::new(0, m) - Mod::new(1, m)).value(), m - 1);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        let p = 1_000_000_007;
+        for value in [1, 2, 12_345, p - 1] {
+            let a = Mod::new(value, p);
+            let inverse = a.inverse().unwrap();
+            assert_eq!(a * inverse, Mod::new(1, p));
+            // Fermat: a⁻¹ = aᵖ⁻²
+            assert_eq!(inverse, a.pow(p - 2));
+        }
+
+        assert_eq!(Mod::new(6, 9).inverse(), None);
+        assert_eq!(Mod::new(0, 7).inverse(), None);
+    }
+
+    #[test]
+    fn test_mod_display() {
+        assert_eq!(Mod::new(12, 5).to_string(), "2 (mod 5)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Modular operands must share the same modulus")]
+    fn test_mod_mismatched_moduli() {
+        let _ = Mod::new(1, 7) + Mod::new(1, 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be positive")]
+    fn test_mod_zero_modulus() {
+        Mod::new(3, 0);
+    }
+}
@@ -2,6 +2,9 @@
 //!
 //! This module provides fundamental mathematical constants and number manipulation utilities.
 
+use crate::base::rational::Rational;
+use crate::specials::digamma::digamma;
+
 /// Machine epsilon for f64 precision
 ///
 /// The smallest representable positive number such that 1.0 + EPSILON != 1.0
@@ -57,3 +60,1326 @@ pub const EULER: f64 = std::f64::consts::E;
 /// assert!((PHI * PHI - (PHI + 1.0)).abs() < 1e-15);
 /// ```
 pub const PHI: f64 = 1.618033988749895;
+
+/// The Euler-Mascheroni constant (γ)
+///
+/// The limit of Hₙ - ln n ≈ 0.5772156649015329, where Hₙ is the n-th harmonic number
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::{EULER_MASCHERONI, harmonic};
+///
+/// // Hₙ - ln n approaches γ like 1/2n
+/// let n = 1_000_000;
+/// assert!((harmonic(n) - (n as f64).ln() - EULER_MASCHERONI).abs() < 1e-6);
+/// ```
+pub const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+
+/// Greatest common divisor of two integers
+///
+/// Computed with the Euclidean algorithm. By convention `gcd(0, 0) = 0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::gcd;
+///
+/// assert_eq!(gcd(48, 18), 6);
+/// assert_eq!(gcd(17, 5), 1);
+/// assert_eq!(gcd(0, 9), 9);
+/// ```
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Modular exponentiation baseᵉˣᵖ mod modulus
+///
+/// Uses binary exponentiation with 128-bit intermediate products, so it needs
+/// O(log exp) multiplications and never overflows. By convention the result
+/// for modulus 1 is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::mod_pow;
+///
+/// assert_eq!(mod_pow(3, 4, 7), 4); // 81 = 11·7 + 4
+/// assert_eq!(mod_pow(2, 0, 5), 1);
+///
+/// // Fermat's little theorem: aᵖ⁻¹ ≡ 1 (mod p)
+/// assert_eq!(mod_pow(123_456_789, 1_000_000_006, 1_000_000_007), 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    if modulus == 0 {
+        panic!("Modulus must be positive");
+    }
+
+    let m = modulus as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// Multiplicative inverse of `a` modulo `modulus`
+///
+/// Finds x with a·x ≡ 1 (mod modulus) by the extended Euclidean algorithm. The
+/// inverse exists exactly when gcd(a, modulus) = 1; otherwise `None` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::mod_inverse;
+///
+/// assert_eq!(mod_inverse(3, 7), Some(5)); // 3·5 = 15 ≡ 1
+/// assert_eq!(mod_inverse(4, 8), None);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        panic!("Modulus must be positive");
+    }
+
+    // Invariant: old_s·a ≡ old_r and s·a ≡ r (mod modulus)
+    let (mut old_r, mut r) = ((a % modulus) as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+
+    if old_r != 1 {
+        // gcd > 1, or modulus 1 where every residue is 0
+        return (modulus == 1).then_some(0);
+    }
+    Some(old_s.rem_euclid(modulus as i128) as u64)
+}
+
+/// Prime factorization of n as (prime, exponent) pairs in ascending order
+///
+/// Uses trial division by 2 and then odd candidates up to √n, so the cost is
+/// O(√p) where p is the second-largest prime factor — instant for numbers with
+/// small factors, but up to a few seconds for a product of two primes near 2³².
+/// By convention 1 has the empty factorization.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::factorize;
+///
+/// assert_eq!(factorize(360), [(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(97), [(97, 1)]);
+/// assert!(factorize(1).is_empty());
+/// ```
+///
+/// # Panics
+///
+/// Panics if n is zero.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    if n == 0 {
+        panic!("Cannot factorize zero");
+    }
+
+    let mut factors = Vec::new();
+    let mut p = 2;
+    // p <= n / p is p² <= n without overflowing
+    while p <= n / p {
+        if n.is_multiple_of(p) {
+            let mut exponent = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// Von Mangoldt function Λ(n)
+///
+/// Λ(n) = ln p when n = pᵏ is a power of a single prime p (k ≥ 1), and 0
+/// otherwise, including Λ(1) = 0. Summed over the divisors of n it gives ln n,
+/// which makes it the natural weight for counting primes.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::von_mangoldt;
+///
+/// assert_eq!(von_mangoldt(8), 2.0_f64.ln());
+/// assert_eq!(von_mangoldt(7), 7.0_f64.ln());
+/// assert_eq!(von_mangoldt(6), 0.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if n is zero.
+pub fn von_mangoldt(n: u64) -> f64 {
+    if n == 0 {
+        panic!("Von Mangoldt function undefined for n = 0");
+    }
+
+    match factorize(n).as_slice() {
+        [(p, _)] => (*p as f64).ln(),
+        _ => 0.0,
+    }
+}
+
+/// Second Chebyshev function ψ(x) = Σₙ≤ₓ Λ(n)
+///
+/// Equivalently ψ(x) = ln lcm(1, 2, ..., x). The prime number theorem is the
+/// statement ψ(x) ~ x.
+///
+/// The primes up to x are found with a sieve of Eratosthenes, and each prime p
+/// contributes ln p once for every power pᵏ ≤ x, so the cost is O(x log log x)
+/// time and O(x) memory.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::chebyshev_psi;
+///
+/// // lcm(1, ..., 10) = 2520
+/// assert!((chebyshev_psi(10) - 2520.0_f64.ln()).abs() < 1e-14);
+///
+/// // Prime number theorem: ψ(x)/x → 1
+/// let ratio = chebyshev_psi(100_000) / 100_000.0;
+/// assert!((ratio - 1.0).abs() < 0.01);
+/// ```
+pub fn chebyshev_psi(x: u64) -> f64 {
+    if x < 2 {
+        return 0.0;
+    }
+
+    let n = x as usize;
+    let mut composite = vec![false; n + 1];
+    let mut sum = 0.0;
+
+    for p in 2..=n {
+        if composite[p] {
+            continue;
+        }
+        for multiple in (p * p..=n).step_by(p) {
+            composite[multiple] = true;
+        }
+
+        // Number of powers pᵏ ≤ x
+        let mut count = 0;
+        let mut power = p;
+        loop {
+            count += 1;
+            match power.checked_mul(p) {
+                Some(next) if next <= n => power = next,
+                _ => break,
+            }
+        }
+        sum += count as f64 * (p as f64).ln();
+    }
+
+    sum
+}
+
+/// Largest trial divisor tried by [`prime_factors_detail`] before switching to
+/// Miller-Rabin and Pollard's rho; trial division alone settles every n < 2⁴⁰
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 20;
+
+/// Witnesses for which the Miller-Rabin test is exact for every n < 2⁶⁴
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Deterministic primality test
+///
+/// Runs the Miller-Rabin test with the first twelve primes as witnesses, which
+/// is known to have no false positives below 3.3·10²⁴, and so is exact for every
+/// `u64`. The cost is O(log³ n) regardless of the size of n's factors.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::is_prime;
+///
+/// assert!(is_prime(97));
+/// assert!(!is_prime(561)); // Carmichael number 3·11·17
+/// assert!(is_prime(18_446_744_073_709_551_557)); // largest prime below 2⁶⁴
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in MILLER_RABIN_BASES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    // n - 1 = d·2ˢ with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for a in MILLER_RABIN_BASES {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Evidence for the primality or compositeness of a number, see
+/// [`prime_factors_detail`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrimeProof {
+    /// The number is prime. No divisor up to `trial_bound` exists; if
+    /// `trial_bound` is below √n, the remaining range was ruled out by the
+    /// deterministic Miller-Rabin test of [`is_prime`]
+    Prime { trial_bound: u64 },
+    /// The number is composite, and `factor` is a divisor with
+    /// 1 < factor < n
+    Composite { factor: u64 },
+}
+
+/// Decides whether n is prime and returns the evidence
+///
+/// Instead of a bare `bool`, the result shows why: a composite number comes with
+/// an actual divisor, and a prime with the bound up to which trial division
+/// found none.
+///
+/// # Implementation
+///
+/// Trial division runs up to min(√n, 2²⁰). If it finds a divisor, that is the
+/// smallest prime factor of n. For n ≥ 2⁴⁰ that survive it, [`is_prime`]
+/// decides, and a composite n is then split by Pollard's rho algorithm, whose
+/// factor is not necessarily the smallest.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::{prime_factors_detail, PrimeProof};
+///
+/// assert_eq!(prime_factors_detail(97), PrimeProof::Prime { trial_bound: 9 });
+/// assert_eq!(prime_factors_detail(91), PrimeProof::Composite { factor: 7 });
+///
+/// // Two prime factors near 2³¹, out of reach of the trial division
+/// let n = 2_147_483_647 * 2_147_483_629;
+/// match prime_factors_detail(n) {
+///     PrimeProof::Composite { factor } => assert_eq!(n % factor, 0),
+///     PrimeProof::Prime { .. } => unreachable!(),
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if n < 2, which is neither prime nor composite.
+pub fn prime_factors_detail(n: u64) -> PrimeProof {
+    if n < 2 {
+        panic!("Primality is undefined for n < 2");
+    }
+
+    let root = n.isqrt();
+    let trial_bound = root.min(TRIAL_DIVISION_LIMIT);
+    let mut d = 2;
+    while d <= trial_bound {
+        if n.is_multiple_of(d) {
+            return PrimeProof::Composite { factor: d };
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+
+    if trial_bound == root || is_prime(n) {
+        PrimeProof::Prime { trial_bound }
+    } else {
+        PrimeProof::Composite {
+            factor: pollard_rho(n),
+        }
+    }
+}
+
+/// a·b mod m with a 128-bit intermediate product
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// A non-trivial factor of the odd composite n, by Pollard's rho algorithm with
+/// Brent's cycle detection
+///
+/// The pseudo-random sequence x ↦ x² + c (mod n) cycles modulo each prime factor
+/// p after about √p steps; a collision modulo p shows up as gcd(|x - y|, n) > 1.
+/// If a choice of c fails by finding n itself, the next c is tried.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let step = |x: u64| (mul_mod(x, x, n) + c) % n;
+        let (mut x, mut y): (u64, u64) = (2, 2);
+        let mut power = 1;
+        let mut length = 0;
+        loop {
+            y = step(y);
+            length += 1;
+            let divisor = gcd(x.abs_diff(y), n);
+            if divisor == n {
+                break;
+            }
+            if divisor > 1 {
+                return divisor;
+            }
+            // Brent: move the tortoise to the hare at every power of two
+            if length == power {
+                x = y;
+                power *= 2;
+                length = 0;
+            }
+        }
+    }
+    unreachable!("Pollard's rho tries every increment c")
+}
+
+/// Factorial n! = 1 · 2 · ... · n
+///
+/// By convention 0! = 1.
+///
+/// # Overflow
+///
+/// 21! already exceeds `u64::MAX`. For `n > 20` the multiplication overflows:
+/// debug builds panic and release builds silently wrap to a wrong result.
+/// Use [`factorial_checked`] when `n` is not known to be small.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::factorial;
+///
+/// assert_eq!(factorial(0), 1);
+/// assert_eq!(factorial(5), 120);
+/// ```
+pub fn factorial(n: u64) -> u64 {
+    (1..=n).product()
+}
+
+/// Checked factorial, returning `None` if n! does not fit in a `u64`
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::factorial_checked;
+///
+/// assert_eq!(factorial_checked(20), Some(2_432_902_008_176_640_000));
+/// assert_eq!(factorial_checked(21), None);
+/// ```
+pub fn factorial_checked(n: u64) -> Option<u64> {
+    (1..=n).try_fold(1u64, |acc, i| acc.checked_mul(i))
+}
+
+/// Binomial coefficient C(n, k) = n! / (k!(n-k)!)
+///
+/// Computed multiplicatively without forming the factorials, so intermediate
+/// values stay as small as the result allows. Returns 0 when `k > n`.
+///
+/// # Overflow
+///
+/// When C(n, k) itself exceeds `u64::MAX` (e.g. `binomial(68, 34)`) the
+/// multiplication overflows: debug builds panic and release builds silently wrap.
+/// Use [`binomial_checked`] for large arguments.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::binomial;
+///
+/// assert_eq!(binomial(5, 2), 10);
+/// assert_eq!(binomial(10, 0), 1);
+/// assert_eq!(binomial(3, 5), 0);
+/// ```
+pub fn binomial(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+
+    let k = k.min(n - k);
+    let mut result = 1;
+
+    for i in 0..k {
+        // result · (n - i) / (i + 1) is always an integer; dividing out the
+        // common factor first keeps the intermediate product small
+        let g = gcd(result, i + 1);
+        result = (result / g) * ((n - i) / ((i + 1) / g));
+    }
+
+    result
+}
+
+/// Checked binomial coefficient, returning `None` if C(n, k) does not fit in a `u64`
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::binomial_checked;
+///
+/// assert_eq!(binomial_checked(67, 33), Some(14_226_520_737_620_288_370));
+/// assert_eq!(binomial_checked(68, 34), None);
+/// ```
+pub fn binomial_checked(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    let mut result: u64 = 1;
+
+    for i in 0..k {
+        let g = gcd(result, i + 1);
+        result = (result / g).checked_mul((n - i) / ((i + 1) / g))?;
+    }
+
+    Some(result)
+}
+
+/// Number of integer partitions p(n): the ways of writing n as a sum of positive
+/// integers, ignoring order
+///
+/// Computed by the dynamic-programming recurrence that admits the parts 1, 2, ..., n
+/// one at a time, in O(n²) additions. Every intermediate value is bounded by p(n),
+/// so the computation overflows only when the result does. By convention p(0) = 1.
+///
+/// # Overflow
+///
+/// p(416) = 17 873 792 969 689 876 004 is the largest value that fits in a `u64`.
+/// For `n > 416` the addition overflows: debug builds panic and release builds
+/// silently wrap to a wrong result.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::partition_count;
+///
+/// // 4 = 3+1 = 2+2 = 2+1+1 = 1+1+1+1
+/// assert_eq!(partition_count(4), 5);
+/// assert_eq!(partition_count(50), 204_226);
+/// ```
+pub fn partition_count(n: u64) -> u64 {
+    let n = n as usize;
+    let mut counts = vec![0u64; n + 1];
+    counts[0] = 1;
+
+    for part in 1..=n {
+        for total in part..=n {
+            counts[total] += counts[total - part];
+        }
+    }
+
+    counts[n]
+}
+
+/// Farey sequence of order n: the reduced fractions in [0, 1] with denominator
+/// at most n, in ascending order
+///
+/// Each term follows from the previous two by the mediant recurrence: after a/b
+/// and c/d the next term is (k·c - a)/(k·d - b) with k = ⌊(n + b)/d⌋. No sorting
+/// or gcd reduction is needed, since neighbours in a Farey sequence satisfy
+/// b·c - a·d = 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::farey;
+///
+/// let terms: Vec<String> = farey(3).iter().map(|r| r.to_string()).collect();
+/// assert_eq!(terms, ["0/1", "1/3", "1/2", "2/3", "1/1"]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub fn farey(n: u64) -> Vec<Rational> {
+    if n == 0 {
+        panic!("Farey sequence requires an order of at least one");
+    }
+
+    let (mut a, mut b, mut c, mut d) = (0, 1, 1, n);
+    let mut terms = vec![Rational::new(0, 1)];
+
+    while c <= n {
+        terms.push(Rational::new(c as i64, d));
+        let k = (n + b) / d;
+        (a, b, c, d) = (c, d, k * c - a, k * d - b);
+    }
+
+    terms
+}
+
+/// Falling factorial n·(n-1)···(n-k+1), the number of permutations P(n, k)
+///
+/// Equal to n!/(n-k)!, but computed from the k factors directly so it does not
+/// overflow just because n! does. Returns 1 for `k = 0` and 0 when `k > n`.
+///
+/// # Overflow
+///
+/// When the result itself exceeds `u64::MAX` the multiplication overflows: debug
+/// builds panic and release builds silently wrap to a wrong result.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::falling_factorial;
+///
+/// assert_eq!(falling_factorial(5, 2), 20);
+/// // 100!/98! without forming 100!
+/// assert_eq!(falling_factorial(100, 2), 9_900);
+/// ```
+pub fn falling_factorial(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    ((n - k)..n).map(|i| i + 1).product()
+}
+
+/// Rising factorial (Pochhammer symbol) (x)ₖ = x·(x+1)···(x+k-1)
+///
+/// The building block of hypergeometric series. (x)₀ = 1 and (1)ₖ = k!.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::rising_factorial;
+///
+/// assert_eq!(rising_factorial(1.0, 5), 120.0);
+/// assert_eq!(rising_factorial(0.5, 2), 0.75);
+/// ```
+pub fn rising_factorial(x: f64, k: u32) -> f64 {
+    (0..k).map(|i| x + i as f64).product()
+}
+
+/// Above this index [`harmonic`] switches from summation to the digamma function
+const HARMONIC_SUM_LIMIT: u64 = 1_000;
+
+/// Harmonic number Hₙ = 1 + 1/2 + ... + 1/n
+///
+/// H₀ = 0. Up to n = 1000 the terms are summed directly, smallest first; beyond
+/// that the sum is evaluated as [`harmonic_real`], which is accurate to rounding
+/// there and does not cost n operations.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::harmonic;
+///
+/// assert_eq!(harmonic(1), 1.0);
+/// assert!((harmonic(4) - 25.0 / 12.0).abs() < 1e-15);
+/// ```
+pub fn harmonic(n: u64) -> f64 {
+    if n > HARMONIC_SUM_LIMIT {
+        return harmonic_real(n as f64);
+    }
+    (1..=n).rev().map(|k| 1.0 / k as f64).sum()
+}
+
+/// Harmonic number of a real argument, H(x) = ψ(x + 1) + γ
+///
+/// Interpolates the harmonic numbers through the digamma function ψ, so that
+/// H(n) = Hₙ at the integers and H(x) = H(x - 1) + 1/x everywhere.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::harmonic_real;
+///
+/// // H(1/2) = 2 - 2 ln 2
+/// assert!((harmonic_real(0.5) - (2.0 - 2.0 * 2.0_f64.ln())).abs() < 1e-15);
+/// ```
+///
+/// # Panics
+///
+/// Panics if x is a negative integer, where ψ(x + 1) has a pole.
+pub fn harmonic_real(x: f64) -> f64 {
+    digamma(x + 1.0) + EULER_MASCHERONI
+}
+
+/// Euclidean norm √(a² + b²) without intermediate overflow or underflow
+///
+/// Squaring directly overflows once |a| or |b| exceeds about 1.3e154 and underflows
+/// to zero below about 1.5e-154, even when the result is representable. Factoring
+/// out the larger magnitude avoids both:
+///
+/// √(a² + b²) = |max| · √(1 + (min/max)²)
+///
+/// An infinite argument gives infinity, even if the other one is NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::hypot;
+///
+/// assert_eq!(hypot(3.0, 4.0), 5.0);
+///
+/// // (1e200)² overflows, the result does not
+/// assert!((hypot(1e200, 1e200) / 1e200 - 2.0_f64.sqrt()).abs() < 1e-15);
+/// ```
+pub fn hypot(a: f64, b: f64) -> f64 {
+    if a.is_infinite() || b.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    let (a, b) = (a.abs(), b.abs());
+    let (large, small) = if a >= b { (a, b) } else { (b, a) };
+    if large == 0.0 || large.is_nan() || small.is_nan() {
+        return large + small;
+    }
+
+    let ratio = small / large;
+    large * (1.0 + ratio * ratio).sqrt()
+}
+
+/// Euclidean distance between two points in the plane
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::distance2;
+///
+/// assert_eq!(distance2(&[1.0, 1.0], &[4.0, 5.0]), 5.0);
+/// ```
+pub fn distance2(p: &[f64; 2], q: &[f64; 2]) -> f64 {
+    hypot(q[0] - p[0], q[1] - p[1])
+}
+
+/// Euclidean distance between two points in space
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::distance3;
+///
+/// assert_eq!(distance3(&[0.0, 0.0, 0.0], &[2.0, 3.0, 6.0]), 7.0);
+/// ```
+pub fn distance3(p: &[f64; 3], q: &[f64; 3]) -> f64 {
+    let planar = hypot(q[0] - p[0], q[1] - p[1]);
+    hypot(planar, q[2] - p[2])
+}
+
+/// `n` evenly spaced points from `start` to `end`, both included
+///
+/// Each point is computed independently as start + i·(end - start)/(n - 1), so
+/// rounding errors do not accumulate along the sequence, and the last point is
+/// exactly `end`. For `n = 1` the result is `[start]`; for `n = 0` it is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::linspace;
+///
+/// assert_eq!(linspace(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// assert_eq!(linspace(2.0, 3.0, 1), vec![2.0]);
+/// ```
+pub fn linspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    match n {
+        0 => Vec::new(),
+        1 => vec![start],
+        _ => {
+            let step = (end - start) / (n - 1) as f64;
+            let mut points: Vec<f64> = (0..n).map(|i| start + i as f64 * step).collect();
+            points[n - 1] = end;
+            points
+        }
+    }
+}
+
+/// Evaluates `f` at `n` evenly spaced points, returning `(x, f(x))` pairs
+///
+/// The points are those of [`linspace`], which makes this convenient for plotting
+/// and tabulating functions.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::sample;
+///
+/// let table = sample(0.0, 2.0, 3, |x| x * x);
+/// assert_eq!(table, vec![(0.0, 0.0), (1.0, 1.0), (2.0, 4.0)]);
+/// ```
+pub fn sample<F>(start: f64, end: f64, n: usize, f: F) -> Vec<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    linspace(start, end, n)
+        .into_iter()
+        .map(|x| (x, f(x)))
+        .collect()
+}
+
+/// `n` geometrically spaced points from `start` to `end`, both included
+///
+/// Consecutive points have a constant ratio (end/start)^(1/(n - 1)), i.e. they are
+/// evenly spaced on a logarithmic axis.
+///
+/// The points are computed independently as 10 raised to evenly spaced exponents
+/// between log₁₀(start) and log₁₀(end), so exact powers of ten come out exactly,
+/// and the endpoints are set to `start` and `end` exactly. For `n = 1` the result
+/// is `[start]`; for `n = 0` it is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::geomspace;
+///
+/// assert_eq!(geomspace(1.0, 1000.0, 4), vec![1.0, 10.0, 100.0, 1000.0]);
+/// assert_eq!(geomspace(100.0, 1.0, 3), vec![100.0, 10.0, 1.0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics unless `start` and `end` are both positive.
+pub fn geomspace(start: f64, end: f64, n: usize) -> Vec<f64> {
+    if start.is_nan() || end.is_nan() || start <= 0.0 || end <= 0.0 {
+        panic!("Geometric spacing requires positive endpoints");
+    }
+
+    let mut points: Vec<f64> = linspace(start.log10(), end.log10(), n)
+        .into_iter()
+        .map(|exponent| 10f64.powf(exponent))
+        .collect();
+    if let Some(first) = points.first_mut() {
+        *first = start;
+    }
+    if n > 1 {
+        points[n - 1] = end;
+    }
+    points
+}
+
+/// Sign of a real number as -1, 0 or 1
+///
+/// Unlike [`f64::signum`], which returns `1.0` for `+0.0` and `-1.0` for `-0.0`,
+/// both zeros map to 0. NaN has no sign and also maps to 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::signum_strict;
+///
+/// assert_eq!(signum_strict(2.5), 1);
+/// assert_eq!(signum_strict(-1e-300), -1);
+/// assert_eq!(signum_strict(0.0), 0);
+/// assert_eq!(signum_strict(-0.0), 0);
+/// ```
+pub fn signum_strict(x: f64) -> i32 {
+    if x > 0.0 {
+        1
+    } else if x < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Whether `a` and `b` are both strictly positive or both strictly negative
+///
+/// Bracketing root finders use `!same_sign(f(a), f(b))` to detect a sign change.
+/// Testing `f(a) * f(b) < 0` instead is unreliable: the product of two tiny values
+/// underflows to zero and hides the sign change.
+///
+/// A zero is not considered to share a sign with anything, since it is already a
+/// root. NaN has no sign, so `same_sign` is `false` whenever either argument is
+/// NaN; callers that must reject NaN should test for it separately.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::numbers::same_sign;
+///
+/// assert!(same_sign(3.0, 1e-300));
+/// assert!(!same_sign(1e-200, -1e-200)); // the product underflows to -0.0
+/// assert!(!same_sign(0.0, 5.0));
+/// ```
+pub fn same_sign(a: f64, b: f64) -> bool {
+    let (sa, sb) = (signum_strict(a), signum_strict(b));
+    sa != 0 && sa == sb
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(3, 4, 7), 4);
+        assert_eq!(mod_pow(0, 0, 7), 1);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+
+        // Products near u64::MAX would overflow without 128-bit intermediates
+        let p = 18_446_744_073_709_551_557; // largest prime below 2⁶⁴
+        assert_eq!(mod_pow(2, p - 1, p), 1);
+        assert_eq!(mod_pow(p - 1, 2, p), 1);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        for m in [2u64, 7, 12, 97, 1_000_000_007] {
+            for a in 0..50 {
+                match mod_inverse(a, m) {
+                    Some(x) => assert_eq!((a as u128 * x as u128 % m as u128), 1 % m as u128),
+                    None => assert_ne!(gcd(a, m), 1),
+                }
+            }
+        }
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(1), []);
+        assert_eq!(factorize(2), [(2, 1)]);
+        assert_eq!(factorize(1024), [(2, 10)]);
+        assert_eq!(
+            factorize(600_851_475_143),
+            [(71, 1), (839, 1), (1471, 1), (6857, 1)]
+        );
+        assert_eq!(factorize(1_000_000_007), [(1_000_000_007, 1)]);
+
+        for n in 1..2000 {
+            let product: u64 = factorize(n).iter().map(|&(p, k)| p.pow(k)).product();
+            assert_eq!(product, n);
+        }
+    }
+
+    #[test]
+    fn test_von_mangoldt() {
+        assert_eq!(von_mangoldt(1), 0.0);
+        assert_eq!(von_mangoldt(8), 2.0_f64.ln());
+        assert_eq!(von_mangoldt(6), 0.0);
+        assert_eq!(von_mangoldt(7), 7.0_f64.ln());
+        assert_eq!(von_mangoldt(81), 3.0_f64.ln());
+        assert_eq!(von_mangoldt(1 << 63), 2.0_f64.ln());
+
+        // Σ_{d | n} Λ(d) = ln n
+        for n in 1..300u64 {
+            let sum: f64 = (1..=n)
+                .filter(|d| n.is_multiple_of(*d))
+                .map(von_mangoldt)
+                .sum();
+            assert!((sum - (n as f64).ln()).abs() < 1e-12, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_psi() {
+        assert_eq!(chebyshev_psi(0), 0.0);
+        assert_eq!(chebyshev_psi(1), 0.0);
+        assert_eq!(chebyshev_psi(2), 2.0_f64.ln());
+
+        // Agrees with the direct sum of Λ(n)
+        let direct: f64 = (1..=500).map(von_mangoldt).sum();
+        assert!((chebyshev_psi(500) - direct).abs() < 1e-10);
+
+        // Prime number theorem: ψ(x)/x → 1
+        for x in [10_000, 100_000, 1_000_000] {
+            let ratio = chebyshev_psi(x) / x as f64;
+            assert!((ratio - 1.0).abs() < 0.02, "ψ({x})/{x} = {ratio}");
+        }
+    }
+
+    #[test]
+    fn test_is_prime_matches_trial_division() {
+        for n in 0..5000u64 {
+            let by_trial = n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert_eq!(is_prime(n), by_trial, "n = {n}");
+        }
+
+        assert!(is_prime((1 << 61) - 1));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        // Strong pseudoprimes to several small bases
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+    }
+
+    #[test]
+    fn test_prime_factors_detail_small() {
+        assert_eq!(
+            prime_factors_detail(2),
+            PrimeProof::Prime { trial_bound: 1 }
+        );
+        assert_eq!(
+            prime_factors_detail(97),
+            PrimeProof::Prime { trial_bound: 9 }
+        );
+        assert_eq!(
+            prime_factors_detail(91),
+            PrimeProof::Composite { factor: 7 }
+        );
+        assert_eq!(
+            prime_factors_detail(561),
+            PrimeProof::Composite { factor: 3 }
+        );
+        assert_eq!(
+            prime_factors_detail(1_000_000_007),
+            PrimeProof::Prime {
+                trial_bound: 31_622
+            }
+        );
+    }
+
+    #[test]
+    fn test_prime_factors_detail_large() {
+        assert_eq!(
+            prime_factors_detail(18_446_744_073_709_551_557),
+            PrimeProof::Prime {
+                trial_bound: TRIAL_DIVISION_LIMIT
+            }
+        );
+
+        // Both factors lie beyond the trial division limit, so Pollard's rho runs
+        let (p, q) = (2_147_483_647, 1_000_000_007);
+        match prime_factors_detail(p * q) {
+            PrimeProof::Composite { factor } => assert!(factor == p || factor == q),
+            proof => panic!("expected a factor, got {proof:?}"),
+        }
+
+        // A square of a prime
+        let p = 4_294_967_291;
+        assert_eq!(
+            prime_factors_detail(p * p),
+            PrimeProof::Composite { factor: p }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Primality is undefined for n < 2")]
+    fn test_prime_factors_detail_one() {
+        prime_factors_detail(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot factorize zero")]
+    fn test_factorize_zero() {
+        factorize(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Modulus must be positive")]
+    fn test_mod_pow_zero_modulus() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(gcd(18, 48), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(7, 0), 7);
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), 1);
+        assert_eq!(factorial(1), 1);
+        assert_eq!(factorial(10), 3_628_800);
+    }
+
+    #[test]
+    fn test_factorial_checked() {
+        // 20! is the largest factorial that fits in a u64
+        assert_eq!(factorial_checked(20), Some(factorial(20)));
+        assert_eq!(factorial_checked(21), None);
+        assert_eq!(factorial_checked(0), Some(1));
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2), 10);
+        assert_eq!(binomial(5, 3), 10);
+        assert_eq!(binomial(52, 5), 2_598_960);
+        assert_eq!(binomial(4, 7), 0);
+
+        // Pascal's rule
+        for n in 1..30 {
+            for k in 1..n {
+                assert_eq!(binomial(n, k), binomial(n - 1, k - 1) + binomial(n - 1, k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_binomial_checked() {
+        assert_eq!(binomial_checked(52, 5), Some(2_598_960));
+        assert_eq!(binomial_checked(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(binomial_checked(68, 34), None);
+        assert_eq!(binomial_checked(3, 5), Some(0));
+    }
+
+    #[test]
+    fn test_partition_count() {
+        let expected = [1, 1, 2, 3, 5, 7, 11, 15, 22, 30, 42];
+        for (n, &p) in expected.iter().enumerate() {
+            assert_eq!(partition_count(n as u64), p);
+        }
+        assert_eq!(partition_count(50), 204_226);
+        assert_eq!(partition_count(100), 190_569_292);
+    }
+
+    #[test]
+    fn test_partition_count_largest_u64() {
+        assert_eq!(partition_count(416), 17_873_792_969_689_876_004);
+    }
+
+    #[test]
+    fn test_farey_small_orders() {
+        assert_eq!(farey(1), [Rational::new(0, 1), Rational::new(1, 1)]);
+
+        let expected = [(0, 1), (1, 3), (1, 2), (2, 3), (1, 1)];
+        let terms = farey(3);
+        assert_eq!(terms.len(), expected.len());
+        for (r, (p, q)) in terms.iter().zip(expected) {
+            assert_eq!((r.numer(), r.denom()), (p, q));
+        }
+    }
+
+    #[test]
+    fn test_farey_terms_reduced_and_ascending() {
+        let n = 12;
+        let terms = farey(n);
+
+        for r in &terms {
+            assert_eq!(gcd(r.numer() as u64, r.denom()), 1);
+            assert!(r.denom() <= n);
+        }
+        for pair in terms.windows(2) {
+            assert!(pair[0] < pair[1]);
+            // Neighbours differ by exactly 1/(b·d)
+            let det =
+                pair[1].numer() * pair[0].denom() as i64 - pair[0].numer() * pair[1].denom() as i64;
+            assert_eq!(det, 1);
+        }
+
+        // |F_n| = 1 + Σ φ(q) for q ≤ n, which is 47 for n = 12
+        assert_eq!(terms.len(), 47);
+    }
+
+    #[test]
+    #[should_panic(expected = "Farey sequence requires an order of at least one")]
+    fn test_farey_zero_order() {
+        farey(0);
+    }
+
+    #[test]
+    fn test_falling_factorial() {
+        assert_eq!(falling_factorial(5, 2), 20);
+        assert_eq!(falling_factorial(5, 5), 120);
+        assert_eq!(falling_factorial(3, 4), 0);
+        for n in 0..10 {
+            assert_eq!(falling_factorial(n, 0), 1);
+        }
+
+        // Fits even though 10⁹! is far beyond u64
+        assert_eq!(falling_factorial(1_000_000_000, 2), 999_999_999_000_000_000);
+
+        // C(n, k) = P(n, k) / k!
+        for n in 0..20 {
+            for k in 0..=n {
+                assert_eq!(falling_factorial(n, k) / factorial(k), binomial(n, k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rising_factorial() {
+        assert_eq!(rising_factorial(1.0, 5), 120.0);
+        assert_eq!(rising_factorial(3.7, 0), 1.0);
+        assert_eq!(rising_factorial(-2.0, 3), 0.0);
+
+        // (x)ₖ = (x+k-1)ₖ falling, e.g. (4)₃ = 4·5·6 = P(6, 3)
+        assert_eq!(rising_factorial(4.0, 3), falling_factorial(6, 3) as f64);
+    }
+
+    #[test]
+    fn test_harmonic() {
+        assert_eq!(harmonic(0), 0.0);
+        assert_eq!(harmonic(1), 1.0);
+        assert_eq!(harmonic(2), 1.5);
+        assert!((harmonic(4) - 25.0 / 12.0).abs() < 1e-15);
+
+        // Both sides of the switch to the digamma evaluation agree
+        let below = harmonic(HARMONIC_SUM_LIMIT);
+        let above = harmonic(HARMONIC_SUM_LIMIT + 1);
+        assert!((above - below - 1.0 / (HARMONIC_SUM_LIMIT + 1) as f64).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_harmonic_real() {
+        for n in 0..=50 {
+            let (exact, real) = (harmonic(n), harmonic_real(n as f64));
+            assert!((exact - real).abs() < 1e-14 * exact.max(1.0), "n = {n}");
+        }
+
+        // H(x) = H(x - 1) + 1/x away from the integers
+        for x in [0.25, 0.5, 1.7, 3.3, 12.9, -0.5] {
+            let recurrence = harmonic_real(x - 1.0) + 1.0 / x;
+            assert!((harmonic_real(x) - recurrence).abs() < 1e-13, "x = {x}");
+        }
+
+        assert!((harmonic_real(0.5) - (2.0 - 2.0 * 2.0_f64.ln())).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_hypot() {
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+        assert_eq!(hypot(-5.0, 12.0), 13.0);
+        assert_eq!(hypot(0.0, 0.0), 0.0);
+        assert_eq!(hypot(0.0, -2.5), 2.5);
+
+        // Naive squaring overflows to infinity and underflows to zero
+        assert!((1e200_f64 * 1e200).is_infinite());
+        assert!((hypot(1e200, 1e200) / 1e200 - 2.0_f64.sqrt()).abs() < 1e-15);
+        assert_eq!(1e-200_f64 * 1e-200, 0.0);
+        assert!((hypot(3e-200, 4e-200) / 1e-200 - 5.0).abs() < 1e-14);
+
+        assert_eq!(hypot(f64::INFINITY, f64::NAN), f64::INFINITY);
+        assert!(hypot(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_distances() {
+        assert_eq!(distance2(&[0.0, 0.0], &[3.0, 4.0]), 5.0);
+        assert_eq!(distance2(&[-1.0, 2.0], &[-1.0, 2.0]), 0.0);
+        assert_eq!(distance3(&[1.0, 2.0, 3.0], &[3.0, 5.0, 9.0]), 7.0);
+        assert_eq!(distance3(&[0.0, 0.0, 0.0], &[1.0, 4.0, 8.0]), 9.0);
+
+        // Symmetric in its arguments
+        let (p, q) = ([0.3, -1.7, 2.2], [4.1, 0.5, -3.3]);
+        assert_eq!(distance3(&p, &q), distance3(&q, &p));
+    }
+
+    #[test]
+    fn test_linspace() {
+        assert_eq!(linspace(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(linspace(1.0, -1.0, 3), vec![1.0, 0.0, -1.0]);
+        assert_eq!(linspace(0.0, 1.0, 1), vec![0.0]);
+        assert!(linspace(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_linspace_exact_end() {
+        // Repeatedly adding 0.1 drifts away from 0.7; linspace ends exactly there
+        let points = linspace(0.1, 0.7, 7);
+        assert_eq!(points.len(), 7);
+        assert_eq!(points[0], 0.1);
+        assert_eq!(points[6], 0.7);
+        assert!((points[3] - 0.4).abs() < 1e-16);
+    }
+
+    #[test]
+    fn test_sample() {
+        let table = sample(-1.0, 1.0, 9, |x| x.exp());
+        let xs = linspace(-1.0, 1.0, 9);
+        assert_eq!(table.len(), 9);
+        for ((x, y), expected_x) in table.into_iter().zip(xs) {
+            assert_eq!(x, expected_x);
+            assert_eq!(y, x.exp());
+        }
+    }
+
+    #[test]
+    fn test_geomspace() {
+        assert_eq!(geomspace(1.0, 1000.0, 4), vec![1.0, 10.0, 100.0, 1000.0]);
+        assert_eq!(geomspace(1e-3, 1e3, 7)[3], 1.0);
+        assert_eq!(geomspace(5.0, 7.0, 1), vec![5.0]);
+        assert!(geomspace(5.0, 7.0, 0).is_empty());
+
+        // Constant ratio between consecutive points
+        let points = geomspace(2.0, 2.0 * 3.0_f64.powi(8), 9);
+        for pair in points.windows(2) {
+            assert!((pair[1] / pair[0] - 3.0).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_geomspace_exact_endpoints() {
+        let points = geomspace(0.3, 7.1, 13);
+        assert_eq!(points[0], 0.3);
+        assert_eq!(points[12], 7.1);
+
+        // Descending ranges keep their endpoints too
+        let descending = geomspace(7.1, 0.3, 13);
+        assert_eq!(descending[0], 7.1);
+        assert_eq!(descending[12], 0.3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Geometric spacing requires positive endpoints")]
+    fn test_geomspace_zero_endpoint() {
+        geomspace(0.0, 10.0, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Geometric spacing requires positive endpoints")]
+    fn test_geomspace_sign_crossing() {
+        geomspace(-1.0, 10.0, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Geometric spacing requires positive endpoints")]
+    fn test_geomspace_negative_endpoints() {
+        geomspace(-1.0, -100.0, 3);
+    }
+
+    #[test]
+    fn test_signum_strict() {
+        assert_eq!(signum_strict(0.0), 0);
+        assert_eq!(signum_strict(-0.0), 0);
+        assert_eq!(signum_strict(f64::MIN_POSITIVE / 2.0), 1);
+        assert_eq!(signum_strict(-1e300), -1);
+        assert_eq!(signum_strict(f64::INFINITY), 1);
+        assert_eq!(signum_strict(f64::NEG_INFINITY), -1);
+        assert_eq!(signum_strict(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_same_sign() {
+        assert!(same_sign(1.0, 2.0));
+        assert!(same_sign(-1.0, -1e-300));
+        assert!(!same_sign(1.0, -2.0));
+
+        // Tiny positive and huge negative
+        assert!(!same_sign(1e-300, -1e300));
+
+        // The product of these underflows to -0.0, so `a * b < 0.0` misses the change
+        let (a, b) = (1e-200, -1e-200);
+        assert_eq!(a * b, 0.0);
+        assert!(!same_sign(a, b));
+
+        // Zeros and NaN never share a sign
+        assert!(!same_sign(0.0, 0.0));
+        assert!(!same_sign(-0.0, -1.0));
+        assert!(!same_sign(f64::NAN, 1.0));
+        assert!(!same_sign(f64::NAN, f64::NAN));
+    }
+}
@@ -0,0 +1,310 @@
+//! # Polynomials
+//!
+//! A dense polynomial type with real coefficients.
+
+use crate::base::complex::Complex64;
+
+/// A polynomial p(x) = c₀ + c₁x + c₂x² + ... + cₙxⁿ with real coefficients
+///
+/// Coefficients are stored in ascending order of degree, so `coeffs()[k]`
+/// multiplies xᵏ. Trailing zero coefficients are trimmed on construction, which
+/// keeps [`degree`](Polynomial::degree) and equality comparisons meaningful.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::poly::Polynomial;
+///
+/// // p(x) = 1 - 3x + 2x²
+/// let p = Polynomial::new(vec![1.0, -3.0, 2.0]);
+///
+/// assert_eq!(p.degree(), 2);
+/// assert_eq!(p.eval(2.0), 3.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coeffs: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a polynomial from coefficients in ascending order of degree
+    ///
+    /// Trailing zeros are removed; an empty or all-zero vector gives the zero polynomial.
+    pub fn new(mut coeffs: Vec<f64>) -> Self {
+        while coeffs.last() == Some(&0.0) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    /// Builds the monic polynomial Π(x - rᵢ) with the given real roots
+    ///
+    /// Repeated roots are allowed and give roots of higher multiplicity.
+    /// An empty slice gives the constant polynomial 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::poly::Polynomial;
+    ///
+    /// // (x - 1)(x + 1) = x² - 1
+    /// let p = Polynomial::from_roots(&[1.0, -1.0]);
+    /// assert_eq!(p, Polynomial::new(vec![-1.0, 0.0, 1.0]));
+    /// ```
+    pub fn from_roots(roots: &[f64]) -> Self {
+        let mut coeffs = vec![1.0];
+
+        for &r in roots {
+            // Multiply the current product by (x - r)
+            let mut next = vec![0.0; coeffs.len() + 1];
+            for (k, &c) in coeffs.iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= r * c;
+            }
+            coeffs = next;
+        }
+
+        Polynomial::new(coeffs)
+    }
+
+    /// Builds the monic polynomial Π(z - rᵢ) from complex roots
+    ///
+    /// The product is expanded in complex arithmetic and only the real parts of
+    /// the coefficients are kept. This is exact when every non-real root is
+    /// accompanied by its conjugate, in which case the imaginary parts cancel up
+    /// to rounding error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use sophy::base::poly::Polynomial;
+    ///
+    /// // (x - i)(x + i) = x² + 1
+    /// let p = Polynomial::from_roots_complex(&[Complex64::I, -Complex64::I]);
+    /// assert_eq!(p, Polynomial::new(vec![1.0, 0.0, 1.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the roots do not come in conjugate pairs, since the resulting
+    /// polynomial would have non-real coefficients.
+    pub fn from_roots_complex(roots: &[Complex64]) -> Self {
+        let mut coeffs = vec![Complex64::ONE];
+
+        for &r in roots {
+            let mut next = vec![Complex64::ZERO; coeffs.len() + 1];
+            for (k, &c) in coeffs.iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= r * c;
+            }
+            coeffs = next;
+        }
+
+        let scale = coeffs.iter().map(|c| c.norm()).fold(1.0, f64::max);
+        if coeffs.iter().any(|c| c.im.abs() > 1e-10 * scale) {
+            panic!("Roots must come in conjugate pairs to give real coefficients");
+        }
+
+        Polynomial::new(coeffs.iter().map(|c| c.re).collect())
+    }
+
+    /// Coefficients in ascending order of degree
+    pub fn coeffs(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    /// Degree of the polynomial
+    ///
+    /// The zero polynomial is reported as degree 0.
+    pub fn degree(&self) -> usize {
+        self.coeffs.len().saturating_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's scheme
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coeffs.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    /// Evaluates the polynomial at a complex argument using Horner's scheme
+    ///
+    /// Useful for checking roots returned by the complex solvers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use sophy::base::poly::Polynomial;
+    ///
+    /// // x² + 1 vanishes at i
+    /// let p = Polynomial::new(vec![1.0, 0.0, 1.0]);
+    /// assert_eq!(p.eval_complex(Complex64::I), Complex64::ZERO);
+    /// ```
+    pub fn eval_complex(&self, z: Complex64) -> Complex64 {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(Complex64::ZERO, |acc, &c| acc * z + Complex64::new(c, 0.0))
+    }
+
+    /// Evaluates the derivative p'(z) at a complex argument
+    ///
+    /// Horner's scheme is differentiated alongside the value, so the derivative
+    /// polynomial is never formed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::complex::Complex64;
+    /// use sophy::base::poly::Polynomial;
+    ///
+    /// // d/dz (z² + 1) = 2z
+    /// let p = Polynomial::new(vec![1.0, 0.0, 1.0]);
+    /// assert_eq!(p.eval_derivative_complex(Complex64::I), Complex64::new(0.0, 2.0));
+    /// ```
+    pub fn eval_derivative_complex(&self, z: Complex64) -> Complex64 {
+        let (_, derivative) = self.coeffs.iter().rev().fold(
+            (Complex64::ZERO, Complex64::ZERO),
+            |(value, derivative), &c| (value * z + Complex64::new(c, 0.0), derivative * z + value),
+        );
+        derivative
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::aberth::aberth;
+
+    #[test]
+    fn test_polynomial_trims_trailing_zeros() {
+        let p = Polynomial::new(vec![1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(p.coeffs(), &[1.0, 2.0]);
+        assert_eq!(p.degree(), 1);
+        assert_eq!(Polynomial::new(vec![0.0]).degree(), 0);
+    }
+
+    #[test]
+    fn test_from_roots() {
+        // (x - 1)(x + 1) = x² - 1
+        assert_eq!(
+            Polynomial::from_roots(&[1.0, -1.0]),
+            Polynomial::new(vec![-1.0, 0.0, 1.0])
+        );
+
+        // (x - 2)² = x² - 4x + 4
+        assert_eq!(
+            Polynomial::from_roots(&[2.0, 2.0]),
+            Polynomial::new(vec![4.0, -4.0, 1.0])
+        );
+
+        assert_eq!(Polynomial::from_roots(&[]), Polynomial::new(vec![1.0]));
+    }
+
+    #[test]
+    fn test_from_roots_vanishes_at_roots() {
+        let roots = [-3.0, 0.5, 1.25, 4.0];
+        let p = Polynomial::from_roots(&roots);
+        assert_eq!(p.degree(), 4);
+        for r in roots {
+            assert!(p.eval(r).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_from_roots_complex() {
+        // (x - 1)(x - (1 + 2i))(x - (1 - 2i)) = x³ - 3x² + 7x - 5
+        let roots = [
+            Complex64::new(1.0, 0.0),
+            Complex64::new(1.0, 2.0),
+            Complex64::new(1.0, -2.0),
+        ];
+        let p = Polynomial::from_roots_complex(&roots);
+        assert_eq!(p, Polynomial::new(vec![-5.0, 7.0, -3.0, 1.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Roots must come in conjugate pairs to give real coefficients")]
+    fn test_from_roots_complex_unpaired() {
+        Polynomial::from_roots_complex(&[Complex64::I]);
+    }
+
+    #[test]
+    fn test_eval_complex() {
+        let p = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        assert_eq!(p.eval_complex(Complex64::I), Complex64::ZERO);
+        assert_eq!(p.eval_complex(-Complex64::I), Complex64::ZERO);
+        // (1 + i)² + 1 = 1 + 2i
+        assert_eq!(
+            p.eval_complex(Complex64::new(1.0, 1.0)),
+            Complex64::new(1.0, 2.0)
+        );
+
+        // Real arguments reproduce eval
+        let p = Polynomial::new(vec![-5.0, 7.0, -3.0, 1.0]);
+        for x in [-2.0, -0.5, 0.0, 1.5, 3.0] {
+            assert_eq!(
+                p.eval_complex(Complex64::new(x, 0.0)),
+                Complex64::new(p.eval(x), 0.0)
+            );
+        }
+
+        assert_eq!(
+            Polynomial::new(vec![]).eval_complex(Complex64::I),
+            Complex64::ZERO
+        );
+    }
+
+    #[test]
+    fn test_eval_derivative_complex() {
+        // p = x³ - 3x² + 7x - 5, p' = 3x² - 6x + 7
+        let p = Polynomial::new(vec![-5.0, 7.0, -3.0, 1.0]);
+        let dp = Polynomial::new(vec![7.0, -6.0, 3.0]);
+        for x in [-2.0, -0.5, 0.0, 1.5, 3.0] {
+            let z = Complex64::new(x, 0.0);
+            assert_eq!(
+                p.eval_derivative_complex(z),
+                Complex64::new(dp.eval(x), 0.0)
+            );
+        }
+
+        // Agrees with the derivative polynomial at complex points too
+        let z = Complex64::new(0.5, -1.5);
+        assert!((p.eval_derivative_complex(z) - dp.eval_complex(z)).norm() < 1e-14);
+
+        // Constants have zero derivative
+        let c = Polynomial::new(vec![4.0]);
+        assert_eq!(c.eval_derivative_complex(Complex64::I), Complex64::ZERO);
+    }
+
+    #[test]
+    fn test_eval_complex_at_aberth_roots() {
+        let p = Polynomial::from_roots_complex(&[
+            Complex64::new(1.0, 2.0),
+            Complex64::new(1.0, -2.0),
+            Complex64::new(-0.5, 0.0),
+        ]);
+        let coeffs: Vec<Complex64> = p.coeffs().iter().map(|&c| Complex64::new(c, 0.0)).collect();
+        for root in aberth(&coeffs, 1e-14, 200) {
+            assert!(p.eval_complex(root).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_roots_round_trip() {
+        let roots = [-2.5, -1.0, 0.75, 3.0];
+        let p = Polynomial::from_roots(&roots);
+
+        let coeffs: Vec<Complex64> = p.coeffs().iter().map(|&c| Complex64::new(c, 0.0)).collect();
+        let found = aberth(&coeffs, 1e-14, 200);
+
+        assert_eq!(found.len(), roots.len());
+        for r in roots {
+            assert!(
+                found
+                    .iter()
+                    .any(|z| (*z - Complex64::new(r, 0.0)).norm() < 1e-10)
+            );
+        }
+    }
+}
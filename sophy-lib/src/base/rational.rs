@@ -0,0 +1,129 @@
+//! # Rational Numbers
+//!
+//! An exact fraction type with a 64-bit numerator and denominator, kept in
+//! lowest terms.
+
+use crate::base::numbers::gcd;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A fraction p/q in lowest terms with a positive denominator
+///
+/// The sign is carried by the numerator and common factors are removed on
+/// construction, so equal values always have equal representations.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::rational::Rational;
+///
+/// let r = Rational::new(6, 8);
+/// assert_eq!((r.numer(), r.denom()), (3, 4));
+/// assert_eq!(r.to_string(), "3/4");
+/// assert!(Rational::new(1, 3) < Rational::new(1, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i64,
+    denom: u64,
+}
+
+impl Rational {
+    /// Creates the fraction `numer/denom` reduced to lowest terms
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is zero.
+    pub fn new(numer: i64, denom: u64) -> Self {
+        if denom == 0 {
+            panic!("Rational number requires a nonzero denominator");
+        }
+
+        // The gcd can be 2⁶³, which does not fit in i64, so divide in i128;
+        // the quotient always fits, since its magnitude reaches 2⁶³ only as i64::MIN / 1
+        let g = gcd(numer.unsigned_abs(), denom);
+        Rational {
+            numer: (numer as i128 / g as i128) as i64,
+            denom: denom / g,
+        }
+    }
+
+    /// Numerator, carrying the sign of the fraction
+    pub fn numer(self) -> i64 {
+        self.numer
+    }
+
+    /// Denominator, always positive
+    pub fn denom(self) -> u64 {
+        self.denom
+    }
+
+    /// Nearest `f64` to the fraction
+    pub fn to_f64(self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+}
+
+impl Ord for Rational {
+    /// Compares a/b with c/d as a·d with c·b, exactly in 128-bit arithmetic
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.numer as i128 * other.denom as i128;
+        let rhs = other.numer as i128 * self.denom as i128;
+        lhs.cmp(&rhs)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational_reduces() {
+        let r = Rational::new(-12, 18);
+        assert_eq!((r.numer(), r.denom()), (-2, 3));
+        assert_eq!(Rational::new(0, 7), Rational::new(0, 1));
+        assert_eq!(Rational::new(4, 2).to_string(), "2/1");
+    }
+
+    #[test]
+    fn test_rational_i64_min() {
+        // gcd(2⁶³, 2⁶³) = 2⁶³ does not fit in i64
+        let r = Rational::new(i64::MIN, 1 << 63);
+        assert_eq!((r.numer(), r.denom()), (-1, 1));
+
+        let r = Rational::new(i64::MIN, 1);
+        assert_eq!((r.numer(), r.denom()), (i64::MIN, 1));
+        let r = Rational::new(i64::MIN, 6);
+        assert_eq!((r.numer(), r.denom()), (i64::MIN / 2, 3));
+    }
+
+    #[test]
+    fn test_rational_ordering() {
+        assert!(Rational::new(-1, 2) < Rational::new(1, 3));
+        assert!(Rational::new(2, 3) > Rational::new(3, 5));
+        assert_eq!(Rational::new(1, 3).to_f64(), 1.0 / 3.0);
+
+        // Cross products beyond the i64 range still compare exactly
+        let a = Rational::new(i64::MAX - 1, u64::MAX);
+        let b = Rational::new(i64::MAX, u64::MAX);
+        assert!(a < b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational number requires a nonzero denominator")]
+    fn test_rational_zero_denominator() {
+        Rational::new(1, 0);
+    }
+}
@@ -0,0 +1,245 @@
+//! # Pseudo-Random Numbers
+//!
+//! A small, seedable generator for Monte Carlo methods, randomized algorithms and
+//! resampling. The same seed always produces the same sequence on every platform,
+//! so randomized results are reproducible.
+//!
+//! The generator is xoshiro256** by Blackman and Vigna: 256 bits of state, a period
+//! of 2²⁵⁶ - 1, and excellent statistical quality. It is **not** suitable for
+//! cryptography.
+
+/// The xoshiro256** pseudo-random number generator
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::rng::Xoshiro256;
+///
+/// let mut a = Xoshiro256::new(42);
+/// let mut b = Xoshiro256::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+///
+/// let x = a.next_f64();
+/// assert!((0.0..1.0).contains(&x));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Xoshiro256 {
+    state: [u64; 4],
+}
+
+impl Xoshiro256 {
+    /// Creates a generator from a 64-bit seed
+    ///
+    /// The seed is expanded into the full state with SplitMix64, as recommended by
+    /// the authors, so that similar seeds still give unrelated sequences.
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+
+        Xoshiro256 {
+            state: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+
+    /// Next 64 uniformly distributed random bits
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    /// Uniformly distributed `f64` in [0, 1) with 53 random bits
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Uniformly distributed integer in `0..n`, without modulo bias
+    ///
+    /// Uses Lemire's multiply-and-reject method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        if n == 0 {
+            panic!("Random range must not be empty");
+        }
+
+        // Reject the low products that would make some outputs more likely
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let product = self.next_u64() as u128 * n as u128;
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Shuffles `slice` in place with the Fisher-Yates algorithm
+    ///
+    /// Every permutation is equally likely.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::rng::Xoshiro256;
+    ///
+    /// let mut rng = Xoshiro256::new(7);
+    /// let mut values = [1, 2, 3, 4, 5];
+    /// rng.shuffle(&mut values);
+    ///
+    /// let mut sorted = values;
+    /// sorted.sort();
+    /// assert_eq!(sorted, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            slice.swap(i, j);
+        }
+    }
+
+    /// Samples `k` distinct indices from `0..n`, in random order
+    ///
+    /// Runs a partial Fisher-Yates shuffle of `0..n` that stops after `k` steps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::base::rng::Xoshiro256;
+    ///
+    /// let mut rng = Xoshiro256::new(1);
+    /// let picks = rng.sample_indices(10, 3);
+    /// assert_eq!(picks.len(), 3);
+    /// assert!(picks.iter().all(|&i| i < 10));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k > n`.
+    pub fn sample_indices(&mut self, n: usize, k: usize) -> Vec<usize> {
+        if k > n {
+            panic!("Cannot sample more indices than the population size");
+        }
+
+        let mut indices: Vec<usize> = (0..n).collect();
+        for i in 0..k {
+            let j = i + self.below((n - i) as u64) as usize;
+            indices.swap(i, j);
+        }
+        indices.truncate(k);
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xoshiro_reference_output() {
+        // From the reference C implementation with state {1, 2, 3, 4}
+        let mut rng = Xoshiro256 {
+            state: [1, 2, 3, 4],
+        };
+        assert_eq!(rng.next_u64(), 11_520);
+        assert_eq!(rng.next_u64(), 0);
+        assert_eq!(rng.next_u64(), 1_509_978_240);
+    }
+
+    #[test]
+    fn test_seeds_are_reproducible() {
+        let first: Vec<u64> = {
+            let mut rng = Xoshiro256::new(2024);
+            (0..5).map(|_| rng.next_u64()).collect()
+        };
+        let mut rng = Xoshiro256::new(2024);
+        assert!(first.iter().all(|&x| x == rng.next_u64()));
+        assert_ne!(Xoshiro256::new(2025).next_u64(), first[0]);
+    }
+
+    #[test]
+    fn test_next_f64_range_and_mean() {
+        let mut rng = Xoshiro256::new(3);
+        let n = 100_000;
+        let mut sum = 0.0;
+        for _ in 0..n {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+            sum += x;
+        }
+        assert!((sum / n as f64 - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_shuffle_is_permutation() {
+        let mut rng = Xoshiro256::new(11);
+        let mut values: Vec<u32> = (0..100).collect();
+        rng.shuffle(&mut values);
+
+        assert_ne!(values, (0..100).collect::<Vec<_>>());
+        values.sort();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+
+        let mut empty: [u8; 0] = [];
+        rng.shuffle(&mut empty);
+    }
+
+    #[test]
+    fn test_shuffle_is_uniform() {
+        let mut rng = Xoshiro256::new(99);
+        let trials = 60_000;
+        let mut counts = std::collections::HashMap::new();
+
+        for _ in 0..trials {
+            let mut values = [0, 1, 2];
+            rng.shuffle(&mut values);
+            *counts.entry(values).or_insert(0) += 1;
+        }
+
+        // All 3! orderings appear about equally often
+        assert_eq!(counts.len(), 6);
+        for &count in counts.values() {
+            assert!((count as f64 - 10_000.0).abs() < 500.0, "{count}");
+        }
+    }
+
+    #[test]
+    fn test_sample_indices_distinct_and_in_range() {
+        let mut rng = Xoshiro256::new(5);
+        let picks = rng.sample_indices(50, 20);
+        assert_eq!(picks.len(), 20);
+        assert!(picks.iter().all(|&i| i < 50));
+
+        let mut sorted = picks.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), 20);
+
+        let mut all = rng.sample_indices(8, 8);
+        all.sort();
+        assert_eq!(all, (0..8).collect::<Vec<_>>());
+        assert!(rng.sample_indices(8, 0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot sample more indices than the population size")]
+    fn test_sample_indices_too_many() {
+        Xoshiro256::new(0).sample_indices(3, 4);
+    }
+}
@@ -0,0 +1,664 @@
+//! # Error and Robust Statistics
+//!
+//! Summary measures of the discrepancy between an approximation and a reference,
+//! for validating numerical methods against known results, outlier-resistant
+//! location and scale estimates for noisy data, overflow-free log-sum-exp and
+//! softmax, principal component analysis, and serial-correlation diagnostics for
+//! time series.
+
+use crate::base::matrix::Matrix;
+
+/// Checks that two arrays can be compared element by element
+fn check_lengths(a: &[f64], b: &[f64]) {
+    if a.len() != b.len() {
+        panic!("Arrays must have the same length");
+    }
+    if a.is_empty() {
+        panic!("Error statistics require at least one element");
+    }
+}
+
+/// Mean absolute error (1/n)·Σ|aᵢ - bᵢ|
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::mae;
+///
+/// assert_eq!(mae(&[1.0, 2.0, 3.0], &[1.0, 2.5, 2.0]), 0.5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the arrays differ in length or are empty.
+pub fn mae(a: &[f64], b: &[f64]) -> f64 {
+    check_lengths(a, b);
+    let sum: f64 = a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum();
+    sum / a.len() as f64
+}
+
+/// Root-mean-square error √((1/n)·Σ(aᵢ - bᵢ)²)
+///
+/// Always at least as large as [`mae`], and weights large deviations more heavily.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::rmse;
+///
+/// assert_eq!(rmse(&[0.0, 0.0], &[3.0, -3.0]), 3.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the arrays differ in length or are empty.
+pub fn rmse(a: &[f64], b: &[f64]) -> f64 {
+    check_lengths(a, b);
+    let sum: f64 = a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum();
+    (sum / a.len() as f64).sqrt()
+}
+
+/// Maximum absolute error maxᵢ|aᵢ - bᵢ|
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::max_abs_error;
+///
+/// assert_eq!(max_abs_error(&[1.0, 2.0, 3.0], &[1.0, 2.5, 2.0]), 1.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the arrays differ in length or are empty.
+pub fn max_abs_error(a: &[f64], b: &[f64]) -> f64 {
+    check_lengths(a, b);
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Sorted copy of non-empty data, panicking on an empty slice
+fn sorted(data: &[f64]) -> Vec<f64> {
+    if data.is_empty() {
+        panic!("Robust statistics require at least one element");
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// Median of already sorted, non-empty data
+fn median_of_sorted(sorted: &[f64]) -> f64 {
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        0.5 * (sorted[n / 2 - 1] + sorted[n / 2])
+    }
+}
+
+/// Mean after discarding the `proportion` smallest and largest values
+///
+/// ⌊proportion·n⌋ elements are removed from each end of the sorted data, as in
+/// SciPy's `trim_mean`. A proportion of 0 gives the ordinary mean, and values
+/// approaching 0.5 approach the median.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::trimmed_mean;
+///
+/// // The 10% trim drops the outliers -50 and 1000
+/// let data = [-50.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 1000.0];
+/// assert_eq!(trimmed_mean(&data, 0.1), 4.5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `data` is empty or if `proportion` is not in [0, 0.5).
+pub fn trimmed_mean(data: &[f64], proportion: f64) -> f64 {
+    if !(0.0..0.5).contains(&proportion) {
+        panic!("Trim proportion must be in [0, 0.5)");
+    }
+
+    let sorted = sorted(data);
+    let cut = (proportion * sorted.len() as f64) as usize;
+    let kept = &sorted[cut..sorted.len() - cut];
+    kept.iter().sum::<f64>() / kept.len() as f64
+}
+
+/// Median absolute deviation medianᵢ|xᵢ - median(x)|
+///
+/// A robust scale estimate that, unlike the standard deviation, is unaffected by
+/// up to half of the data being outliers. The raw value is returned; multiply by
+/// 1.4826 for a consistent estimate of σ under normally distributed data.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::median_absolute_deviation;
+///
+/// assert_eq!(median_absolute_deviation(&[1.0, 2.0, 3.0, 4.0, 100.0]), 1.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `data` is empty.
+pub fn median_absolute_deviation(data: &[f64]) -> f64 {
+    let center = median_of_sorted(&sorted(data));
+    let deviations: Vec<f64> = data.iter().map(|x| (x - center).abs()).collect();
+    median_of_sorted(&sorted(&deviations))
+}
+
+/// Logarithm of a sum of exponentials ln(Σ exp(xᵢ)), without overflow
+///
+/// Computed as m + ln(Σ exp(xᵢ - m)) with m = maxᵢ xᵢ, so the largest exponent
+/// is exp(0) = 1 and the sum neither overflows nor underflows to zero. An empty
+/// slice gives -∞, the logarithm of an empty sum.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::logsumexp;
+///
+/// // The naive form overflows: exp(1000) = ∞
+/// assert!((1000.0_f64.exp() * 2.0).ln().is_infinite());
+/// assert!((logsumexp(&[1000.0, 1000.0]) - (1000.0 + 2.0_f64.ln())).abs() < 1e-12);
+/// ```
+pub fn logsumexp(xs: &[f64]) -> f64 {
+    let max = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if max.is_infinite() {
+        // All -∞ (or empty) gives -∞; any +∞ dominates the sum
+        return max;
+    }
+
+    let sum: f64 = xs.iter().map(|x| (x - max).exp()).sum();
+    max + sum.ln()
+}
+
+/// Softmax exp(xᵢ) / Σ exp(xⱼ), using the shifted exponentials exp(xᵢ - max)
+///
+/// The result is a probability vector: non-negative entries summing to 1. It is
+/// unchanged by adding a constant to every input.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::softmax;
+///
+/// let p = softmax(&[1000.0, 1000.0 + 2.0_f64.ln()]);
+/// assert!((p[0] - 1.0 / 3.0).abs() < 1e-12);
+/// assert!((p[1] - 2.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn softmax(xs: &[f64]) -> Vec<f64> {
+    let max = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let exps: Vec<f64> = xs.iter().map(|x| (x - max).exp()).collect();
+    let sum: f64 = exps.iter().sum();
+    exps.into_iter().map(|e| e / sum).collect()
+}
+
+/// The principal axes of a data set, as computed by [`pca`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcaResult {
+    /// Mean of the observations, subtracted before the analysis
+    pub mean: Vec<f64>,
+    /// Unit principal directions, by descending variance; each is oriented so
+    /// that its largest-magnitude entry is positive
+    pub components: Vec<Vec<f64>>,
+    /// Variance of the data along each component (the covariance eigenvalues)
+    pub variances: Vec<f64>,
+    /// Fraction of the total variance along each component, summing to 1
+    pub explained_variance_ratio: Vec<f64>,
+}
+
+/// Principal component analysis of a set of observations
+///
+/// The observations are centered, their sample covariance matrix (with the
+/// n - 1 denominator) is formed, and its eigen-decomposition is computed with
+/// [`Matrix::jacobi_eigen`]. The eigenvectors are the principal components and
+/// the eigenvalues the variance along each of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::pca;
+///
+/// // Points spread along the diagonal y = x
+/// let data = vec![vec![-2.0, -2.1], vec![-1.0, -0.9], vec![1.0, 1.1], vec![2.0, 1.9]];
+/// let result = pca(&data);
+///
+/// let first = &result.components[0];
+/// assert!((first[0] - first[1]).abs() < 0.1);
+/// assert!(result.explained_variance_ratio[0] > 0.99);
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are fewer than two observations, they differ in length or
+/// any value is NaN or infinite, or if the covariance overflows, which needs
+/// deviations from the mean beyond about 1e154.
+pub fn pca(data: &[Vec<f64>]) -> PcaResult {
+    if data.len() < 2 {
+        panic!("PCA requires at least two observations");
+    }
+    let dim = data[0].len();
+    if data.iter().any(|row| row.len() != dim) {
+        panic!("PCA observations must all have the same length");
+    }
+    if data.iter().flatten().any(|x| !x.is_finite()) {
+        panic!("PCA observations must be finite");
+    }
+
+    let n = data.len() as f64;
+    let mean: Vec<f64> = (0..dim)
+        .map(|j| data.iter().map(|row| row[j]).sum::<f64>() / n)
+        .collect();
+
+    let mut covariance = Matrix::zeros(dim, dim);
+    for row in data {
+        for i in 0..dim {
+            for j in i..dim {
+                covariance[(i, j)] += (row[i] - mean[i]) * (row[j] - mean[j]);
+            }
+        }
+    }
+    for i in 0..dim {
+        for j in i..dim {
+            covariance[(i, j)] /= n - 1.0;
+            covariance[(j, i)] = covariance[(i, j)];
+        }
+    }
+    if (0..dim).any(|i| (i..dim).any(|j| !covariance[(i, j)].is_finite())) {
+        panic!("PCA covariance overflowed; rescale the observations");
+    }
+
+    let (eigenvalues, vectors) = covariance
+        .jacobi_eigen(1e-15, 100)
+        .expect("Jacobi eigensolver failed to converge on a covariance matrix");
+
+    // Rounding can leave tiny negative eigenvalues for rank-deficient data
+    let variances: Vec<f64> = eigenvalues.iter().rev().map(|&v| v.max(0.0)).collect();
+    let components = (0..dim)
+        .rev()
+        .map(|k| {
+            let mut component: Vec<f64> = (0..dim).map(|i| vectors[(i, k)]).collect();
+            let largest = component
+                .iter()
+                .fold(0.0_f64, |m, &x| if x.abs() > m.abs() { x } else { m });
+            if largest < 0.0 {
+                component.iter_mut().for_each(|x| *x = -*x);
+            }
+            component
+        })
+        .collect();
+
+    let total: f64 = variances.iter().sum();
+    let explained_variance_ratio = variances
+        .iter()
+        .map(|&v| if total > 0.0 { v / total } else { 0.0 })
+        .collect();
+
+    PcaResult {
+        mean,
+        components,
+        variances,
+        explained_variance_ratio,
+    }
+}
+
+/// Sample autocorrelation of a sequence at lags `0..=max_lag`
+///
+/// With x̄ the mean of the data, the autocorrelation at lag k is
+///
+/// ```text
+/// rₖ = Σₜ (xₜ - x̄)(xₜ₊ₖ - x̄) / Σₜ (xₜ - x̄)²
+/// ```
+///
+/// the standard biased estimator, which divides every lag by the same total so
+/// that r₀ = 1 and |rₖ| ≤ 1. For a constant sequence every lag except 0 is NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::autocorrelation;
+///
+/// // Alternating data is perfectly anti-correlated at lag 1
+/// let r = autocorrelation(&[1.0, -1.0, 1.0, -1.0, 1.0, -1.0], 2);
+/// assert_eq!(r[0], 1.0);
+/// assert!(r[1] < -0.8);
+/// assert!(r[2] > 0.6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_lag` is not less than the length of the data.
+pub fn autocorrelation(data: &[f64], max_lag: usize) -> Vec<f64> {
+    if max_lag >= data.len() {
+        panic!("Maximum lag must be less than the data length");
+    }
+
+    let mean = data.iter().sum::<f64>() / data.len() as f64;
+    let centered: Vec<f64> = data.iter().map(|x| x - mean).collect();
+    let total: f64 = centered.iter().map(|x| x * x).sum();
+
+    let mut result = Vec::with_capacity(max_lag + 1);
+    result.push(1.0);
+    for lag in 1..=max_lag {
+        let sum: f64 = centered
+            .iter()
+            .zip(&centered[lag..])
+            .map(|(a, b)| a * b)
+            .sum();
+        result.push(sum / total);
+    }
+    result
+}
+
+/// Durbin-Watson statistic of regression residuals
+///
+/// ```text
+/// d = Σₜ (eₜ - eₜ₋₁)² / Σₜ eₜ²
+/// ```
+///
+/// d lies in [0, 4] and is about 2(1 - r₁), where r₁ is the lag-one
+/// autocorrelation: values near 2 indicate no serial correlation, values towards
+/// 0 positive and values towards 4 negative correlation. All-zero residuals give
+/// NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::base::stats::durbin_watson;
+///
+/// // Slowly drifting residuals are positively correlated
+/// assert!(durbin_watson(&[1.0, 1.1, 1.2, 1.1, 0.9, 0.8]) < 0.1);
+///
+/// // Sign flips at every step are negatively correlated
+/// assert!(durbin_watson(&[1.0, -1.0, 1.0, -1.0]) > 2.9);
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are fewer than two residuals.
+pub fn durbin_watson(residuals: &[f64]) -> f64 {
+    if residuals.len() < 2 {
+        panic!("Durbin-Watson statistic requires at least two residuals");
+    }
+
+    let differences: f64 = residuals.windows(2).map(|w| (w[1] - w[0]).powi(2)).sum();
+    let total: f64 = residuals.iter().map(|e| e * e).sum();
+    differences / total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::numbers::PI;
+
+    #[test]
+    fn test_identical_arrays() {
+        let a = [1.5, -2.0, 3.25];
+        assert_eq!(mae(&a, &a), 0.0);
+        assert_eq!(rmse(&a, &a), 0.0);
+        assert_eq!(max_abs_error(&a, &a), 0.0);
+    }
+
+    #[test]
+    fn test_constant_offset() {
+        let a = [1.0, 2.0, 3.0, 4.0];
+        let b: Vec<f64> = a.iter().map(|x| x + 0.25).collect();
+        assert_eq!(mae(&a, &b), 0.25);
+        assert_eq!(rmse(&a, &b), 0.25);
+        assert_eq!(max_abs_error(&a, &b), 0.25);
+    }
+
+    #[test]
+    fn test_rmse_at_least_mae() {
+        let a = [0.0, 1.0, -3.0, 2.5, 7.0];
+        let b = [0.5, 1.0, -1.0, 2.0, 4.0];
+        assert!(rmse(&a, &b) >= mae(&a, &b));
+        assert!(max_abs_error(&a, &b) >= rmse(&a, &b));
+        assert_eq!(max_abs_error(&a, &b), 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Arrays must have the same length")]
+    fn test_length_mismatch() {
+        mae(&[1.0, 2.0], &[1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error statistics require at least one element")]
+    fn test_empty_arrays() {
+        rmse(&[], &[]);
+    }
+
+    #[test]
+    fn test_trimmed_mean_resists_outliers() {
+        let mut data: Vec<f64> = (1..=18).map(|i| 10.0 + 0.1 * i as f64).collect();
+        let bulk_mean = data.iter().sum::<f64>() / data.len() as f64;
+        data.push(1e6);
+        data.push(-1e6 + 1.0);
+
+        let trimmed = trimmed_mean(&data, 0.1);
+        assert!((trimmed - bulk_mean).abs() < 0.2);
+
+        let plain = trimmed_mean(&data, 0.0);
+        assert!((plain - bulk_mean).abs() > 1.0);
+    }
+
+    #[test]
+    fn test_trimmed_mean_zero_proportion_is_mean() {
+        let data = [3.0, -1.0, 4.0, 1.0, 5.0];
+        assert_eq!(trimmed_mean(&data, 0.0), 12.0 / 5.0);
+        // ⌊0.45·5⌋ = 2 from each end leaves only the median
+        assert_eq!(trimmed_mean(&data, 0.45), 3.0);
+    }
+
+    #[test]
+    fn test_median_absolute_deviation() {
+        let symmetric = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+        assert_eq!(median_absolute_deviation(&symmetric), 2.0);
+
+        // Even length: median 2.5, deviations 1.5, 0.5, 0.5, 1.5
+        assert_eq!(median_absolute_deviation(&[4.0, 1.0, 3.0, 2.0]), 1.0);
+        assert_eq!(median_absolute_deviation(&[7.0]), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Trim proportion must be in [0, 0.5)")]
+    fn test_trimmed_mean_invalid_proportion() {
+        trimmed_mean(&[1.0, 2.0], 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Robust statistics require at least one element")]
+    fn test_median_absolute_deviation_empty() {
+        median_absolute_deviation(&[]);
+    }
+
+    #[test]
+    fn test_logsumexp_large_values() {
+        let value = logsumexp(&[1000.0, 1000.0]);
+        assert!(value.is_finite());
+        assert!((value - 1_000.693_147_180_559_9).abs() < 1e-12);
+
+        assert!((logsumexp(&[-1000.0, -1000.0]) + 1000.0 - 2.0_f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_logsumexp_small_values_match_naive() {
+        let xs = [0.5, -1.2, 2.0, 0.0];
+        let naive = xs.iter().map(|x: &f64| x.exp()).sum::<f64>().ln();
+        assert!((logsumexp(&xs) - naive).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_logsumexp_infinities() {
+        assert_eq!(logsumexp(&[]), f64::NEG_INFINITY);
+        assert_eq!(logsumexp(&[f64::NEG_INFINITY; 3]), f64::NEG_INFINITY);
+        assert_eq!(logsumexp(&[1.0, f64::INFINITY]), f64::INFINITY);
+        assert_eq!(logsumexp(&[f64::NEG_INFINITY, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn test_softmax_sums_to_one() {
+        let p = softmax(&[3.0, 1.0, 0.2, 800.0, -50.0]);
+        assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-15);
+        assert!(p.iter().all(|&x| (0.0..=1.0).contains(&x)));
+        assert!(softmax(&[]).is_empty());
+        assert_eq!(softmax(&[7.0]), [1.0]);
+    }
+
+    #[test]
+    fn test_softmax_shift_invariant() {
+        let xs = [0.3, -1.0, 2.5, 1.1];
+        let shifted: Vec<f64> = xs.iter().map(|x| x + 500.0).collect();
+        for (a, b) in softmax(&xs).iter().zip(softmax(&shifted)) {
+            assert!((a - b).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_pca_dominant_axis() {
+        // Wide spread along x, small wiggle along y
+        let data: Vec<Vec<f64>> = (0..50)
+            .map(|k| {
+                let t = k as f64 / 5.0 - 5.0;
+                vec![t + 3.0, 0.1 * (7.0 * t).sin() - 1.0]
+            })
+            .collect();
+        let result = pca(&data);
+
+        let first = &result.components[0];
+        assert!((first[0] - 1.0).abs() < 1e-3);
+        assert!(first[1].abs() < 0.05);
+        assert!(result.explained_variance_ratio[0] > 0.99);
+        assert!(result.variances[0] > result.variances[1]);
+        assert!((result.mean[0] - 2.9).abs() < 1e-12);
+
+        let ratios: f64 = result.explained_variance_ratio.iter().sum();
+        assert!((ratios - 1.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_pca_isotropic() {
+        let data = vec![
+            vec![1.0, 1.0],
+            vec![1.0, -1.0],
+            vec![-1.0, 1.0],
+            vec![-1.0, -1.0],
+        ];
+        let result = pca(&data);
+
+        for ratio in &result.explained_variance_ratio {
+            assert!((ratio - 0.5).abs() < 1e-14);
+        }
+        for variance in &result.variances {
+            assert!((variance - 4.0 / 3.0).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_pca_components_orthonormal() {
+        let data = vec![
+            vec![2.5, 2.4, 0.5],
+            vec![0.5, 0.7, 1.5],
+            vec![2.2, 2.9, 0.1],
+            vec![1.9, 2.2, -0.3],
+            vec![3.1, 3.0, 0.8],
+            vec![2.3, 2.7, 1.1],
+        ];
+        let result = pca(&data);
+
+        for (i, a) in result.components.iter().enumerate() {
+            for (j, b) in result.components.iter().enumerate() {
+                let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-13);
+            }
+        }
+        assert!(result.variances.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "PCA requires at least two observations")]
+    fn test_pca_single_observation() {
+        pca(&[vec![1.0, 2.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "PCA observations must be finite")]
+    fn test_pca_nan_observation() {
+        pca(&[vec![1.0, 2.0], vec![f64::NAN, 0.5], vec![3.0, 1.0]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "PCA covariance overflowed; rescale the observations")]
+    fn test_pca_covariance_overflow() {
+        pca(&[vec![-1e200, 0.0], vec![1e200, 1.0]]);
+    }
+
+    #[test]
+    fn test_autocorrelation_white_noise() {
+        let mut rng = crate::base::rng::Xoshiro256::new(17);
+        let data: Vec<f64> = (0..10_000).map(|_| rng.next_f64() - 0.5).collect();
+        let r = autocorrelation(&data, 10);
+
+        assert_eq!(r.len(), 11);
+        assert_eq!(r[0], 1.0);
+        // The standard error at nonzero lags is about 1/√n = 0.01
+        for &value in &r[1..] {
+            assert!(value.abs() < 0.05, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_autocorrelation_periodic_peak() {
+        let period = 12;
+        let data: Vec<f64> = (0..240)
+            .map(|t| (2.0 * PI * t as f64 / period as f64).sin())
+            .collect();
+        let r = autocorrelation(&data, 20);
+
+        // The largest correlation away from lag 0 is at the period
+        let peak = (1..=20).max_by(|&i, &j| r[i].total_cmp(&r[j])).unwrap();
+        assert_eq!(peak, period);
+        assert!(r[period] > 0.9);
+        assert!(r[period / 2] < -0.9);
+    }
+
+    #[test]
+    fn test_autocorrelation_lag_zero() {
+        for data in [vec![3.0], vec![1.0, 5.0], vec![2.0, 2.0, 2.0]] {
+            assert_eq!(autocorrelation(&data, 0), [1.0]);
+        }
+        assert!(autocorrelation(&[2.0, 2.0, 2.0], 1)[1].is_nan());
+    }
+
+    #[test]
+    fn test_durbin_watson_matches_autocorrelation() {
+        let mut rng = crate::base::rng::Xoshiro256::new(4);
+        let residuals: Vec<f64> = (0..5_000).map(|_| rng.next_f64() - 0.5).collect();
+        let d = durbin_watson(&residuals);
+        assert!((d - 2.0).abs() < 0.1, "{d}");
+
+        // d ≈ 2(1 - r₁) for zero-mean residuals
+        let r1 = autocorrelation(&residuals, 1)[1];
+        assert!((d - 2.0 * (1.0 - r1)).abs() < 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "Maximum lag must be less than the data length")]
+    fn test_autocorrelation_lag_too_large() {
+        autocorrelation(&[1.0, 2.0, 3.0], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Durbin-Watson statistic requires at least two residuals")]
+    fn test_durbin_watson_too_short() {
+        durbin_watson(&[1.0]);
+    }
+}
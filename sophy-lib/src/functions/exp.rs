@@ -0,0 +1,105 @@
+//! Exponential function implementations
+//!
+//! Variants of the exponential function that remain accurate where the
+//! straightforward expression cancels catastrophically.
+
+/// Exponential minus one, eˣ - 1
+///
+/// Accurate to a few units in the last place even when |x| is tiny, where
+/// `x.exp() - 1.0` subtracts two nearly equal numbers and returns 0 or garbage.
+///
+/// ## Implementation
+///
+/// For |x| < 1 this uses Kahan's correction: with u = fl(eˣ),
+///
+/// ```text
+/// eˣ - 1 ≈ (u - 1) · x / ln(u)
+/// ```
+///
+/// The rounding error in `u` cancels between the numerator and ln(u).
+/// For |x| ≥ 1 the subtraction loses at most one bit, so `x.exp() - 1.0` is used.
+///
+/// ## Special Values
+///
+/// - expm1(0) = 0
+/// - expm1(-∞) = -1
+/// - expm1(+∞) = +∞
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::functions::expm1;
+///
+/// // The naive form collapses to zero
+/// assert_eq!(1e-17_f64.exp() - 1.0, 0.0);
+/// assert_eq!(expm1(1e-17), 1e-17);
+///
+/// assert!((expm1(1.0) - (std::f64::consts::E - 1.0)).abs() < 1e-15);
+/// ```
+pub fn expm1(x: f64) -> f64 {
+    if x.abs() >= 1.0 || x.is_nan() {
+        return x.exp() - 1.0;
+    }
+
+    let u = x.exp();
+    if u == 1.0 {
+        return x;
+    }
+
+    let um1 = u - 1.0;
+    if um1 == -1.0 {
+        return -1.0;
+    }
+
+    um1 * x / u.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expm1_tiny_argument() {
+        let x = 1e-15;
+        let value = expm1(x);
+        assert!(value != 0.0);
+        assert!(((value - x) / x).abs() < 1e-15);
+        assert_eq!(expm1(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_expm1_matches_std() {
+        let mut x: f64 = -20.0;
+        while x < 20.0 {
+            let expected = x.exp_m1();
+            let value = expm1(x);
+            assert!(
+                (value - expected).abs() <= 2.0 * f64::EPSILON * expected.abs(),
+                "expm1({x}) = {value}, expected {expected}"
+            );
+            x += 0.0137;
+        }
+
+        for k in 1..300 {
+            let x = 10f64.powi(-k);
+            assert!((expm1(x) - x.exp_m1()).abs() <= 2.0 * f64::EPSILON * x);
+            assert!((expm1(-x) - (-x).exp_m1()).abs() <= 2.0 * f64::EPSILON * x);
+        }
+    }
+
+    #[test]
+    fn test_expm1_special_values() {
+        assert_eq!(expm1(f64::NEG_INFINITY), -1.0);
+        assert_eq!(expm1(f64::INFINITY), f64::INFINITY);
+        assert!(expm1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_expm1_inverts_log1p() {
+        use crate::functions::log1p;
+
+        for &x in &[1e-12, 3e-7, 0.01, 0.3, -0.2, -1e-9] {
+            assert!((log1p(expm1(x)) - x).abs() <= 4.0 * f64::EPSILON * x.abs());
+        }
+    }
+}
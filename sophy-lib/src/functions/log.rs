@@ -0,0 +1,94 @@
+//! Logarithmic function implementations
+//!
+//! Variants of the natural logarithm that remain accurate where `f64::ln`
+//! applied to a rounded argument does not.
+
+/// Natural logarithm of one plus the argument, ln(1 + x)
+///
+/// Accurate to a few units in the last place even when |x| is tiny, where
+/// `(1.0 + x).ln()` would return 0 or lose most of its significant digits.
+///
+/// ## Implementation
+///
+/// For |x| < 1 this uses Goldberg's correction: with u = fl(1 + x),
+///
+/// ```text
+/// ln(1 + x) ≈ ln(u) · x / (u - 1)
+/// ```
+///
+/// The rounding error committed in forming `u` cancels between ln(u) and u - 1.
+/// For |x| ≥ 1 the sum 1 + x is either exact or harmless, so the plain
+/// logarithm is used.
+///
+/// ## Special Values
+///
+/// - log1p(0) = 0
+/// - log1p(-1) = -∞
+/// - log1p(x) = NaN for x < -1
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::functions::log1p;
+///
+/// // The naive form collapses to zero
+/// assert_eq!((1.0_f64 + 1e-17).ln(), 0.0);
+/// assert_eq!(log1p(1e-17), 1e-17);
+///
+/// assert!((log1p(1.0) - 2.0_f64.ln()).abs() < 1e-15);
+/// ```
+pub fn log1p(x: f64) -> f64 {
+    if x.abs() >= 1.0 || x.is_nan() {
+        return (1.0 + x).ln();
+    }
+
+    let u = 1.0 + x;
+    if u == 1.0 {
+        // x is below half an ulp of 1, where ln(1 + x) = x - x²/2 + ... rounds to x
+        return x;
+    }
+
+    u.ln() * x / (u - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log1p_tiny_argument() {
+        let x = 1e-15;
+        let value = log1p(x);
+        assert!(value != 0.0);
+        assert!(((value - x) / x).abs() < 1e-15);
+        assert_eq!(log1p(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_log1p_matches_std() {
+        let mut x: f64 = -0.999;
+        while x < 10.0 {
+            let expected = x.ln_1p();
+            let value = log1p(x);
+            assert!(
+                (value - expected).abs() <= 2.0 * f64::EPSILON * expected.abs(),
+                "log1p({x}) = {value}, expected {expected}"
+            );
+            x += 0.0137;
+        }
+
+        for k in 1..300 {
+            let x = 10f64.powi(-k);
+            assert!((log1p(x) - x.ln_1p()).abs() <= 2.0 * f64::EPSILON * x);
+            assert!((log1p(-x) - (-x).ln_1p()).abs() <= 2.0 * f64::EPSILON * x);
+        }
+    }
+
+    #[test]
+    fn test_log1p_special_values() {
+        assert_eq!(log1p(-1.0), f64::NEG_INFINITY);
+        assert!(log1p(-2.0).is_nan());
+        assert!(log1p(f64::NAN).is_nan());
+        assert_eq!(log1p(f64::INFINITY), f64::INFINITY);
+    }
+}
@@ -0,0 +1,47 @@
+//! # Elementary Functions Module
+//!
+//! This module provides elementary mathematical functions implemented with
+//! numerical accuracy in mind, covering cases where the naive formula loses
+//! precision.
+//!
+//! ## Available Functions
+//!
+//! ### Exponential and Logarithmic
+//! - [`expm1`]: eˣ - 1, accurate for small `x`
+//! - [`log1p`]: ln(1 + x), accurate for small `x`
+//!
+//! ### Inverse Trigonometric
+//! - [`atan`]: Arctangent from argument reduction and a power series
+//! - [`atan2`]: Four-quadrant arctangent of y/x
+//! - [`asin`], [`acos`]: Arcsine and arccosine on [-1, 1], built on `atan`
+//!
+//! ## Module Organization
+//!
+//! - `exp.rs` - Exponential function variants
+//! - `log.rs` - Logarithmic function variants
+//! - `trig.rs` - Inverse trigonometric functions
+//!
+//! ## Usage Examples
+//!
+//! ```rust
+//! use sophy::functions::{expm1, log1p};
+//!
+//! // The naive forms round 1 + 1e-15 and lose most significant digits
+//! let x = 1e-15;
+//! assert!((log1p(x) - x).abs() < 1e-30);
+//! assert!((expm1(x) - x).abs() < 1e-30);
+//! ```
+//!
+//! ## Why Not the Naive Formulas?
+//!
+//! For |x| much smaller than 1, `1.0 + x` rounds away most of the digits of `x`
+//! before the logarithm is even taken, and `x.exp() - 1.0` subtracts two nearly
+//! equal numbers. Both lose roughly `-log10(|x|)` significant digits.
+
+pub mod exp;
+pub mod log;
+pub mod trig;
+
+pub use exp::expm1;
+pub use log::log1p;
+pub use trig::{acos, asin, atan, atan2};
@@ -0,0 +1,333 @@
+//! Inverse trigonometric function implementations
+//!
+//! Arctangent evaluated from argument reduction and a short power series, using
+//! only arithmetic, so it does not depend on the platform math library. Arcsine
+//! and arccosine are derived from it.
+
+use crate::base::numbers::PI;
+
+/// tan(π/12) = 2 - √3, the bound of the range where the series is summed
+const TAN_PI_12: f64 = 0.2679491924311227;
+
+/// √3 = tan(π/3)
+const SQRT_3: f64 = 1.7320508075688772;
+
+/// Arctangent in radians, in [-π/2, π/2]
+///
+/// ## Implementation
+///
+/// The argument is reduced in two steps:
+///
+/// ```text
+/// atan(x) = π/2 - atan(1/x)                         for x > 1
+/// atan(x) = π/6 + atan((√3·x - 1)/(x + √3))         for x > 2 - √3
+/// ```
+///
+/// leaving |t| ≤ tan(π/12) ≈ 0.268, where the Euler-Gregory series
+/// t - t³/3 + t⁵/5 - ... gains more than a digit per term. Negative arguments use
+/// the odd symmetry atan(-x) = -atan(x).
+///
+/// ## Special Values
+///
+/// - atan(0) = 0, with the sign of zero preserved
+/// - atan(±∞) = ±π/2
+/// - atan(NaN) = NaN
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::functions::atan;
+/// use std::f64::consts::PI;
+///
+/// assert!((atan(1.0) - PI / 4.0).abs() < 1e-15);
+/// assert!((atan(-3.0_f64.sqrt()) + PI / 3.0).abs() < 1e-15);
+/// ```
+pub fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x < 0.0 {
+        return -atan(-x);
+    }
+
+    if x > 1.0 {
+        return 0.5 * PI - atan_reduced(1.0 / x);
+    }
+    atan_reduced(x)
+}
+
+/// atan on [0, 1], shifting arguments above tan(π/12) down by π/6
+fn atan_reduced(x: f64) -> f64 {
+    if x > TAN_PI_12 {
+        PI / 6.0 + atan_series((SQRT_3 * x - 1.0) / (x + SQRT_3))
+    } else {
+        atan_series(x)
+    }
+}
+
+/// Euler-Gregory series t - t³/3 + t⁵/5 - ... for |t| ≤ tan(π/12)
+fn atan_series(t: f64) -> f64 {
+    let t2 = t * t;
+    let mut power = t;
+    let mut sum = t;
+    let mut k = 1.0;
+
+    loop {
+        power *= -t2;
+        k += 2.0;
+        let term = power / k;
+        if term.abs() <= 0.5 * f64::EPSILON * sum.abs() {
+            return sum;
+        }
+        sum += term;
+    }
+}
+
+/// Four-quadrant arctangent of y/x in radians, in [-π, π]
+///
+/// The angle of the point (x, y) measured from the positive x axis. Unlike
+/// atan(y/x) it distinguishes opposite quadrants and handles x = 0.
+///
+/// ## Special Values
+///
+/// The signed-zero and infinity conventions of IEEE 754 and C's `atan2` are
+/// followed, for example:
+///
+/// - atan2(±0, +0) = ±0 and atan2(±0, -0) = ±π
+/// - atan2(y, ±0) = ±π/2 with the sign of y, for y ≠ 0
+/// - atan2(±∞, +∞) = ±π/4 and atan2(±∞, -∞) = ±3π/4
+/// - NaN if either argument is NaN
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::functions::atan2;
+/// use std::f64::consts::PI;
+///
+/// assert!((atan2(1.0, 1.0) - PI / 4.0).abs() < 1e-15);
+/// assert!((atan2(1.0, -1.0) - 3.0 * PI / 4.0).abs() < 1e-15);
+/// assert_eq!(atan2(0.0, -1.0), PI);
+/// ```
+pub fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    // π with the sign of y, for the left half-plane
+    let half_turn = if y.is_sign_negative() { -PI } else { PI };
+
+    if x.is_infinite() && y.is_infinite() {
+        let quarter = if x > 0.0 { 0.25 } else { 0.75 };
+        return quarter * half_turn;
+    }
+    if x == 0.0 {
+        if y == 0.0 {
+            return if x.is_sign_negative() { half_turn } else { y };
+        }
+        return 0.5 * half_turn;
+    }
+
+    let angle = atan(y / x);
+    if x > 0.0 {
+        angle
+    } else {
+        // atan(y/x) lies in the opposite quadrant; a half-turn brings it back
+        angle + half_turn
+    }
+}
+
+/// Arcsine in radians, in [-π/2, π/2]
+///
+/// ## Implementation
+///
+/// asin(x) = atan(x / √((1 - x)(1 + x)))
+///
+/// Factoring 1 - x² keeps the denominator accurate near |x| = 1, where 1 - x
+/// is computed exactly. The endpoints return ±π/2 directly.
+///
+/// ## Special Values
+///
+/// - asin(±1) = ±π/2
+/// - asin(x) = NaN for |x| > 1 or NaN input
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::functions::asin;
+/// use std::f64::consts::PI;
+///
+/// assert!((asin(0.5) - PI / 6.0).abs() < 1e-15);
+/// assert_eq!(asin(1.0), PI / 2.0);
+/// assert!(asin(1.5).is_nan());
+/// ```
+pub fn asin(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if x.abs() == 1.0 {
+        return 0.5 * PI * x;
+    }
+
+    atan(x / ((1.0 - x) * (1.0 + x)).sqrt())
+}
+
+/// Arccosine in radians, in [0, π]
+///
+/// ## Implementation
+///
+/// acos(x) = 2·atan(√((1 - x)/(1 + x)))
+///
+/// This half-angle form avoids the cancellation of π/2 - asin(x) near x = 1,
+/// where acos(x) is small. The endpoints return 0 and π directly.
+///
+/// ## Special Values
+///
+/// - acos(1) = 0 and acos(-1) = π
+/// - acos(x) = NaN for |x| > 1 or NaN input
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::functions::acos;
+/// use std::f64::consts::PI;
+///
+/// assert_eq!(acos(0.0), PI / 2.0);
+/// assert!((acos(-0.5) - 2.0 * PI / 3.0).abs() < 1e-15);
+/// ```
+pub fn acos(x: f64) -> f64 {
+    if x.is_nan() || x.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if x == 1.0 {
+        return 0.0;
+    }
+    if x == -1.0 {
+        return PI;
+    }
+
+    2.0 * atan(((1.0 - x) / (1.0 + x)).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan_known_values() {
+        assert!((atan(1.0) - PI / 4.0).abs() < 1e-15);
+        assert!((atan(SQRT_3) - PI / 3.0).abs() < 1e-15);
+        assert!((atan(1.0 / SQRT_3) - PI / 6.0).abs() < 1e-15);
+        assert_eq!(atan(f64::INFINITY), 0.5 * PI);
+        assert_eq!(atan(f64::NEG_INFINITY), -0.5 * PI);
+        assert!(atan(f64::NAN).is_nan());
+        assert!(atan(-0.0).is_sign_negative());
+    }
+
+    #[test]
+    fn test_atan_matches_std() {
+        let mut x: f64 = -20.0;
+        while x < 20.0 {
+            assert!((atan(x) - x.atan()).abs() < 1e-15, "atan({x})");
+            x += 0.0173;
+        }
+
+        for k in -300..300 {
+            let x = 10f64.powf(k as f64 / 10.0);
+            let expected = x.atan();
+            assert!((atan(x) - expected).abs() <= 2.0 * f64::EPSILON * expected);
+        }
+    }
+
+    #[test]
+    fn test_atan2_quadrants() {
+        assert!((atan2(1.0, 1.0) - PI / 4.0).abs() < 1e-15);
+        assert!((atan2(1.0, -1.0) - 3.0 * PI / 4.0).abs() < 1e-15);
+        assert!((atan2(-1.0, -1.0) + 3.0 * PI / 4.0).abs() < 1e-15);
+        assert!((atan2(-1.0, 1.0) + PI / 4.0).abs() < 1e-15);
+
+        let mut angle: f64 = -3.1;
+        while angle < PI {
+            let (y, x) = (2.5 * angle.sin(), 2.5 * angle.cos());
+            assert!((atan2(y, x) - y.atan2(x)).abs() < 1e-14, "atan2({y}, {x})");
+            angle += 0.01;
+        }
+    }
+
+    #[test]
+    fn test_atan2_axes_and_special_values() {
+        assert_eq!(atan2(0.0, -1.0), PI);
+        assert_eq!(atan2(-0.0, -1.0), -PI);
+        assert_eq!(atan2(2.0, 0.0), 0.5 * PI);
+        assert_eq!(atan2(-2.0, -0.0), -0.5 * PI);
+        assert_eq!(atan2(0.0, 3.0), 0.0);
+
+        for (y, x) in [
+            (0.0, 0.0),
+            (-0.0, 0.0),
+            (0.0, -0.0),
+            (-0.0, -0.0),
+            (f64::INFINITY, f64::INFINITY),
+            (f64::NEG_INFINITY, f64::NEG_INFINITY),
+            (1.0, f64::NEG_INFINITY),
+            (-1.0, f64::INFINITY),
+            (f64::INFINITY, -5.0),
+        ] {
+            let (value, expected) = (atan2(y, x), y.atan2(x));
+            assert_eq!(value, expected, "atan2({y}, {x})");
+            assert_eq!(value.is_sign_negative(), expected.is_sign_negative());
+        }
+
+        assert!(atan2(f64::NAN, 1.0).is_nan());
+        assert!(atan2(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_asin_acos_known_values() {
+        assert_eq!(asin(1.0), 0.5 * PI);
+        assert_eq!(asin(-1.0), -0.5 * PI);
+        assert_eq!(asin(0.0), 0.0);
+        assert!((asin(0.5) - PI / 6.0).abs() < 1e-15);
+
+        assert_eq!(acos(0.0), 0.5 * PI);
+        assert_eq!(acos(1.0), 0.0);
+        assert_eq!(acos(-1.0), PI);
+        assert!((acos(0.5) - PI / 3.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_asin_acos_symmetry() {
+        for i in 0..=100 {
+            let x = i as f64 / 100.0;
+            assert_eq!(asin(-x), -asin(x));
+            assert!((acos(-x) - (PI - acos(x))).abs() < 1e-15);
+            assert!((asin(x) + acos(x) - 0.5 * PI).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_asin_acos_match_std() {
+        let mut x: f64 = -1.0;
+        while x <= 1.0 {
+            assert!((asin(x) - x.asin()).abs() < 1e-15, "asin({x})");
+            assert!((acos(x) - x.acos()).abs() < 1e-15, "acos({x})");
+            x += 0.0013;
+        }
+
+        // Near the endpoints, where the asin denominator and acos itself vanish
+        for k in 1..16 {
+            let x = 1.0 - 10f64.powi(-k);
+            assert!((asin(x) - x.asin()).abs() < 1e-13);
+            assert!((asin(-x) - (-x).asin()).abs() < 1e-13);
+            assert!((acos(x) - x.acos()).abs() <= 4.0 * f64::EPSILON * x.acos());
+            assert!((acos(-x) - (-x).acos()).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_asin_acos_out_of_domain() {
+        for x in [1.0 + f64::EPSILON, -2.0, f64::INFINITY, f64::NAN] {
+            assert!(asin(x).is_nan());
+            assert!(acos(x).is_nan());
+        }
+    }
+}
@@ -77,13 +77,28 @@
 //! let error_val = erf(1.0);
 //! ```
 //!
+//! ### The Prelude
+//!
+//! Import the most common constants, types and functions at once:
+//!
+//! ```rust
+//! use sophy::prelude::*;
+//!
+//! // φ is the positive root of x² - x - 1
+//! let root = raphson(1.0, |x| x * x - x - 1.0, |x| 2.0 * x - 1.0, 1e-12, 100);
+//! assert!((root - PHI).abs() < 1e-12);
+//! assert!((gamma(5.0) - 24.0).abs() < 1e-10);
+//! ```
+//!
 //! ## 🏗️ Architecture
 //!
 //! Sophy is organized into focused modules:
 //!
 //! - [`methods`]: Numerical methods for solving mathematical problems
 //! - [`base`]: Fundamental number operations and utilities
+//! - [`functions`]: Elementary functions with careful numerics (log1p, expm1, etc.)
 //! - [`specials`]: Special mathematical functions (gamma, zeta, erf, etc.)
+//! - [`prelude`]: Glob-importable selection of the most common items
 //!
 //! ## 🔬 Precision & Performance
 //!
@@ -100,7 +115,9 @@
 //! or [Apache License 2.0](https://www.apache.org/licenses/LICENSE-2.0).
 
 pub mod base;
+pub mod functions;
 pub mod methods;
+pub mod prelude;
 pub mod specials;
 #[cfg(test)]
 mod tests {
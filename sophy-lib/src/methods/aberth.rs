@@ -0,0 +1,217 @@
+//! # Aberth-Ehrlich Method
+//!
+//! Simultaneous approximation of all roots of a complex polynomial.
+//!
+//! Each root estimate zᵢ takes a Newton step that is corrected by the "repulsion"
+//! of the other estimates:
+//!
+//! **wᵢ = N(zᵢ) / (1 - N(zᵢ)·Σⱼ≠ᵢ 1/(zᵢ - zⱼ)),   N(z) = p(z)/p'(z)**
+//!
+//! ## Algorithm Properties
+//!
+//! - **Convergence**: Cubic for simple roots, linear for multiple roots
+//! - **Robustness**: Converges from almost any set of distinct starting points,
+//!   which makes it more reliable than running Newton's method from a grid
+//! - **Versus Durand-Kerner**: Uses derivative information, so far fewer sweeps
+//!   are usually needed
+
+use crate::base::complex::Complex64;
+use crate::base::numbers::PI;
+
+/// Finds all roots of a complex polynomial with the Aberth-Ehrlich iteration.
+///
+/// The initial estimates are spread on a circle around the origin whose radius is
+/// Fujiwara's bound on the root moduli, rotated slightly so that no estimate lies
+/// on a symmetry axis of a real polynomial.
+///
+/// ## Arguments
+///
+/// * `coeffs` - Polynomial coefficients in ascending order, so `coeffs[k]`
+///   multiplies zᵏ. Trailing zero coefficients are ignored.
+/// * `tol` - Convergence tolerance. Iteration stops once every correction satisfies
+///   `|wᵢ| < tol`.
+/// * `max_iter` - Maximum number of sweeps over all roots.
+///
+/// ## Returns
+///
+/// A vector with one estimate per root (counted with multiplicity). Constant
+/// polynomials have no roots and produce an empty vector.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::methods::aberth::aberth;
+///
+/// // z² + 1 = 0  →  z = ±i
+/// let coeffs = [Complex64::ONE, Complex64::ZERO, Complex64::ONE];
+/// let roots = aberth(&coeffs, 1e-14, 100);
+///
+/// assert_eq!(roots.len(), 2);
+/// for root in roots {
+///     assert!((root.norm() - 1.0).abs() < 1e-12);
+///     assert!(root.re.abs() < 1e-12);
+/// }
+/// ```
+///
+/// ## Multiple Roots
+///
+/// Convergence towards a root of multiplicity m is only linear, and the attainable
+/// accuracy is limited to roughly ε^(1/m) (about 1e-8 for a double root), because
+/// the polynomial is flat there and rounding errors dominate p(z).
+pub fn aberth(coeffs: &[Complex64], tol: f64, max_iter: usize) -> Vec<Complex64> {
+    let degree = match coeffs.iter().rposition(|c| *c != Complex64::ZERO) {
+        Some(d) => d,
+        None => return Vec::new(),
+    };
+    let coeffs = &coeffs[..=degree];
+
+    if degree == 0 {
+        return Vec::new();
+    }
+
+    let radius = fujiwara_bound(coeffs);
+    let mut roots: Vec<Complex64> = (0..degree)
+        .map(|k| {
+            let theta = 2.0 * PI * k as f64 / degree as f64 + 0.4;
+            Complex64::new(radius * theta.cos(), radius * theta.sin())
+        })
+        .collect();
+
+    for _ in 0..max_iter {
+        let mut max_correction: f64 = 0.0;
+
+        for i in 0..degree {
+            let (p, dp) = horner(coeffs, roots[i]);
+            if p == Complex64::ZERO {
+                continue;
+            }
+
+            let ratio = p / dp;
+            let repulsion = roots
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .fold(Complex64::ZERO, |acc, (_, &zj)| {
+                    acc + Complex64::ONE / (roots[i] - zj)
+                });
+
+            let correction = ratio / (Complex64::ONE - ratio * repulsion);
+            roots[i] -= correction;
+            max_correction = max_correction.max(correction.norm());
+        }
+
+        if max_correction < tol {
+            break;
+        }
+    }
+
+    roots
+}
+
+/// Evaluates p(z) and p'(z) together with Horner's scheme
+fn horner(coeffs: &[Complex64], z: Complex64) -> (Complex64, Complex64) {
+    let mut p = Complex64::ZERO;
+    let mut dp = Complex64::ZERO;
+
+    for &c in coeffs.iter().rev() {
+        dp = dp * z + p;
+        p = p * z + c;
+    }
+
+    (p, dp)
+}
+
+/// Fujiwara's upper bound on the moduli of the roots
+fn fujiwara_bound(coeffs: &[Complex64]) -> f64 {
+    let n = coeffs.len() - 1;
+    let lead = coeffs[n].norm();
+
+    let bound = (1..=n)
+        .map(|k| {
+            let mut c = coeffs[n - k].norm() / lead;
+            if k == n {
+                c /= 2.0;
+            }
+            c.powf(1.0 / k as f64)
+        })
+        .fold(0.0, f64::max);
+
+    if bound > 0.0 { 2.0 * bound } else { 1.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn real_coeffs(coeffs: &[f64]) -> Vec<Complex64> {
+        coeffs.iter().map(|&c| Complex64::new(c, 0.0)).collect()
+    }
+
+    #[test]
+    fn test_aberth_cube_roots_of_unity() {
+        // z³ - 1
+        let coeffs = real_coeffs(&[-1.0, 0.0, 0.0, 1.0]);
+        let roots = aberth(&coeffs, 1e-14, 100);
+        assert_eq!(roots.len(), 3);
+
+        let expected = [
+            Complex64::new(1.0, 0.0),
+            Complex64::new(-0.5, 3.0_f64.sqrt() / 2.0),
+            Complex64::new(-0.5, -(3.0_f64.sqrt()) / 2.0),
+        ];
+        for e in expected {
+            assert!(roots.iter().any(|r| (*r - e).norm() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_aberth_double_root() {
+        // (z - 1)²(z + 2) = z³ - 3z + 2
+        let coeffs = real_coeffs(&[2.0, -3.0, 0.0, 1.0]);
+        let roots = aberth(&coeffs, 1e-12, 500);
+
+        // The simple root is found to full accuracy
+        assert!(
+            roots
+                .iter()
+                .any(|r| (*r - Complex64::new(-2.0, 0.0)).norm() < 1e-12)
+        );
+
+        // The double root only to about √ε
+        let near_one = roots
+            .iter()
+            .filter(|r| (**r - Complex64::ONE).norm() < 1e-6)
+            .count();
+        assert_eq!(near_one, 2);
+    }
+
+    #[test]
+    fn test_aberth_complex_coefficients() {
+        // (z - i)(z - 2) = z² - (2 + i)z + 2i
+        let coeffs = [
+            Complex64::new(0.0, 2.0),
+            Complex64::new(-2.0, -1.0),
+            Complex64::ONE,
+        ];
+        let roots = aberth(&coeffs, 1e-14, 100);
+
+        assert!(roots.iter().any(|r| (*r - Complex64::I).norm() < 1e-12));
+        assert!(
+            roots
+                .iter()
+                .any(|r| (*r - Complex64::new(2.0, 0.0)).norm() < 1e-12)
+        );
+    }
+
+    #[test]
+    fn test_aberth_degenerate_input() {
+        assert!(aberth(&[], 1e-12, 10).is_empty());
+        assert!(aberth(&real_coeffs(&[3.0]), 1e-12, 10).is_empty());
+
+        // Trailing zeros do not add spurious roots: 2z - 4 = 0
+        let roots = aberth(&real_coeffs(&[-4.0, 2.0, 0.0]), 1e-14, 50);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - Complex64::new(2.0, 0.0)).norm() < 1e-14);
+    }
+}
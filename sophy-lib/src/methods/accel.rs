@@ -0,0 +1,228 @@
+//! # Series Acceleration
+//!
+//! Sums of slowly converging infinite series Σ aₖ from finitely many terms. The
+//! partial sums Sₙ = a₀ + ... + aₙ₋₁ are computed and then transformed into a
+//! better estimate of the limit, with the transformation chosen by [`AccelMethod`]
+//! according to how the series converges.
+
+use crate::base::numbers::EPSILON;
+
+/// Number of Aitken Δ² passes applied by [`AccelMethod::Aitken`]
+const AITKEN_PASSES: usize = 3;
+
+/// Maximum number of averaging passes applied by [`AccelMethod::EulerTransform`]
+const EULER_PASSES: usize = 20;
+
+/// Number of partial sums, at n = N, N/2, N/4, ..., extrapolated by
+/// [`AccelMethod::Richardson`]
+const RICHARDSON_LEVELS: usize = 5;
+
+/// The transformation [`accelerate_sum`] applies to the partial sums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccelMethod {
+    /// The last partial sum, without acceleration
+    Direct,
+    /// Repeated Aitken Δ² extrapolation, S - (ΔS)²/Δ²S, of the last partial sums.
+    /// Exact for geometric convergence and effective whenever the error shrinks by
+    /// a roughly constant factor per term.
+    Aitken,
+    /// Euler's transformation, as repeated averaging of neighbouring partial sums
+    /// from the first third of them on, but at most the last 21 (van Wijngaarden's
+    /// form). Suited to alternating series, whose partial sums oscillate around
+    /// the limit.
+    EulerTransform,
+    /// Richardson extrapolation to n → ∞ of the partial sums at n = N, N/2, N/4,
+    /// ..., modelling the error as a polynomial in 1/n. Suited to series with
+    /// terms decaying like a power of k, such as Σ 1/k². Five levels are used, so
+    /// the error model must already hold at N/16 terms.
+    Richardson,
+}
+
+/// Estimates the sum of an infinite series from its first `max_terms` terms.
+///
+/// ## Arguments
+///
+/// * `term` - The k-th term aₖ of the series, for k = 0, 1, 2, ...
+/// * `method` - The transformation applied to the partial sums.
+/// * `max_terms` - Number of terms evaluated.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::accel::{AccelMethod, accelerate_sum};
+/// use std::f64::consts::PI;
+///
+/// // Leibniz series 1 - 1/3 + 1/5 - ... = π/4
+/// let leibniz = |k: u64| if k.is_multiple_of(2) { 1.0 } else { -1.0 } / (2 * k + 1) as f64;
+///
+/// let direct = accelerate_sum(leibniz, AccelMethod::Direct, 30);
+/// let euler = accelerate_sum(leibniz, AccelMethod::EulerTransform, 30);
+/// assert!((direct - PI / 4.0).abs() > 1e-3);
+/// assert!((euler - PI / 4.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `max_terms` is zero.
+pub fn accelerate_sum<T>(term: T, method: AccelMethod, max_terms: usize) -> f64
+where
+    T: Fn(u64) -> f64,
+{
+    if max_terms == 0 {
+        panic!("Series acceleration requires at least one term");
+    }
+
+    let partial_sums: Vec<f64> = (0..max_terms as u64)
+        .scan(0.0, |sum, k| {
+            *sum += term(k);
+            Some(*sum)
+        })
+        .collect();
+
+    match method {
+        AccelMethod::Direct => partial_sums[max_terms - 1],
+        AccelMethod::Aitken => aitken(&partial_sums),
+        AccelMethod::EulerTransform => euler_transform(&partial_sums),
+        AccelMethod::Richardson => richardson(&partial_sums),
+    }
+}
+
+/// Iterated Aitken Δ² on the last 2·[`AITKEN_PASSES`] + 1 partial sums
+fn aitken(sums: &[f64]) -> f64 {
+    let start = sums.len().saturating_sub(2 * AITKEN_PASSES + 1);
+    let mut row = sums[start..].to_vec();
+
+    while row.len() >= 3 {
+        row = row
+            .windows(3)
+            .map(|w| {
+                let second_difference = w[2] - 2.0 * w[1] + w[0];
+                // Converged to rounding: there is nothing left to extrapolate
+                if second_difference.abs() <= EPSILON * w[2].abs() {
+                    w[2]
+                } else {
+                    w[2] - (w[2] - w[1]).powi(2) / second_difference
+                }
+            })
+            .collect();
+    }
+
+    row[row.len() - 1]
+}
+
+/// Repeated pairwise averaging of the partial sums from the first third on,
+/// limited to the last [`EULER_PASSES`] + 1 so the cost stays independent of the
+/// number of terms
+fn euler_transform(sums: &[f64]) -> f64 {
+    let start = (sums.len() / 3).max(sums.len().saturating_sub(EULER_PASSES + 1));
+    let mut row = sums[start..].to_vec();
+
+    while row.len() > 1 {
+        row = row.windows(2).map(|w| 0.5 * (w[0] + w[1])).collect();
+    }
+
+    row[0]
+}
+
+/// Neville extrapolation of Sₙ to h = 1/n → 0 from n = N, N/2, N/4, ...
+fn richardson(sums: &[f64]) -> f64 {
+    let mut counts: Vec<usize> = std::iter::successors(Some(sums.len()), |&n| Some(n / 2))
+        .take_while(|&n| n >= 1)
+        .take(RICHARDSON_LEVELS)
+        .collect();
+    counts.reverse();
+
+    let steps: Vec<f64> = counts.iter().map(|&n| 1.0 / n as f64).collect();
+    let mut table: Vec<f64> = counts.iter().map(|&n| sums[n - 1]).collect();
+
+    for m in 1..table.len() {
+        for i in 0..table.len() - m {
+            let (near, far) = (steps[i + m], steps[i]);
+            table[i] = (far * table[i + 1] - near * table[i]) / (far - near);
+        }
+    }
+
+    table[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::numbers::{EULER, PI};
+
+    const METHODS: [AccelMethod; 4] = [
+        AccelMethod::Direct,
+        AccelMethod::Aitken,
+        AccelMethod::EulerTransform,
+        AccelMethod::Richardson,
+    ];
+
+    fn leibniz(k: u64) -> f64 {
+        let sign = if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+        sign / (2 * k + 1) as f64
+    }
+
+    #[test]
+    fn test_euler_transform_leibniz() {
+        let direct = accelerate_sum(leibniz, AccelMethod::Direct, 30);
+        assert!((direct - PI / 4.0).abs() > 1e-3);
+
+        let euler = accelerate_sum(leibniz, AccelMethod::EulerTransform, 30);
+        assert!((euler - PI / 4.0).abs() < 1e-14);
+
+        // Even a million direct terms stay far behind
+        let direct = accelerate_sum(leibniz, AccelMethod::Direct, 1_000_000);
+        assert!((direct - PI / 4.0).abs() > 1e-7);
+
+        // Only the last partial sums are averaged, so many terms stay cheap
+        let euler = accelerate_sum(leibniz, AccelMethod::EulerTransform, 1_000_000);
+        assert!((euler - PI / 4.0).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_aitken_leibniz() {
+        let aitken = accelerate_sum(leibniz, AccelMethod::Aitken, 30);
+        assert!((aitken - PI / 4.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_richardson_basel() {
+        // Σ 1/(k + 1)² = π²/6, with error ≈ 1/n after n terms
+        let basel = |k: u64| 1.0 / ((k + 1) * (k + 1)) as f64;
+        let exact = PI * PI / 6.0;
+
+        let direct = accelerate_sum(basel, AccelMethod::Direct, 128);
+        let richardson = accelerate_sum(basel, AccelMethod::Richardson, 128);
+        assert!((direct - exact).abs() > 1e-3);
+        assert!((richardson - exact).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fast_series_all_methods_agree() {
+        // Σ 1/k! = e; Richardson needs N/16 terms to have converged as well
+        let inverse_factorial = |k: u64| 1.0 / (1..=k).map(|i| i as f64).product::<f64>();
+        for method in METHODS {
+            let sum = accelerate_sum(inverse_factorial, method, 400);
+            assert!((sum - EULER).abs() < 1e-15, "{method:?}: {sum}");
+        }
+
+        // Geometric series Σ 2⁻ᵏ = 2
+        for method in METHODS {
+            let sum = accelerate_sum(|k| 0.5_f64.powi(k as i32), method, 1000);
+            assert!((sum - 2.0).abs() < 1e-15, "{method:?}: {sum}");
+        }
+    }
+
+    #[test]
+    fn test_few_terms() {
+        for method in METHODS {
+            assert_eq!(accelerate_sum(|_| 1.5, method, 1), 1.5, "{method:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Series acceleration requires at least one term")]
+    fn test_zero_terms() {
+        accelerate_sum(leibniz, AccelMethod::Direct, 0);
+    }
+}
@@ -0,0 +1,275 @@
+//! # Minimax Polynomial Approximation
+//!
+//! The Remez exchange algorithm for the polynomial of a given degree that
+//! minimizes the maximum error max|f(x) - p(x)| on an interval.
+//!
+//! ## Equioscillation
+//!
+//! By Chebyshev's alternation theorem, p of degree n is the minimax approximation
+//! exactly when the error f - p attains its maximum magnitude E at n + 2 points with
+//! alternating signs. The algorithm alternates between two steps:
+//!
+//! 1. Given a reference x₀ < x₁ < ... < xₙ₊₁, solve the linear system
+//!    **p(xᵢ) + (-1)ⁱ·E = f(xᵢ)** for the coefficients of p and the level E
+//! 2. Replace the reference by the alternating extrema of the new error curve
+//!
+//! Near the solution the levels converge quadratically.
+
+use crate::base::matrix::Matrix;
+use crate::base::numbers::PI;
+use crate::base::poly::Polynomial;
+
+/// Number of error samples per reference point used to locate the extrema
+const SAMPLES_PER_POINT: usize = 500;
+
+/// Computes the minimax polynomial approximation of `f` on `[a, b]`.
+///
+/// The reference starts at the Chebyshev extrema of `[a, b]`, for which the
+/// interpolating polynomial is already close to minimax, and is then exchanged for
+/// the alternating extrema of the error curve, found on a dense sampling grid.
+/// Internally the problem is solved on [-1, 1] for conditioning and the result is
+/// converted to the monomial basis in `x`.
+///
+/// ## Arguments
+///
+/// * `f` - The function to approximate. It should be continuous on `[a, b]`.
+/// * `a`, `b` - Interval endpoints with `a < b`.
+/// * `degree` - Degree of the approximating polynomial.
+/// * `iterations` - Maximum number of exchange steps. The loop stops early when
+///   the error is already equioscillating or `f` is reproduced exactly.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::approx::remez;
+///
+/// let p = remez(|x: f64| x.exp(), -1.0, 1.0, 4, 10);
+///
+/// let max_error = (0..=1000)
+///     .map(|i| -1.0 + 2.0 * i as f64 / 1000.0)
+///     .map(|x| (x.exp() - p.eval(x)).abs())
+///     .fold(0.0, f64::max);
+/// assert!(max_error < 6e-4);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a >= b`.
+pub fn remez<F>(f: F, a: f64, b: f64, degree: usize, iterations: usize) -> Polynomial
+where
+    F: Fn(f64) -> f64,
+{
+    if a >= b {
+        panic!("Remez algorithm requires an interval with a < b");
+    }
+
+    let mid = 0.5 * (a + b);
+    let half = 0.5 * (b - a);
+    let g = |t: f64| f(mid + half * t);
+
+    let n = degree + 2;
+    let mut reference: Vec<f64> = (0..n)
+        .map(|i| -(PI * i as f64 / (n - 1) as f64).cos())
+        .collect();
+
+    let grid: Vec<f64> = (0..=SAMPLES_PER_POINT * n)
+        .map(|i| -1.0 + 2.0 * i as f64 / (SAMPLES_PER_POINT * n) as f64)
+        .collect();
+    let samples: Vec<f64> = grid.iter().map(|&t| g(t)).collect();
+
+    let mut coeffs = vec![0.0; degree + 1];
+
+    for _ in 0..iterations.max(1) {
+        let (next, level) = match solve_reference(&g, &reference) {
+            Some(solution) => solution,
+            None => break,
+        };
+        coeffs = next;
+
+        let errors: Vec<f64> = grid
+            .iter()
+            .zip(&samples)
+            .map(|(&t, &y)| y - horner(&coeffs, t))
+            .collect();
+        let extrema = alternating_extrema(&errors, n);
+
+        let max_error = errors.iter().fold(0.0_f64, |m, e| m.max(e.abs()));
+        if extrema.len() < n || max_error <= level.abs() * (1.0 + 1e-12) {
+            break;
+        }
+
+        reference = extrema.iter().map(|&i| grid[i]).collect();
+    }
+
+    Polynomial::new(to_monomial(&coeffs, mid, half))
+}
+
+/// Solves p(tᵢ) + (-1)ⁱ·E = g(tᵢ), returning the coefficients of p and the level E
+fn solve_reference<G>(g: &G, reference: &[f64]) -> Option<(Vec<f64>, f64)>
+where
+    G: Fn(f64) -> f64,
+{
+    let n = reference.len();
+    let mut system = Matrix::zeros(n, n);
+    let mut rhs = vec![0.0; n];
+
+    for (i, &t) in reference.iter().enumerate() {
+        let mut power = 1.0;
+        for k in 0..n - 1 {
+            system[(i, k)] = power;
+            power *= t;
+        }
+        system[(i, n - 1)] = if i.is_multiple_of(2) { 1.0 } else { -1.0 };
+        rhs[i] = g(t);
+    }
+
+    let mut solution = system.solve(&rhs)?;
+    let level = solution.pop()?;
+    Some((solution, level))
+}
+
+/// Indices of the largest error magnitude in each run of constant sign, reduced to
+/// at most `count` alternating extrema by discarding the smallest ones
+fn alternating_extrema(errors: &[f64], count: usize) -> Vec<usize> {
+    let mut extrema: Vec<usize> = Vec::new();
+
+    for (i, &e) in errors.iter().enumerate() {
+        if e == 0.0 {
+            continue;
+        }
+        match extrema.last() {
+            Some(&last) if errors[last].signum() == e.signum() => {
+                if e.abs() > errors[last].abs() {
+                    *extrema.last_mut().unwrap() = i;
+                }
+            }
+            _ => extrema.push(i),
+        }
+    }
+
+    // Removing an end point or two neighbours keeps the signs alternating
+    while extrema.len() > count {
+        let magnitude = |k: usize| errors[extrema[k]].abs();
+        let last = extrema.len() - 1;
+
+        if extrema.len() == count + 1 {
+            let drop = if magnitude(0) < magnitude(last) {
+                0
+            } else {
+                last
+            };
+            extrema.remove(drop);
+            continue;
+        }
+
+        let smallest = (0..=last)
+            .min_by(|&i, &j| magnitude(i).total_cmp(&magnitude(j)))
+            .unwrap();
+        if smallest == 0 || smallest == last {
+            extrema.remove(smallest);
+        } else {
+            let neighbour = if magnitude(smallest - 1) < magnitude(smallest + 1) {
+                smallest - 1
+            } else {
+                smallest + 1
+            };
+            extrema.remove(smallest.max(neighbour));
+            extrema.remove(smallest.min(neighbour));
+        }
+    }
+
+    extrema
+}
+
+/// Evaluates a polynomial with ascending coefficients at `t`
+fn horner(coeffs: &[f64], t: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, &c| acc * t + c)
+}
+
+/// Converts Σ cₖtᵏ with t = (x - mid)/half into ascending coefficients in x
+fn to_monomial(coeffs: &[f64], mid: f64, half: f64) -> Vec<f64> {
+    let mut result = vec![0.0; coeffs.len()];
+    let mut power = vec![1.0];
+
+    for &c in coeffs {
+        for (r, &p) in result.iter_mut().zip(&power) {
+            *r += c * p;
+        }
+
+        // power ← power · (x - mid)/half
+        let mut next = vec![0.0; power.len() + 1];
+        for (k, &p) in power.iter().enumerate() {
+            next[k + 1] += p / half;
+            next[k] -= p * mid / half;
+        }
+        power = next;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_curve<F: Fn(f64) -> f64>(f: F, p: &Polynomial, a: f64, b: f64) -> Vec<f64> {
+        (0..=20_000)
+            .map(|i| a + (b - a) * i as f64 / 20_000.0)
+            .map(|x| f(x) - p.eval(x))
+            .collect()
+    }
+
+    #[test]
+    fn test_remez_exp_equioscillates() {
+        let degree = 4;
+        let p = remez(|x: f64| x.exp(), -1.0, 1.0, degree, 20);
+        assert_eq!(p.degree(), degree);
+
+        let errors = error_curve(|x: f64| x.exp(), &p, -1.0, 1.0);
+        let max_error = errors.iter().fold(0.0_f64, |m, e| m.max(e.abs()));
+        assert!(max_error < 6e-4);
+
+        // degree + 2 alternating extrema of (nearly) equal magnitude
+        let extrema = alternating_extrema(&errors, degree + 2);
+        assert_eq!(extrema.len(), degree + 2);
+        for &i in &extrema {
+            assert!(errors[i].abs() > 0.999 * max_error);
+        }
+    }
+
+    #[test]
+    fn test_remez_beats_taylor() {
+        let p = remez(|x: f64| x.sin(), 0.0, 1.0, 3, 20);
+        let errors = error_curve(|x: f64| x.sin(), &p, 0.0, 1.0);
+        let max_error = errors.iter().fold(0.0_f64, |m, e| m.max(e.abs()));
+
+        // The degree-3 Taylor polynomial x - x³/6 is off by ≈ 8e-3 at x = 1
+        let taylor_error = (1.0_f64.sin() - (1.0 - 1.0 / 6.0)).abs();
+        assert!(max_error < 2e-4);
+        assert!(max_error < taylor_error / 40.0);
+    }
+
+    #[test]
+    fn test_remez_degree_zero_is_midrange() {
+        // sin on [0, π] ranges over [0, 1]
+        let p = remez(|x: f64| x.sin(), 0.0, PI, 0, 10);
+        assert_eq!(p.degree(), 0);
+        assert!((p.eval(0.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_remez_reproduces_polynomials() {
+        let f = |x: f64| 2.0 - x + 0.5 * x * x;
+        let p = remez(f, 1.0, 3.0, 2, 10);
+        let expected = [2.0, -1.0, 0.5];
+        for (c, e) in p.coeffs().iter().zip(expected) {
+            assert!((c - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Remez algorithm requires an interval with a < b")]
+    fn test_remez_invalid_interval() {
+        remez(|x| x, 1.0, 1.0, 2, 5);
+    }
+}
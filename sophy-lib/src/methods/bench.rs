@@ -0,0 +1,237 @@
+//! # Root-Finder Comparison
+//!
+//! Runs several root-finding methods on the same problem and reports how much work
+//! each one needed, so the best method for a given function can be chosen
+//! empirically rather than by rule of thumb.
+//!
+//! Work is measured in iterations and function evaluations (derivative evaluations
+//! included), not wall time, so results are deterministic and comparable across
+//! machines.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use sophy::methods::bench::{compare_solvers, RootProblem, SolverKind};
+//!
+//! let problem = RootProblem::new(|x: f64| x * x - 2.0, (0.0, 2.0), 2.0_f64.sqrt())
+//!     .with_derivative(|x: f64| 2.0 * x);
+//!
+//! let results = compare_solvers(&problem, &[SolverKind::Bisection, SolverKind::Brent]);
+//! assert!(results.iter().all(|r| r.converged));
+//! assert!(results[1].evaluations < results[0].evaluations);
+//! ```
+
+use crate::methods::bisection::{RootError, bisection_counted};
+use crate::methods::brent::brent_counted;
+use crate::methods::raphson::try_raphson_counted;
+use crate::methods::secant::secant_counted;
+use std::cell::Cell;
+
+/// A root-finding test problem with a known solution
+pub struct RootProblem<'a> {
+    f: Box<dyn Fn(f64) -> f64 + 'a>,
+    df: Option<Box<dyn Fn(f64) -> f64 + 'a>>,
+    bracket: (f64, f64),
+    root: f64,
+    tol: f64,
+    max_iter: usize,
+}
+
+impl<'a> RootProblem<'a> {
+    /// Creates a problem for `f` with a sign-changing `bracket` around the known `root`
+    ///
+    /// The tolerance defaults to 1e-12 and the iteration limit to 200.
+    pub fn new<F>(f: F, bracket: (f64, f64), root: f64) -> Self
+    where
+        F: Fn(f64) -> f64 + 'a,
+    {
+        RootProblem {
+            f: Box::new(f),
+            df: None,
+            bracket,
+            root,
+            tol: 1e-12,
+            max_iter: 200,
+        }
+    }
+
+    /// Adds the derivative of `f`, which Newton's method requires
+    pub fn with_derivative<DF>(mut self, df: DF) -> Self
+    where
+        DF: Fn(f64) -> f64 + 'a,
+    {
+        self.df = Some(Box::new(df));
+        self
+    }
+
+    /// Sets the convergence tolerance on the root
+    pub fn with_tolerance(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Sets the maximum number of iterations per solver
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        self.max_iter = max_iter;
+        self
+    }
+}
+
+/// The root-finding methods available for comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverKind {
+    /// Newton-Raphson from the bracket midpoint; needs the derivative
+    Newton,
+    /// Secant method from the two bracket endpoints
+    Secant,
+    /// Interval bisection
+    Bisection,
+    /// Brent's method: inverse quadratic interpolation safeguarded by bisection
+    Brent,
+}
+
+/// The outcome of running one solver on a [`RootProblem`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverResult {
+    /// The method that produced this result
+    pub solver: SolverKind,
+    /// The final estimate of the root
+    pub root: f64,
+    /// Absolute distance from the known root
+    pub error: f64,
+    /// Number of iterations performed
+    pub iterations: usize,
+    /// Number of evaluations of `f` and its derivative
+    pub evaluations: usize,
+    /// Whether the method met the tolerance within the iteration limit
+    pub converged: bool,
+}
+
+/// Runs each of `solvers` on `problem` and reports their results in the same order.
+///
+/// Newton's method is reported as not converged, with zero evaluations, if the
+/// problem has no derivative. The bracketing methods likewise fail immediately if
+/// `f` does not change sign over the bracket.
+pub fn compare_solvers(problem: &RootProblem, solvers: &[SolverKind]) -> Vec<SolverResult> {
+    solvers
+        .iter()
+        .map(|&solver| {
+            let evaluations = Cell::new(0);
+            let f = |x: f64| {
+                evaluations.set(evaluations.get() + 1);
+                (problem.f)(x)
+            };
+
+            let (a, b) = problem.bracket;
+            let (tol, max_iter) = (problem.tol, problem.max_iter);
+            let (root, iterations, converged) = match solver {
+                SolverKind::Newton => match &problem.df {
+                    Some(df) => {
+                        let df = |x: f64| {
+                            evaluations.set(evaluations.get() + 1);
+                            df(x)
+                        };
+                        let midpoint = 0.5 * (a + b);
+                        outcome(try_raphson_counted(midpoint, &f, &df, tol, max_iter))
+                    }
+                    None => (f64::NAN, 0, false),
+                },
+                SolverKind::Secant => outcome(secant_counted(a, b, &f, tol, max_iter)),
+                SolverKind::Bisection => outcome(bisection_counted(a, b, &f, tol, max_iter)),
+                SolverKind::Brent => outcome(brent_counted(a, b, &f, tol, max_iter)),
+            };
+
+            SolverResult {
+                solver,
+                root,
+                error: (root - problem.root).abs(),
+                iterations,
+                evaluations: evaluations.get(),
+                converged,
+            }
+        })
+        .collect()
+}
+
+/// The root, iteration count and convergence of a counted solver run
+fn outcome((result, iterations): (Result<f64, RootError>, usize)) -> (f64, usize, bool) {
+    match result {
+        Ok(root) => (root, iterations, true),
+        Err(RootError::MaxIterationsReached { last, .. }) => (last, iterations, false),
+        Err(_) => (f64::NAN, iterations, false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [SolverKind; 4] = [
+        SolverKind::Newton,
+        SolverKind::Secant,
+        SolverKind::Bisection,
+        SolverKind::Brent,
+    ];
+
+    fn sqrt2_problem() -> RootProblem<'static> {
+        RootProblem::new(|x: f64| x * x - 2.0, (0.0, 2.0), 2.0_f64.sqrt())
+            .with_derivative(|x: f64| 2.0 * x)
+    }
+
+    #[test]
+    fn test_compare_solvers_sqrt2() {
+        let results = compare_solvers(&sqrt2_problem(), &ALL);
+        assert_eq!(results.len(), 4);
+
+        for (result, kind) in results.iter().zip(ALL) {
+            assert_eq!(result.solver, kind);
+            assert!(result.converged, "{kind:?} did not converge");
+            assert!(result.error < 1e-11, "{kind:?} error {}", result.error);
+        }
+
+        let evals = |kind: SolverKind| {
+            results
+                .iter()
+                .find(|r| r.solver == kind)
+                .unwrap()
+                .evaluations
+        };
+
+        // Bisection gains one bit per evaluation and is by far the most expensive
+        assert!(evals(SolverKind::Bisection) > 30);
+        for kind in [SolverKind::Newton, SolverKind::Secant, SolverKind::Brent] {
+            assert!(evals(kind) < evals(SolverKind::Bisection) / 2);
+        }
+
+        // Newton needs the fewest iterations, at two evaluations each
+        let newton = results[0];
+        assert!(results.iter().all(|r| newton.iterations <= r.iterations));
+        assert_eq!(newton.evaluations, 2 * newton.iterations);
+    }
+
+    #[test]
+    fn test_compare_solvers_missing_derivative() {
+        let problem = RootProblem::new(|x: f64| x.cos() - x, (0.0, 1.0), 0.739_085_133_215_160_6);
+        let results = compare_solvers(&problem, &[SolverKind::Newton, SolverKind::Brent]);
+
+        assert!(!results[0].converged);
+        assert_eq!(results[0].evaluations, 0);
+        assert!(results[1].converged);
+        assert!(results[1].error < 1e-12);
+    }
+
+    #[test]
+    fn test_compare_solvers_invalid_bracket() {
+        let problem = RootProblem::new(|x: f64| x * x + 1.0, (-1.0, 1.0), f64::NAN);
+        let results = compare_solvers(&problem, &[SolverKind::Bisection, SolverKind::Brent]);
+        assert!(results.iter().all(|r| !r.converged && r.iterations == 0));
+    }
+
+    #[test]
+    fn test_compare_solvers_iteration_limit() {
+        let problem = sqrt2_problem().with_max_iter(5);
+        let bisection = compare_solvers(&problem, &[SolverKind::Bisection])[0];
+        assert!(!bisection.converged);
+        assert_eq!(bisection.iterations, 5);
+    }
+}
@@ -0,0 +1,270 @@
+//! # Bisection Method
+//!
+//! Root finding by repeatedly halving an interval on which the function changes
+//! sign. Only function values are used, so unlike [`raphson`](super::raphson())
+//! no derivative is needed and the function may be non-smooth. Convergence is
+//! linear, one bit of accuracy per iteration, but guaranteed for any continuous
+//! function with a sign change.
+
+use crate::base::numbers::same_sign;
+use std::fmt;
+
+/// The reason a real root finder failed to produce a root
+///
+/// Returned by [`bisection`], [`secant`](super::secant::secant),
+/// [`try_raphson`](super::raphson::try_raphson) and [`halley`](super::halley::halley).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError {
+    /// f has the same sign at both ends, so no root is bracketed
+    NoSignChange {
+        /// f at the lower end of the interval
+        f_a: f64,
+        /// f at the upper end of the interval
+        f_b: f64,
+    },
+    /// f took (nearly) equal values at two iterates, so the secant through
+    /// them has no root
+    FlatSecant {
+        /// The latest iterate
+        at: f64,
+    },
+    /// The derivative became too small to take a Newton step, or Halley's
+    /// denominator 2f'² - f·f'' did
+    DerivativeTooSmall {
+        /// The iterate at which the derivative fell below the tolerance
+        x: f64,
+    },
+    /// The estimate had not settled to the tolerance after the iteration limit
+    MaxIterationsReached {
+        /// The last estimate of the root
+        last: f64,
+        /// The number of iterations performed, equal to the limit
+        iterations: usize,
+    },
+    /// f or a derivative returned NaN, or an iterate was not finite
+    NanEncountered,
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NoSignChange { f_a, f_b } => write!(
+                f,
+                "no sign change on the interval (f(a) = {f_a:e}, f(b) = {f_b:e})"
+            ),
+            RootError::FlatSecant { at } => {
+                write!(f, "secant slope vanished at x = {at}")
+            }
+            RootError::DerivativeTooSmall { x } => {
+                write!(f, "derivative too small at x = {x}")
+            }
+            RootError::MaxIterationsReached { last, iterations } => {
+                write!(f, "iteration limit of {iterations} reached at x = {last}")
+            }
+            RootError::NanEncountered => f.write_str("NaN encountered during the iteration"),
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+/// Bisection root-finding method.
+///
+/// Halves the interval [a, b], keeping the half on which f changes sign, until
+/// it is narrower than 2·`tol`, and returns its midpoint.
+///
+/// ## Arguments
+///
+/// * `a`, `b` - Interval endpoints, in either order.
+/// * `f` - A function that is continuous on the interval.
+/// * `tol` - Maximum distance of the result from the bracketed root.
+/// * `max_iter` - Maximum number of halvings.
+///
+/// ## Returns
+///
+/// The root, which is an endpoint exactly if f vanishes there, or
+/// - [`RootError::NoSignChange`] if f(a) and f(b) have the same sign
+/// - [`RootError::MaxIterationsReached`] if the interval is still wider than 2·`tol`
+///   after `max_iter` halvings
+/// - [`RootError::NanEncountered`] if f returns NaN at an endpoint or midpoint
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::bisection::{RootError, bisection};
+///
+/// // √2 as the root of x² - 2, without a derivative
+/// let root = bisection(1.0, 2.0, |x| x * x - 2.0, 1e-12, 100).unwrap();
+/// assert!((root - 2.0_f64.sqrt()).abs() < 1e-12);
+///
+/// // x² + 1 has no real roots
+/// let error = bisection(-1.0, 1.0, |x| x * x + 1.0, 1e-12, 100);
+/// assert!(matches!(error, Err(RootError::NoSignChange { .. })));
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `tol` is not positive.
+pub fn bisection<F>(a: f64, b: f64, f: F, tol: f64, max_iter: usize) -> Result<f64, RootError>
+where
+    F: Fn(f64) -> f64,
+{
+    bisection_counted(a, b, &f, tol, max_iter).0
+}
+
+/// [`bisection`], also returning the number of iterations performed
+pub(crate) fn bisection_counted<F>(
+    a: f64,
+    b: f64,
+    f: &F,
+    tol: f64,
+    max_iter: usize,
+) -> (Result<f64, RootError>, usize)
+where
+    F: Fn(f64) -> f64,
+{
+    if tol <= 0.0 {
+        panic!("Bisection tolerance must be positive");
+    }
+
+    let (mut a, mut b) = if a <= b { (a, b) } else { (b, a) };
+    let mut f_a = f(a);
+    let f_b = f(b);
+
+    if f_a.is_nan() || f_b.is_nan() {
+        return (Err(RootError::NanEncountered), 0);
+    }
+    if f_a == 0.0 {
+        return (Ok(a), 0);
+    }
+    if f_b == 0.0 {
+        return (Ok(b), 0);
+    }
+    if same_sign(f_a, f_b) {
+        return (Err(RootError::NoSignChange { f_a, f_b }), 0);
+    }
+
+    for iteration in 1..=max_iter {
+        let mid = 0.5 * (a + b);
+        // Stop once the interval cannot be split further in floating point
+        if 0.5 * (b - a) < tol || mid <= a || mid >= b {
+            return (Ok(mid), iteration);
+        }
+
+        let f_mid = f(mid);
+        if f_mid.is_nan() {
+            return (Err(RootError::NanEncountered), iteration);
+        }
+        if f_mid == 0.0 {
+            return (Ok(mid), iteration);
+        }
+        if same_sign(f_a, f_mid) {
+            a = mid;
+            f_a = f_mid;
+        } else {
+            b = mid;
+        }
+    }
+
+    let result = if 0.5 * (b - a) < tol {
+        Ok(0.5 * (a + b))
+    } else {
+        Err(RootError::MaxIterationsReached {
+            last: 0.5 * (a + b),
+            iterations: max_iter,
+        })
+    };
+    (result, max_iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::testing::assert_converges;
+
+    #[test]
+    fn test_bisection_sqrt2() {
+        let root = bisection(1.0, 2.0, |x| x * x - 2.0, 1e-12, 100);
+        assert_converges(root, 2.0_f64.sqrt(), 1e-12);
+    }
+
+    #[test]
+    fn test_bisection_cos() {
+        let root = bisection(0.0, 2.0, f64::cos, 1e-14, 100).unwrap();
+        assert!((root - 0.5 * std::f64::consts::PI).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_bisection_non_smooth() {
+        // |x - 1| - 0.5 has a kink at 1, and roots at 0.5 and 1.5
+        let root = bisection(1.0, 3.0, |x: f64| (x - 1.0).abs() - 0.5, 1e-12, 100).unwrap();
+        assert!((root - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bisection_swapped_interval() {
+        let forward = bisection(1.0, 2.0, |x| x * x - 2.0, 1e-12, 100).unwrap();
+        let reversed = bisection(2.0, 1.0, |x| x * x - 2.0, 1e-12, 100).unwrap();
+        assert_eq!(forward, reversed);
+    }
+
+    #[test]
+    fn test_bisection_root_at_endpoint() {
+        assert_eq!(bisection(2.0, 5.0, |x| x - 2.0, 1e-12, 100), Ok(2.0));
+        assert_eq!(bisection(-3.0, 2.0, |x| x - 2.0, 1e-12, 100), Ok(2.0));
+        // Even when f does not change sign elsewhere
+        assert_eq!(
+            bisection(0.0, 4.0, |x| (x - 4.0).powi(2), 1e-12, 100),
+            Ok(4.0)
+        );
+    }
+
+    #[test]
+    fn test_bisection_no_sign_change() {
+        assert_eq!(
+            bisection(-1.0, 1.0, |x| x * x + 1.0, 1e-12, 100),
+            Err(RootError::NoSignChange { f_a: 2.0, f_b: 2.0 })
+        );
+    }
+
+    #[test]
+    fn test_bisection_nan() {
+        // √x - 1 is NaN on the negative half of the interval
+        assert_eq!(
+            bisection(-1.0, 4.0, |x: f64| x.sqrt() - 1.0, 1e-12, 200),
+            Err(RootError::NanEncountered)
+        );
+        // NaN only at an interior midpoint
+        let f = |x: f64| if x == 1.5 { f64::NAN } else { x - 1.25 };
+        assert_eq!(
+            bisection(1.0, 2.0, f, 1e-12, 100),
+            Err(RootError::NanEncountered)
+        );
+    }
+
+    #[test]
+    fn test_bisection_max_iterations() {
+        // Ten halvings of [1, 2] leave a width of 2⁻¹⁰
+        assert_eq!(
+            bisection(1.0, 2.0, |x| x * x - 2.0, 1e-12, 10),
+            Err(RootError::MaxIterationsReached {
+                last: 1.41455078125,
+                iterations: 10
+            })
+        );
+        assert_eq!(
+            RootError::MaxIterationsReached {
+                last: 1.25,
+                iterations: 2
+            }
+            .to_string(),
+            "iteration limit of 2 reached at x = 1.25"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Bisection tolerance must be positive")]
+    fn test_bisection_invalid_tolerance() {
+        let _ = bisection(1.0, 2.0, |x| x * x - 2.0, 0.0, 100);
+    }
+}
@@ -0,0 +1,241 @@
+//! # Brent's Method
+//!
+//! Bracketed root finding that combines the safety of bisection with the speed of
+//! interpolation. Each step tries inverse quadratic interpolation through the last
+//! three points (or the secant through two) and falls back to bisection whenever
+//! the interpolated step is not clearly making progress, so convergence is
+//! guaranteed for any continuous function with a sign change, while smooth
+//! functions converge superlinearly.
+//!
+//! The implementation follows Brent's ZEROIN: `b` is the best estimate so far,
+//! the contrapoint `c` keeps f(b) and f(c) of opposite signs so that the root
+//! stays bracketed between them, and `a` is the previous value of `b`.
+
+use crate::base::numbers::{EPSILON, same_sign};
+use crate::methods::bisection::RootError;
+
+/// Brent's root-finding method.
+///
+/// ## Arguments
+///
+/// * `a`, `b` - Interval endpoints, in either order, at which f has opposite signs.
+/// * `f` - A function that is continuous on the interval.
+/// * `tol` - Width of the final bracket around the root.
+/// * `max_iter` - Maximum number of iterations, each evaluating f once.
+///
+/// ## Returns
+///
+/// The root, which is an endpoint exactly if f vanishes there, or
+/// - [`RootError::NoSignChange`] if f(a) and f(b) have the same sign
+/// - [`RootError::MaxIterationsReached`] if the bracket is still wider than `tol` after
+///   `max_iter` iterations
+/// - [`RootError::NanEncountered`] if f returns NaN at an endpoint or iterate
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::brent::brent;
+///
+/// // The real root of x³ - x - 2
+/// let root = brent(1.0, 2.0, |x: f64| x.powi(3) - x - 2.0, 1e-14, 100).unwrap();
+/// assert!((root.powi(3) - root - 2.0).abs() < 1e-13);
+/// ```
+pub fn brent<F>(a: f64, b: f64, f: F, tol: f64, max_iter: usize) -> Result<f64, RootError>
+where
+    F: Fn(f64) -> f64,
+{
+    brent_counted(a, b, &f, tol, max_iter).0
+}
+
+/// [`brent`], also returning the number of iterations performed
+pub(crate) fn brent_counted<F>(
+    a: f64,
+    b: f64,
+    f: &F,
+    tol: f64,
+    max_iter: usize,
+) -> (Result<f64, RootError>, usize)
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa.is_nan() || fb.is_nan() {
+        return (Err(RootError::NanEncountered), 0);
+    }
+    if same_sign(fa, fb) {
+        return (Err(RootError::NoSignChange { f_a: fa, f_b: fb }), 0);
+    }
+
+    let (mut c, mut fc) = (b, fb);
+    let (mut d, mut e) = (b - a, b - a);
+
+    for iteration in 1..=max_iter {
+        if same_sign(fb, fc) {
+            // Keep the root bracketed between b and c
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol = 2.0 * EPSILON * b.abs() + 0.5 * tol;
+        let m = 0.5 * (c - b);
+        if m.abs() <= tol || fb == 0.0 {
+            return (Ok(b), iteration);
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // Attempt inverse quadratic interpolation (secant if only two points)
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * m * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            }
+            p = p.abs();
+
+            // Accept the interpolation only if it lands well inside the bracket
+            // and shrinks faster than the step before last; otherwise bisect
+            if 2.0 * p < (3.0 * m * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = d;
+            }
+        } else {
+            d = m;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(m) };
+        fb = f(b);
+        if fb.is_nan() {
+            return (Err(RootError::NanEncountered), iteration);
+        }
+    }
+
+    let error = RootError::MaxIterationsReached {
+        last: b,
+        iterations: max_iter,
+    };
+    (Err(error), max_iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::bisection::bisection;
+    use crate::methods::raphson::try_raphson;
+    use crate::methods::testing::assert_converges;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_brent_cubic() {
+        let f = |x: f64| x.powi(3) - x - 2.0;
+        let root = brent(1.0, 2.0, f, 1e-14, 100).unwrap();
+        assert!((root - 1.521_379_706_804_567_6).abs() < 1e-14);
+
+        // Endpoints in either order
+        assert_eq!(brent(2.0, 1.0, f, 1e-14, 100), Ok(root));
+    }
+
+    #[test]
+    fn test_brent_beats_bisection() {
+        let evaluations = Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x.powi(3) - x - 2.0
+        };
+
+        let root = brent(1.0, 2.0, f, 1e-12, 100).unwrap();
+        let brent_evaluations = evaluations.replace(0);
+        let bisected = bisection(1.0, 2.0, f, 1e-12, 100).unwrap();
+        let bisection_evaluations = evaluations.get();
+
+        assert!((root - bisected).abs() < 2e-12);
+        assert!(
+            3 * brent_evaluations < bisection_evaluations,
+            "{brent_evaluations} vs {bisection_evaluations}"
+        );
+    }
+
+    #[test]
+    fn test_brent_flat_region() {
+        // tanh is flat to rounding far from its step, where Newton's method fails
+        let f = |x: f64| (10.0 * (x - 0.3)).tanh();
+        let df = |x: f64| 10.0 / (10.0 * (x - 0.3)).cosh().powi(2);
+        assert!(matches!(
+            try_raphson(2.0, f, df, 1e-12, 100),
+            Err(RootError::DerivativeTooSmall { .. })
+        ));
+
+        let root = brent(-1.0, 2.0, f, 1e-14, 100).unwrap();
+        assert!((root - 0.3).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_brent_non_smooth() {
+        // A kink at the root and a jump elsewhere do not stop the bracketing
+        let f = |x: f64| if x < 0.7 { -1.0 } else { (x - 0.7).sqrt() };
+        assert_converges(brent(0.0, 2.0, f, 1e-12, 200), 0.7, 1e-12);
+    }
+
+    #[test]
+    fn test_brent_root_at_endpoint() {
+        assert_eq!(brent(2.0, 5.0, |x| x - 2.0, 1e-12, 100), Ok(2.0));
+        assert_eq!(brent(-3.0, 2.0, |x| x - 2.0, 1e-12, 100), Ok(2.0));
+    }
+
+    #[test]
+    fn test_brent_nan() {
+        // √x - 1 is NaN on the negative half of the interval
+        assert_eq!(
+            brent(-1.0, 4.0, |x: f64| x.sqrt() - 1.0, 1e-12, 100),
+            Err(RootError::NanEncountered)
+        );
+        // Finite at both ends, NaN at the first interpolated iterate
+        let f = |x: f64| {
+            if x > 1.0 && x < 3.9 {
+                f64::NAN
+            } else {
+                x - 2.0
+            }
+        };
+        assert_eq!(
+            brent(1.0, 4.0, f, 1e-12, 100),
+            Err(RootError::NanEncountered)
+        );
+    }
+
+    #[test]
+    fn test_brent_errors() {
+        assert_eq!(
+            brent(-1.0, 1.0, |x| x * x + 1.0, 1e-12, 100),
+            Err(RootError::NoSignChange { f_a: 2.0, f_b: 2.0 })
+        );
+        assert!(matches!(
+            brent(1.0, 2.0, |x: f64| x.powi(3) - x - 2.0, 1e-14, 2),
+            Err(RootError::MaxIterationsReached { iterations: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+//! # Chebyshev Proxy Root Finding
+//!
+//! Finds all real roots of a smooth function on an interval by replacing the
+//! function with a Chebyshev interpolant ("proxy") and computing the roots of the
+//! proxy as eigenvalues of its colleague matrix.
+//!
+//! For p(x) = Σ cₖTₖ(x) the recurrence x·Tₖ = (Tₖ₋₁ + Tₖ₊₁)/2 turns root finding
+//! into an eigenvalue problem, in the same way that the companion matrix does for
+//! polynomials in the monomial basis, but without the ill-conditioning of the
+//! monomial basis on an interval.
+//!
+//! ## Algorithm Properties
+//!
+//! - **Global**: Every root in the interval is found at once; no starting guesses
+//!   or bracketing intervals are needed
+//! - **Accuracy**: Limited by how well the interpolant resolves `f`; for analytic
+//!   functions the Chebyshev coefficients decay geometrically, so a moderate degree
+//!   gives roots close to machine precision
+//! - **Cost**: O(n³) for the eigenvalue computation of an n×n matrix
+
+use crate::base::matrix::Matrix;
+use crate::base::numbers::PI;
+
+/// Finds the real roots of `f` on `[a, b]` from a Chebyshev interpolant.
+///
+/// `f` is sampled at the `degree + 1` Chebyshev-Lobatto points of `[a, b]`, the
+/// samples are converted to Chebyshev coefficients, and negligible trailing
+/// coefficients are discarded. The real eigenvalues of the colleague matrix of
+/// the remaining series that lie in `[a, b]` are returned as the roots.
+///
+/// ## Arguments
+///
+/// * `f` - The function whose roots are sought. It should be smooth on `[a, b]`.
+/// * `a`, `b` - Interval endpoints with `a < b`.
+/// * `degree` - Degree of the Chebyshev interpolant. It must be large enough to
+///   resolve `f`; a polynomial of degree `n` is represented exactly by any
+///   `degree >= n`.
+///
+/// ## Returns
+///
+/// The roots in ascending order. Functions without roots on the interval give an
+/// empty vector, while `None` means the roots are unknown: `f` returned NaN or
+/// infinity at a sample point, or the eigenvalues of the colleague matrix could
+/// not be computed.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::chebfun::roots_via_chebyshev;
+///
+/// // cos(x) has roots π/2 and 3π/2 on [0, 5]
+/// let roots = roots_via_chebyshev(|x: f64| x.cos(), 0.0, 5.0, 30).unwrap();
+///
+/// assert_eq!(roots.len(), 2);
+/// assert!((roots[0] - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// assert!((roots[1] - 3.0 * std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+///
+/// ## Multiple Roots
+///
+/// A root of multiplicity m is perturbed to roughly ε^(1/m) by rounding, so the
+/// eigenvalues of a double root may become a complex pair and be discarded.
+///
+/// ## Panics
+///
+/// Panics if `degree` is zero or if `a >= b`.
+pub fn roots_via_chebyshev<F>(f: F, a: f64, b: f64, degree: usize) -> Option<Vec<f64>>
+where
+    F: Fn(f64) -> f64,
+{
+    if degree == 0 {
+        panic!("Chebyshev root finding requires a degree of at least one");
+    }
+    if a >= b {
+        panic!("Chebyshev root finding requires an interval with a < b");
+    }
+
+    let mid = 0.5 * (a + b);
+    let half = 0.5 * (b - a);
+
+    let mut coeffs = chebyshev_coeffs(|t| f(mid + half * t), degree);
+    if coeffs.iter().any(|c| !c.is_finite()) {
+        return None;
+    }
+
+    // Drop the coefficients below the noise level of the interpolant
+    let scale = coeffs.iter().fold(0.0_f64, |m, c| m.max(c.abs()));
+    if scale == 0.0 {
+        return Some(Vec::new());
+    }
+    while coeffs.last().is_some_and(|c| c.abs() <= 1e-13 * scale) {
+        coeffs.pop();
+    }
+
+    let unit_roots = match coeffs.len() - 1 {
+        0 => Vec::new(),
+        1 => vec![-coeffs[0] / coeffs[1]],
+        _ => colleague_eigenvalues(&coeffs)?,
+    };
+
+    let slack = 1e-8;
+    let mut roots: Vec<f64> = unit_roots
+        .into_iter()
+        .filter(|t| t.abs() <= 1.0 + slack)
+        .map(|t| mid + half * t.clamp(-1.0, 1.0))
+        .collect();
+    roots.sort_by(f64::total_cmp);
+    Some(roots)
+}
+
+/// Chebyshev coefficients of the degree-`n` interpolant of `f` on [-1, 1] through
+/// the Chebyshev-Lobatto points cos(πj/n)
+fn chebyshev_coeffs<F>(f: F, n: usize) -> Vec<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let samples: Vec<f64> = (0..=n)
+        .map(|j| f((PI * j as f64 / n as f64).cos()))
+        .collect();
+
+    (0..=n)
+        .map(|k| {
+            let sum: f64 = samples
+                .iter()
+                .enumerate()
+                .map(|(j, &fj)| {
+                    let weight = if j == 0 || j == n { 0.5 } else { 1.0 };
+                    weight * fj * (PI * (j * k) as f64 / n as f64).cos()
+                })
+                .sum();
+            let c = 2.0 * sum / n as f64;
+            if k == 0 || k == n { 0.5 * c } else { c }
+        })
+        .collect()
+}
+
+/// Real eigenvalues of the colleague matrix of Σ cₖTₖ, whose leading
+/// coefficient is nonzero and whose degree is at least two, or `None` if the QR
+/// iteration fails or yields a non-finite eigenvalue
+fn colleague_eigenvalues(coeffs: &[f64]) -> Option<Vec<f64>> {
+    let n = coeffs.len() - 1;
+    let lead = coeffs[n];
+
+    let mut colleague = Matrix::zeros(n, n);
+    colleague[(0, 1)] = 1.0;
+    for k in 1..n - 1 {
+        colleague[(k, k - 1)] = 0.5;
+        colleague[(k, k + 1)] = 0.5;
+    }
+    colleague[(n - 1, n - 2)] = 0.5;
+    for (k, &c) in coeffs[..n].iter().enumerate() {
+        colleague[(n - 1, k)] -= c / (2.0 * lead);
+    }
+
+    let eigenvalues = colleague.eigenvalues()?;
+    if eigenvalues
+        .iter()
+        .any(|z| !(z.re.is_finite() && z.im.is_finite()))
+    {
+        return None;
+    }
+    Some(
+        eigenvalues
+            .into_iter()
+            .filter(|z| z.im.abs() <= 1e-8)
+            .map(|z| z.re)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roots_of_sin_5x() {
+        let roots = roots_via_chebyshev(|x: f64| (5.0 * x).sin(), 0.0, PI, 50).unwrap();
+
+        assert_eq!(roots.len(), 6);
+        for (k, root) in roots.iter().enumerate() {
+            let expected = k as f64 * PI / 5.0;
+            assert!((root - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_polynomial_roots() {
+        // (x - 0.3)(x + 0.7)(x - 1.5)(x + 2): only -0.7 and 0.3 lie in [-1, 1]
+        let p = |x: f64| (x - 0.3) * (x + 0.7) * (x - 1.5) * (x + 2.0);
+        let roots = roots_via_chebyshev(p, -1.0, 1.0, 10).unwrap();
+
+        assert_eq!(roots.len(), 2);
+        assert!((roots[0] + 0.7).abs() < 1e-13);
+        assert!((roots[1] - 0.3).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_linear_and_rootless_functions() {
+        let roots = roots_via_chebyshev(|x: f64| 2.0 * x - 3.0, 0.0, 4.0, 5).unwrap();
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] - 1.5).abs() < 1e-14);
+
+        assert_eq!(
+            roots_via_chebyshev(|x: f64| x.exp(), 0.0, 1.0, 20),
+            Some(vec![])
+        );
+        assert_eq!(roots_via_chebyshev(|_| 0.0, 0.0, 1.0, 4), Some(vec![]));
+    }
+
+    #[test]
+    fn test_eigenvalue_failure_is_not_rootless() {
+        // NaN or infinite samples poison the interpolant; this must not read as
+        // "no roots"
+        let f = |x: f64| if x > 0.5 { f64::NAN } else { x - 0.2 };
+        assert_eq!(roots_via_chebyshev(f, 0.0, 1.0, 10), None);
+        assert_eq!(roots_via_chebyshev(|x: f64| 1.0 / x, 0.0, 1.0, 10), None);
+    }
+
+    #[test]
+    fn test_chebyshev_coeffs_of_t3() {
+        // T₃(x) = 4x³ - 3x
+        let coeffs = chebyshev_coeffs(|x| 4.0 * x.powi(3) - 3.0 * x, 6);
+        for (k, c) in coeffs.iter().enumerate() {
+            let expected = if k == 3 { 1.0 } else { 0.0 };
+            assert!((c - expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Chebyshev root finding requires an interval with a < b")]
+    fn test_invalid_interval() {
+        roots_via_chebyshev(|x| x, 1.0, 0.0, 4);
+    }
+}
@@ -0,0 +1,423 @@
+//! # Numerical Differentiation
+//!
+//! Derivatives of a function estimated from its values, and derivatives of
+//! tabulated values (xᵢ, yᵢ) when only samples, not a function, are available.
+//!
+//! ## Finite Differences of Functions
+//!
+//! The forward difference (f(x + h) - f(x))/h has error O(h), the central
+//! difference (f(x + h) - f(x - h))/(2h) has error O(h²). Shrinking h reduces
+//! this truncation error but magnifies the rounding error in f, about ε·|f|/h,
+//! so a single difference is limited to roughly √ε (forward) or ε^(2/3)
+//! (central) relative accuracy. Richardson extrapolation combines central
+//! differences at h, h/2, h/4, ... to cancel successive even powers of h, and
+//! reaches near machine precision from a moderate h.
+//!
+//! For f: ℝⁿ → ℝ the same central differences along each coordinate axis give
+//! the gradient, and mixed second differences the Hessian.
+//!
+//! ## Finite Differences on Non-Uniform Grids
+//!
+//! At an interior sample with spacings h₋ = xᵢ - xᵢ₋₁ and h₊ = xᵢ₊₁ - xᵢ the
+//! second-order central difference is
+//!
+//! **y'ᵢ ≈ (h₋²·yᵢ₊₁ - h₊²·yᵢ₋₁ + (h₊² - h₋²)·yᵢ) / (h₋·h₊·(h₋ + h₊))**
+//!
+//! which reduces to (yᵢ₊₁ - yᵢ₋₁)/(2h) on a uniform grid and is exact for quadratics.
+
+/// Forward-difference estimate of f'(x): (f(x + h) - f(x))/h.
+///
+/// First-order accurate; a step near √ε·max(1, |x|) balances truncation and
+/// rounding error.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::diff::forward_difference;
+///
+/// let d = forward_difference(f64::exp, 0.0, 1e-8);
+/// assert!((d - 1.0).abs() < 1e-7);
+/// ```
+pub fn forward_difference<F>(f: F, x: f64, h: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    (f(x + h) - f(x)) / h
+}
+
+/// Central-difference estimate of f'(x): (f(x + h) - f(x - h))/(2h).
+///
+/// Second-order accurate and exact for quadratics; a step near
+/// ε^(1/3)·max(1, |x|) balances truncation and rounding error.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::diff::central_difference;
+///
+/// let d = central_difference(f64::sin, 1.0, 1e-5);
+/// assert!((d - 1.0_f64.cos()).abs() < 1e-10);
+/// ```
+pub fn central_difference<F>(f: F, x: f64, h: f64) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+/// Estimate of f'(x) by Richardson extrapolation of central differences.
+///
+/// Builds the Neville table from central differences Dᵢ with steps h/2ⁱ for
+/// i = 0, ..., `order`, eliminating the h², h⁴, ... error terms in turn:
+///
+/// **Dᵢ,ⱼ = Dᵢ,ⱼ₋₁ + (Dᵢ,ⱼ₋₁ - Dᵢ₋₁,ⱼ₋₁)/(4ʲ - 1)**
+///
+/// The result D_order,order has truncation error O(h^(2·order + 2)). Since the
+/// extrapolation does the work, `h` should be moderate, around 0.1 times the
+/// scale on which f varies; too small a step only amplifies rounding error.
+/// `order` zero gives the plain central difference.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::diff::richardson_derivative;
+///
+/// let d = richardson_derivative(f64::sin, 1.0, 0.1, 4);
+/// assert!((d - 1.0_f64.cos()).abs() < 1e-13);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `h` is zero or not finite.
+pub fn richardson_derivative<F>(f: F, x: f64, h: f64, order: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if h == 0.0 || !h.is_finite() {
+        panic!("Richardson derivative step must be nonzero and finite");
+    }
+
+    // The current row of the table, updated in place as the step halves
+    let mut row = Vec::with_capacity(order + 1);
+    let mut step = h;
+    for i in 0..=order {
+        let mut estimate = central_difference(&f, x, step);
+        let mut factor = 1.0;
+        for previous in row.iter_mut().take(i) {
+            factor *= 4.0;
+            let refined = estimate + (estimate - *previous) / (factor - 1.0);
+            *previous = estimate;
+            estimate = refined;
+        }
+        row.push(estimate);
+        step *= 0.5;
+    }
+
+    row[order]
+}
+
+/// Gradient ∇f(x) of a function of several variables by central differences.
+///
+/// Component i is (f(x + h·eᵢ) - f(x - h·eᵢ))/(2h), with error O(h²); this costs
+/// 2n evaluations of f.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::diff::gradient_fd;
+///
+/// // ∇(x² + 3xy) = (2x + 3y, 3x)
+/// let g = gradient_fd(|v| v[0] * v[0] + 3.0 * v[0] * v[1], &[1.0, 2.0], 1e-5);
+/// assert!((g[0] - 8.0).abs() < 1e-9 && (g[1] - 3.0).abs() < 1e-9);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `h` is zero or not finite.
+pub fn gradient_fd<F>(f: F, x: &[f64], h: f64) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    if h == 0.0 || !h.is_finite() {
+        panic!("Finite-difference step must be nonzero and finite");
+    }
+
+    let mut point = x.to_vec();
+    (0..x.len())
+        .map(|i| {
+            point[i] = x[i] + h;
+            let forward = f(&point);
+            point[i] = x[i] - h;
+            let backward = f(&point);
+            point[i] = x[i];
+            (forward - backward) / (2.0 * h)
+        })
+        .collect()
+}
+
+/// Hessian matrix of second partial derivatives by central differences.
+///
+/// Diagonal entries use (f(x + h·eᵢ) - 2f(x) + f(x - h·eᵢ))/h², off-diagonal
+/// entries the mixed difference
+///
+/// **(f(x + h·eᵢ + h·eⱼ) - f(x + h·eᵢ - h·eⱼ) - f(x - h·eᵢ + h·eⱼ) + f(x - h·eᵢ - h·eⱼ))/(4h²)**
+///
+/// both with error O(h²). The result is symmetric by construction and costs
+/// 2n² + 1 evaluations of f. Rounding error grows like ε·|f|/h², so `h` should be
+/// larger than for a gradient, around ε^(1/4)·max(1, |x|).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::diff::hessian_fd;
+///
+/// // x²y has Hessian [[2y, 2x], [2x, 0]]
+/// let hess = hessian_fd(|v| v[0] * v[0] * v[1], &[1.0, 3.0], 1e-3);
+/// assert!((hess[0][0] - 6.0).abs() < 1e-8);
+/// assert!((hess[0][1] - 2.0).abs() < 1e-8 && hess[0][1] == hess[1][0]);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `h` is zero or not finite.
+#[allow(clippy::needless_range_loop)]
+pub fn hessian_fd<F>(f: F, x: &[f64], h: f64) -> Vec<Vec<f64>>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    if h == 0.0 || !h.is_finite() {
+        panic!("Finite-difference step must be nonzero and finite");
+    }
+
+    let n = x.len();
+    let center = f(x);
+    let mut point = x.to_vec();
+    let mut at = |shifts: &[(usize, f64)]| {
+        for &(i, shift) in shifts {
+            point[i] += shift;
+        }
+        let value = f(&point);
+        for &(i, _) in shifts {
+            point[i] = x[i];
+        }
+        value
+    };
+
+    let mut hessian = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        hessian[i][i] = (at(&[(i, h)]) - 2.0 * center + at(&[(i, -h)])) / (h * h);
+        for j in 0..i {
+            let mixed = at(&[(i, h), (j, h)]) - at(&[(i, h), (j, -h)]) - at(&[(i, -h), (j, h)])
+                + at(&[(i, -h), (j, -h)]);
+            hessian[i][j] = mixed / (4.0 * h * h);
+            hessian[j][i] = hessian[i][j];
+        }
+    }
+
+    hessian
+}
+
+/// Derivative of sampled data at every sample point.
+///
+/// Uses second-order central differences at interior points, valid for
+/// non-uniform spacing, and first-order one-sided differences at the two
+/// endpoints. This matches NumPy's `gradient(ys, xs)` with its default edge order.
+///
+/// ## Arguments
+///
+/// * `xs` - Sample positions, strictly increasing.
+/// * `ys` - Sample values, one per position.
+///
+/// ## Returns
+///
+/// A vector of derivative estimates with the same length as the input.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::diff::gradient;
+///
+/// let xs = [0.0, 1.0, 3.0, 4.0];
+/// let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+///
+/// // Exact for quadratics at interior points, even with uneven spacing
+/// let dy = gradient(&xs, &ys);
+/// assert!((dy[1] - 2.0).abs() < 1e-14);
+/// assert!((dy[2] - 6.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `xs` and `ys` differ in length, if there are fewer than two points,
+/// or if `xs` is not strictly increasing.
+pub fn gradient(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    if xs.len() != ys.len() {
+        panic!("Gradient requires xs and ys of equal length");
+    }
+    if xs.len() < 2 {
+        panic!("Gradient requires at least two points");
+    }
+    if xs.windows(2).any(|w| w[1] <= w[0]) {
+        panic!("Gradient nodes must be strictly increasing");
+    }
+
+    let n = xs.len();
+    let mut dy = Vec::with_capacity(n);
+
+    dy.push((ys[1] - ys[0]) / (xs[1] - xs[0]));
+    for i in 1..n - 1 {
+        let h_minus = xs[i] - xs[i - 1];
+        let h_plus = xs[i + 1] - xs[i];
+        let numerator = h_minus * h_minus * ys[i + 1] - h_plus * h_plus * ys[i - 1]
+            + (h_plus * h_plus - h_minus * h_minus) * ys[i];
+        dy.push(numerator / (h_minus * h_plus * (h_minus + h_plus)));
+    }
+    dy.push((ys[n - 1] - ys[n - 2]) / (xs[n - 1] - xs[n - 2]));
+
+    dy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finite_differences_of_sin() {
+        let exact = 1.0_f64.cos();
+        assert!((forward_difference(f64::sin, 1.0, 1e-8) - exact).abs() < 1e-7);
+        assert!((central_difference(f64::sin, 1.0, 1e-5) - exact).abs() < 1e-10);
+        assert!((richardson_derivative(f64::sin, 1.0, 0.1, 4) - exact).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_finite_difference_orders() {
+        // Halving h halves the forward error and quarters the central error
+        let exact = 1.0_f64.exp();
+        let forward = |h| (forward_difference(f64::exp, 1.0, h) - exact).abs();
+        let central = |h| (central_difference(f64::exp, 1.0, h) - exact).abs();
+        assert!((forward(1e-3) / forward(5e-4) - 2.0).abs() < 0.01);
+        assert!((central(1e-2) / central(5e-3) - 4.0).abs() < 0.01);
+
+        // Central differences are exact for quadratics, up to rounding
+        let d = central_difference(|x| 3.0 * x * x - x, 2.0, 0.5);
+        assert!((d - 11.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_richardson_derivative_improves_with_order() {
+        let exact = 1.0_f64.cos();
+        let errors: Vec<f64> = (0..4)
+            .map(|order| (richardson_derivative(f64::sin, 1.0, 0.2, order) - exact).abs())
+            .collect();
+        assert!(errors.windows(2).all(|w| w[1] < 1e-2 * w[0]), "{errors:?}");
+        assert_eq!(
+            richardson_derivative(f64::sin, 1.0, 0.2, 0),
+            central_difference(f64::sin, 1.0, 0.2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Richardson derivative step must be nonzero and finite")]
+    fn test_richardson_derivative_zero_step() {
+        richardson_derivative(f64::sin, 1.0, 0.0, 3);
+    }
+
+    #[test]
+    fn test_gradient_fd_of_quadratic_form() {
+        // f(x) = xᵀAx with A symmetric has gradient 2Ax
+        let a = [[2.0, -1.0, 0.5], [-1.0, 3.0, 1.0], [0.5, 1.0, 4.0]];
+        let f = |x: &[f64]| {
+            (0..3)
+                .map(|i| (0..3).map(|j| x[i] * a[i][j] * x[j]).sum::<f64>())
+                .sum::<f64>()
+        };
+
+        let x = [0.7, -1.2, 2.0];
+        let g = gradient_fd(f, &x, 1e-4);
+        for i in 0..3 {
+            let expected: f64 = (0..3).map(|j| 2.0 * a[i][j] * x[j]).sum();
+            assert!((g[i] - expected).abs() < 1e-9, "{g:?}");
+        }
+    }
+
+    #[test]
+    fn test_hessian_fd_of_quadratic_form() {
+        let a = [[2.0, -1.0, 0.5], [-1.0, 3.0, 1.0], [0.5, 1.0, 4.0]];
+        let f = |x: &[f64]| {
+            (0..3)
+                .map(|i| (0..3).map(|j| x[i] * a[i][j] * x[j]).sum::<f64>())
+                .sum::<f64>()
+        };
+
+        let hessian = hessian_fd(f, &[0.7, -1.2, 2.0], 1e-2);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((hessian[i][j] - 2.0 * a[i][j]).abs() < 1e-9, "{hessian:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_hessian_fd_separable_is_diagonal() {
+        // f = sin x + eʸ + z⁴ has no mixed partial derivatives
+        let f = |v: &[f64]| v[0].sin() + v[1].exp() + v[2].powi(4);
+        let x = [0.4, -0.3, 1.5];
+        let hessian = hessian_fd(f, &x, 1e-3);
+
+        let diagonal = [-x[0].sin(), x[1].exp(), 12.0 * x[2] * x[2]];
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { diagonal[i] } else { 0.0 };
+                assert!((hessian[i][j] - expected).abs() < 1e-5, "{hessian:?}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Finite-difference step must be nonzero and finite")]
+    fn test_gradient_fd_zero_step() {
+        gradient_fd(|v| v[0], &[1.0], 0.0);
+    }
+
+    #[test]
+    fn test_gradient_of_square() {
+        let xs = [-2.0, -1.5, -0.2, 0.0, 0.7, 1.0, 2.5];
+        let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+        let dy = gradient(&xs, &ys);
+
+        assert_eq!(dy.len(), xs.len());
+        for i in 1..xs.len() - 1 {
+            assert!((dy[i] - 2.0 * xs[i]).abs() < 1e-13);
+        }
+
+        // One-sided differences at the ends: slope of the first and last secant
+        assert!((dy[0] - (-3.5)).abs() < 1e-14);
+        assert!((dy[6] - 3.5).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_gradient_of_linear_data() {
+        let xs = [0.0, 0.1, 0.5, 0.6, 2.0];
+        let ys: Vec<f64> = xs.iter().map(|x| 3.0 * x - 1.0).collect();
+        for d in gradient(&xs, &ys) {
+            assert!((d - 3.0).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_gradient_two_points() {
+        assert_eq!(gradient(&[1.0, 3.0], &[2.0, 6.0]), vec![2.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gradient requires xs and ys of equal length")]
+    fn test_gradient_length_mismatch() {
+        gradient(&[0.0, 1.0], &[0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gradient nodes must be strictly increasing")]
+    fn test_gradient_unsorted_nodes() {
+        gradient(&[0.0, 2.0, 1.0], &[0.0, 1.0, 2.0]);
+    }
+}
@@ -0,0 +1,191 @@
+//! # Least-Squares Polynomial Fitting
+//!
+//! Polynomials of a given degree fitted to data (xᵢ, yᵢ) by minimizing the
+//! (weighted) sum of squared residuals.
+//!
+//! ## Normal Equations
+//!
+//! With the design matrix Xᵢₖ = xᵢᵏ and the diagonal weight matrix W, the
+//! coefficients c minimize Σ wᵢ·(yᵢ - p(xᵢ))² and solve
+//!
+//! **(XᵀWX)·c = XᵀW·y**
+//!
+//! The normal equations square the condition number of X, so keep the degree
+//! moderate or centre and scale the xᵢ beforehand.
+
+use crate::base::matrix::Matrix;
+use crate::base::poly::Polynomial;
+
+/// The result of a weighted least-squares polynomial fit
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolyFit {
+    /// The fitted polynomial
+    pub polynomial: Polynomial,
+    /// Weighted residual sum of squares Σ wᵢ·(yᵢ - p(xᵢ))²
+    pub rss: f64,
+}
+
+/// Least-squares polynomial of the given degree through the points (xᵢ, yᵢ).
+///
+/// Equivalent to [`polyfit_weighted`] with unit weights.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::fit::polyfit;
+///
+/// // Points on y = 1 + 2x are fitted exactly
+/// let p = polyfit(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 5.0, 7.0], 1);
+/// assert!((p.coeffs()[0] - 1.0).abs() < 1e-12);
+/// assert!((p.coeffs()[1] - 2.0).abs() < 1e-12);
+/// ```
+///
+/// ## Panics
+///
+/// Panics under the same conditions as [`polyfit_weighted`].
+pub fn polyfit(xs: &[f64], ys: &[f64], degree: usize) -> Polynomial {
+    polyfit_weighted(xs, ys, &vec![1.0; xs.len()], degree).polynomial
+}
+
+/// Weighted least-squares polynomial of the given degree through the points (xᵢ, yᵢ).
+///
+/// Solves the weighted normal equations (XᵀWX)·c = XᵀW·y. A larger weight pulls
+/// the fit closer to its point; for measurements with standard deviations σᵢ the
+/// usual choice is wᵢ = 1/σᵢ².
+///
+/// ## Arguments
+///
+/// * `xs` - Sample positions.
+/// * `ys` - Sample values, one per position.
+/// * `weights` - Non-negative weights, one per position.
+/// * `degree` - Degree of the fitted polynomial.
+///
+/// ## Returns
+///
+/// A [`PolyFit`] holding the polynomial and its weighted residual sum of squares.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::fit::polyfit_weighted;
+///
+/// let xs = [0.0, 1.0, 2.0, 3.0];
+/// let ys = [0.0, 1.0, 2.0, 10.0];
+///
+/// // Nearly ignoring the last point recovers the line y = x
+/// let fit = polyfit_weighted(&xs, &ys, &[1.0, 1.0, 1.0, 1e-12], 1);
+/// assert!((fit.polynomial.eval(3.0) - 3.0).abs() < 1e-9);
+/// assert!(fit.rss < 1e-9);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `xs`, `ys` and `weights` differ in length, if any weight is negative,
+/// or if the normal equations are singular, which happens when fewer than
+/// `degree + 1` distinct positions carry a positive weight.
+pub fn polyfit_weighted(xs: &[f64], ys: &[f64], weights: &[f64], degree: usize) -> PolyFit {
+    if xs.len() != ys.len() || xs.len() != weights.len() {
+        panic!("Polynomial fit requires xs, ys and weights of equal length");
+    }
+    if weights.iter().any(|&w| w < 0.0) {
+        panic!("Polynomial fit weights must be non-negative");
+    }
+
+    let n = degree + 1;
+    let mut normal = Matrix::zeros(n, n);
+    let mut rhs = vec![0.0; n];
+
+    for ((&x, &y), &w) in xs.iter().zip(ys).zip(weights) {
+        // Powers x⁰ ... x^(2·degree) fill the Hankel structure of XᵀWX
+        let mut powers = vec![1.0; 2 * n - 1];
+        for k in 1..powers.len() {
+            powers[k] = powers[k - 1] * x;
+        }
+
+        for j in 0..n {
+            for k in 0..n {
+                normal[(j, k)] += w * powers[j + k];
+            }
+            rhs[j] += w * y * powers[j];
+        }
+    }
+
+    let coeffs = normal
+        .solve(&rhs)
+        .expect("Polynomial fit normal equations are singular");
+    let polynomial = Polynomial::new(coeffs);
+
+    let rss = xs
+        .iter()
+        .zip(ys)
+        .zip(weights)
+        .map(|((&x, &y), &w)| w * (y - polynomial.eval(x)).powi(2))
+        .sum();
+
+    PolyFit { polynomial, rss }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_polyfit_recovers_quadratic() {
+        let xs: Vec<f64> = (0..10).map(|i| i as f64 * 0.5 - 2.0).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| 3.0 - x + 0.25 * x * x).collect();
+
+        let p = polyfit(&xs, &ys, 2);
+        for (c, e) in p.coeffs().iter().zip([3.0, -1.0, 0.25]) {
+            assert!((c - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_uniform_weights_match_unweighted() {
+        let xs = [0.0, 0.7, 1.1, 2.0, 2.9, 4.2];
+        let ys = [1.2, 0.4, 0.9, 2.1, 2.0, 4.4];
+
+        let plain = polyfit(&xs, &ys, 2);
+        let weighted = polyfit_weighted(&xs, &ys, &[3.0; 6], 2);
+
+        for (a, b) in plain.coeffs().iter().zip(weighted.polynomial.coeffs()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        // With unit weights the RSS is the plain sum of squared residuals
+        let rss: f64 = xs
+            .iter()
+            .zip(ys)
+            .map(|(&x, y)| (y - plain.eval(x)).powi(2))
+            .sum();
+        let unit = polyfit_weighted(&xs, &ys, &[1.0; 6], 2);
+        assert!((unit.rss - rss).abs() < 1e-12);
+        assert!((weighted.rss - 3.0 * rss).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_downweighted_outlier() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys = [1.0, 3.0, 5.0, 20.0, 9.0];
+
+        let plain = polyfit(&xs, &ys, 1);
+        let fit = polyfit_weighted(&xs, &ys, &[1.0, 1.0, 1.0, 1e-6, 1.0], 1);
+
+        // The outlier at x = 3 drags the unweighted line; the weighted one follows y = 1 + 2x
+        assert!((plain.eval(3.0) - 7.0).abs() > 1.0);
+        assert!((fit.polynomial.eval(3.0) - 7.0).abs() < 1e-4);
+        assert!((fit.polynomial.eval(3.0) - 20.0).abs() > (plain.eval(3.0) - 20.0).abs());
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomial fit requires xs, ys and weights of equal length")]
+    fn test_mismatched_weights() {
+        polyfit_weighted(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], &[1.0, 1.0], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomial fit normal equations are singular")]
+    fn test_underdetermined_fit() {
+        polyfit(&[1.0, 2.0], &[1.0, 2.0], 2);
+    }
+}
@@ -0,0 +1,166 @@
+//! # Fourier Series
+//!
+//! Fourier coefficients of a continuous periodic function, obtained by numerical
+//! integration over one period. Unlike a discrete Fourier transform of given
+//! samples, the function is evaluated wherever the quadrature rule needs it.
+
+use crate::base::numbers::PI;
+
+/// Fourier coefficients of `f` with period `period`, by composite Simpson's rule
+///
+/// Returns `(a, b)` with `n_harmonics + 1` entries each, such that
+///
+/// ```text
+/// f(x) ≈ a₀ + Σₖ₌₁ᴺ (aₖ·cos(kωx) + bₖ·sin(kωx)),   ω = 2π/T
+/// ```
+///
+/// so a₀ is the mean of f over a period and b₀ is always 0:
+///
+/// ```text
+/// a₀ = (1/T) ∫₀ᵀ f(x) dx
+/// aₖ = (2/T) ∫₀ᵀ f(x)·cos(kωx) dx
+/// bₖ = (2/T) ∫₀ᵀ f(x)·sin(kωx) dx
+/// ```
+///
+/// ## Arguments
+///
+/// * `f` - The periodic function; it is evaluated `n_points + 1` times on
+///   [0, T], and the samples are shared by all harmonics.
+/// * `period` - The period T > 0.
+/// * `n_harmonics` - The highest harmonic N to compute.
+/// * `n_points` - Number of Simpson subintervals, even and at least 2. For
+///   accurate coefficients it should be several times `2 * n_harmonics`, as
+///   cos(kωx) must be resolved too.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::fourier::fourier_coeffs;
+/// use std::f64::consts::PI;
+///
+/// // 1 + 3cos(x) - 2sin(2x)
+/// let f = |x: f64| 1.0 + 3.0 * x.cos() - 2.0 * (2.0 * x).sin();
+/// let (a, b) = fourier_coeffs(f, 2.0 * PI, 3, 200);
+///
+/// assert!((a[0] - 1.0).abs() < 1e-12);
+/// assert!((a[1] - 3.0).abs() < 1e-9);
+/// assert!((b[2] + 2.0).abs() < 1e-9);
+/// assert!(a[3].abs() < 1e-9 && b[3].abs() < 1e-9);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `period` is not positive, or if `n_points` is odd or less than 2.
+pub fn fourier_coeffs<F>(
+    f: F,
+    period: f64,
+    n_harmonics: usize,
+    n_points: usize,
+) -> (Vec<f64>, Vec<f64>)
+where
+    F: Fn(f64) -> f64,
+{
+    if period <= 0.0 {
+        panic!("Fourier series requires a positive period");
+    }
+    if n_points < 2 || !n_points.is_multiple_of(2) {
+        panic!("Simpson's rule requires an even number of subintervals");
+    }
+
+    let h = period / n_points as f64;
+    let omega = 2.0 * PI / period;
+
+    // Samples premultiplied by the Simpson weights 1, 4, 2, 4, ..., 2, 4, 1
+    let weighted: Vec<(f64, f64)> = (0..=n_points)
+        .map(|i| {
+            let weight = if i == 0 || i == n_points {
+                1.0
+            } else if i % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+            let x = i as f64 * h;
+            (x, weight * f(x))
+        })
+        .collect();
+
+    let mut a = vec![0.0; n_harmonics + 1];
+    let mut b = vec![0.0; n_harmonics + 1];
+
+    let scale = 2.0 * h / 3.0 / period;
+    a[0] = 0.5 * scale * weighted.iter().map(|&(_, wf)| wf).sum::<f64>();
+    for k in 1..=n_harmonics {
+        let (mut cos_sum, mut sin_sum) = (0.0, 0.0);
+        for &(x, wf) in &weighted {
+            let (sin, cos) = (k as f64 * omega * x).sin_cos();
+            cos_sum += wf * cos;
+            sin_sum += wf * sin;
+        }
+        a[k] = scale * cos_sum;
+        b[k] = scale * sin_sum;
+    }
+
+    (a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pure_cosine() {
+        let period = 3.0;
+        let f = |x: f64| (2.0 * PI * x / period).cos();
+        let (a, b) = fourier_coeffs(f, period, 6, 400);
+
+        assert!((a[1] - 1.0).abs() < 1e-10);
+        for k in 0..=6 {
+            if k != 1 {
+                assert!(a[k].abs() < 1e-10, "a[{k}] = {}", a[k]);
+            }
+            assert!(b[k].abs() < 1e-10, "b[{k}] = {}", b[k]);
+        }
+    }
+
+    #[test]
+    fn test_square_wave() {
+        // +1 on the first half period, -1 on the second: bₖ = 4/(πk) for odd k
+        let period = 2.0;
+        let square = |x: f64| (2.0 * PI * x / period).sin().signum();
+        let (a, b) = fourier_coeffs(square, period, 7, 20_000);
+
+        for k in 1..=7 {
+            let expected = if k % 2 == 1 {
+                4.0 / (PI * k as f64)
+            } else {
+                0.0
+            };
+            assert!((b[k] - expected).abs() < 1e-3, "b[{k}] = {}", b[k]);
+            assert!(a[k].abs() < 1e-3, "a[{k}] = {}", a[k]);
+        }
+    }
+
+    #[test]
+    fn test_dc_term_is_mean() {
+        let f = |x: f64| x.exp();
+        let period = 1.5;
+        let (a, b) = fourier_coeffs(f, period, 2, 100);
+
+        let mean = (period.exp() - 1.0) / period;
+        assert!((a[0] - mean).abs() < 1e-9);
+        assert_eq!(b[0], 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Simpson's rule requires an even number of subintervals")]
+    fn test_odd_point_count() {
+        fourier_coeffs(f64::sin, 1.0, 2, 101);
+    }
+
+    #[test]
+    #[should_panic(expected = "Fourier series requires a positive period")]
+    fn test_non_positive_period() {
+        fourier_coeffs(f64::sin, 0.0, 2, 100);
+    }
+}
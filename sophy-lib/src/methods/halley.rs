@@ -0,0 +1,169 @@
+//! # Halley's Method
+//!
+//! Root finding with the first and second derivatives:
+//!
+//! **x_{n+1} = x_n - 2·f(x_n)·f'(x_n) / (2·f'(x_n)² - f(x_n)·f''(x_n))**
+//!
+//! Near a simple root the convergence is cubic, so the number of correct digits
+//! roughly triples per iteration, against doubling for Newton-Raphson. This pays
+//! off when f'' comes almost for free, as for polynomials and for the functions
+//! defining constants such as √a or ln a to high precision.
+
+use crate::methods::bisection::RootError;
+
+/// Halley's root-finding method.
+///
+/// ## Arguments
+///
+/// * `x0` - Initial guess for the root.
+/// * `f` - The function whose root is sought.
+/// * `df` - Its first derivative.
+/// * `d2f` - Its second derivative.
+/// * `tol` - Step size below which the iteration has converged.
+/// * `max_iter` - Maximum number of iterations.
+///
+/// ## Returns
+///
+/// The root, or
+/// - [`RootError::DerivativeTooSmall`] if the denominator 2f'² - f·f'' falls
+///   below `tol` in magnitude
+/// - [`RootError::MaxIterationsReached`] with the last iterate if the step is still at
+///   least `tol` after `max_iter` iterations
+/// - [`RootError::NanEncountered`] if `f`, `df` or `d2f` returns NaN or an
+///   iterate overflows
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::halley::halley;
+///
+/// // √2 as the root of x² - 2
+/// let root = halley(1.0, |x| x * x - 2.0, |x| 2.0 * x, |_| 2.0, 1e-15, 20).unwrap();
+/// assert!((root - std::f64::consts::SQRT_2).abs() < 1e-15);
+/// ```
+pub fn halley<F, DF, D2F>(
+    x0: f64,
+    f: F,
+    df: DF,
+    d2f: D2F,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, RootError>
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+    D2F: Fn(f64) -> f64,
+{
+    let mut x = x0;
+
+    for _ in 0..max_iter {
+        let (y, y1, y2) = (f(x), df(x), d2f(x));
+        if y.is_nan() || y1.is_nan() || y2.is_nan() {
+            return Err(RootError::NanEncountered);
+        }
+        if y == 0.0 {
+            return Ok(x);
+        }
+
+        let denominator = 2.0 * y1 * y1 - y * y2;
+        if denominator.abs() < tol {
+            return Err(RootError::DerivativeTooSmall { x });
+        }
+
+        let x_new = x - 2.0 * y * y1 / denominator;
+        if !x_new.is_finite() {
+            return Err(RootError::NanEncountered);
+        }
+        let step = (x_new - x).abs();
+        x = x_new;
+        if step < tol {
+            return Ok(x);
+        }
+    }
+
+    Err(RootError::MaxIterationsReached {
+        last: x,
+        iterations: max_iter,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::raphson::raphson_with_progress;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_halley_sqrt2() {
+        let root = halley(1.0, |x| x * x - 2.0, |x| 2.0 * x, |_| 2.0, 1e-14, 20).unwrap();
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_halley_fewer_iterations_than_newton() {
+        let f = |x: f64| x * x - 2.0;
+        let iterations = Cell::new(0);
+        let counted = |x: f64| {
+            iterations.set(iterations.get() + 1);
+            f(x)
+        };
+
+        let root = halley(1.0, counted, |x| 2.0 * x, |_| 2.0, 1e-14, 50).unwrap();
+        let halley_iterations = iterations.get();
+
+        let mut newton_iterations = 0;
+        let newton = raphson_with_progress(
+            1.0,
+            f,
+            |x| 2.0 * x,
+            1e-14,
+            50,
+            |step, _| {
+                newton_iterations = step;
+            },
+        );
+
+        assert!((root - newton).abs() < 1e-15);
+        assert!(
+            halley_iterations < newton_iterations,
+            "{halley_iterations} vs {newton_iterations}"
+        );
+    }
+
+    #[test]
+    fn test_halley_transcendental() {
+        let root = halley(1.0, |x: f64| x.exp() - 10.0, f64::exp, f64::exp, 1e-14, 50).unwrap();
+        assert!((root - 10.0_f64.ln()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_halley_exact_root() {
+        assert_eq!(
+            halley(3.0, |x| x - 3.0, |_| 1.0, |_| 0.0, 1e-12, 10),
+            Ok(3.0)
+        );
+    }
+
+    #[test]
+    fn test_halley_errors() {
+        // A constant has f' = f'' = 0, so the denominator 2f'² - f·f'' vanishes
+        let flat = halley(0.0, |_| 1.0, |_| 0.0, |_| 0.0, 1e-12, 10);
+        assert_eq!(flat, Err(RootError::DerivativeTooSmall { x: 0.0 }));
+
+        let slow = halley(
+            1.0,
+            |x: f64| x.powi(4),
+            |x| 4.0 * x.powi(3),
+            |x| 12.0 * x * x,
+            1e-12,
+            5,
+        );
+        assert!(matches!(
+            slow,
+            Err(RootError::MaxIterationsReached { iterations: 5, .. })
+        ));
+
+        let nan = halley(-1.0, f64::ln, |x| 1.0 / x, |x| -1.0 / (x * x), 1e-12, 10);
+        assert_eq!(nan, Err(RootError::NanEncountered));
+    }
+}
@@ -0,0 +1,1520 @@
+//! # Numerical Integration
+//!
+//! Quadrature rules for approximating definite integrals of real-valued functions.
+//!
+//! ## Composite Newton-Cotes Rules
+//!
+//! [`trapezoidal`] and [`simpson`] sample the integrand at `n + 1` equally spaced
+//! points. Their errors fall as O(h²) and O(h⁴) in the spacing h, so Simpson's
+//! rule is far more accurate for smooth integrands at the same cost.
+//!
+//! ## Gauss-Legendre Quadrature
+//!
+//! An `n`-point Gauss-Legendre rule integrates polynomials of degree up to `2n - 1`
+//! exactly on [-1, 1]:
+//!
+//! **∫₋₁¹ f(x) dx ≈ Σ wᵢ f(xᵢ)**
+//!
+//! The nodes xᵢ are the roots of the Legendre polynomial Pₙ and are computed on
+//! demand, so any order can be requested instead of being limited to a table.
+//!
+//! ## Gauss-Chebyshev Quadrature
+//!
+//! [`gauss_chebyshev`] integrates g(x)/√(1 - x²) over [-1, 1]. The weight is built
+//! into the rule, so integrands with inverse square-root endpoint singularities are
+//! handled exactly, and nodes and weights have closed forms.
+//!
+//! ## Error Estimates
+//!
+//! [`integrate_with_error`] returns an error estimate alongside the value, obtained
+//! from the difference between Simpson's rule and its refinement on two halves.
+//! [`adaptive_simpson`] runs the same recursion with a caller-chosen depth limit.
+//!
+//! [`gauss_kronrod`] evaluates the 15-point Kronrod extension of the 7-point Gauss
+//! rule, reusing the Gauss nodes, and reports the difference of the two as the error.
+//! [`qag`] applies it adaptively, always bisecting the subinterval with the largest
+//! error estimate, in the manner of QUADPACK's QAG.
+//!
+//! ## Romberg Integration
+//!
+//! [`TrapezoidRefiner`] halves the step of the trapezoidal rule on every call
+//! while evaluating the integrand only at the new midpoints. [`romberg`] feeds its
+//! successive estimates into Richardson extrapolation.
+//!
+//! ## Sampled Data
+//!
+//! [`cumulative_trapezoid`] and [`cumulative_trapezoid_nonuniform`] integrate
+//! tabulated values and return the running integral at every sample, an
+//! antiderivative curve rather than a single number.
+
+use crate::base::numbers::PI;
+use crate::methods::options::IterativeOptions;
+use crate::methods::raphson::raphson;
+use crate::specials::legendre::{legendre_p, legendre_p_derivative};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Computes the nodes and weights of the `n`-point Gauss-Legendre rule on [-1, 1].
+///
+/// Each node is a root of Pₙ(x), found with the Newton-Raphson method starting from
+/// the classical asymptotic guess:
+///
+/// ```text
+/// xᵢ⁽⁰⁾ = cos(π(i - 0.25)/(n + 0.5)),   i = 1, ..., n
+/// ```
+///
+/// The corresponding weights are:
+///
+/// ```text
+/// wᵢ = 2 / ((1 - xᵢ²)·P'ₙ(xᵢ)²)
+/// ```
+///
+/// ## Arguments
+///
+/// * `n` - Number of quadrature points.
+///
+/// ## Returns
+///
+/// A tuple `(nodes, weights)` with the nodes sorted in ascending order.
+/// For `n = 0` both vectors are empty.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::gauss_legendre_nodes;
+///
+/// let (nodes, weights) = gauss_legendre_nodes(2);
+///
+/// // Two-point rule: ±1/√3 with unit weights
+/// assert!((nodes[1] - 1.0 / 3.0_f64.sqrt()).abs() < 1e-15);
+/// assert!((weights[0] - 1.0).abs() < 1e-15);
+///
+/// // Weights always sum to the length of [-1, 1]
+/// let (_, weights) = gauss_legendre_nodes(20);
+/// assert!((weights.iter().sum::<f64>() - 2.0).abs() < 1e-13);
+/// ```
+pub fn gauss_legendre_nodes(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut nodes = Vec::with_capacity(n);
+    let mut weights = Vec::with_capacity(n);
+
+    // Initial guesses are in descending order, so walk them backwards
+    for i in (1..=n).rev() {
+        let guess = (PI * (i as f64 - 0.25) / (n as f64 + 0.5)).cos();
+        let x = raphson(
+            guess,
+            |x| legendre_p(n, x),
+            |x| legendre_p_derivative(n, x),
+            1e-15,
+            100,
+        );
+        let dp = legendre_p_derivative(n, x);
+
+        nodes.push(x);
+        weights.push(2.0 / ((1.0 - x * x) * dp * dp));
+    }
+
+    (nodes, weights)
+}
+
+/// Gauss-Legendre quadrature of `f` over [a, b] with `n` points.
+///
+/// The nodes from [`gauss_legendre_nodes`] are mapped from [-1, 1] onto [a, b].
+/// The rule is exact for polynomials of degree up to `2n - 1`.
+///
+/// ## Arguments
+///
+/// * `f` - The integrand.
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+/// * `n` - Number of quadrature points (any order is supported).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::gauss_legendre;
+///
+/// // ∫₀^π sin(x) dx = 2
+/// let integral = gauss_legendre(|x: f64| x.sin(), 0.0, std::f64::consts::PI, 10);
+/// assert!((integral - 2.0).abs() < 1e-12);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `n` is zero.
+pub fn gauss_legendre<F>(f: F, a: f64, b: f64, n: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if n == 0 {
+        panic!("Gauss-Legendre quadrature requires at least one point");
+    }
+
+    let (nodes, weights) = gauss_legendre_nodes(n);
+    let half_width = 0.5 * (b - a);
+    let center = 0.5 * (a + b);
+
+    let sum: f64 = nodes
+        .iter()
+        .zip(&weights)
+        .map(|(&x, &w)| w * f(center + half_width * x))
+        .sum();
+
+    half_width * sum
+}
+
+/// Gauss-Chebyshev quadrature of g(x)/√(1 - x²) over [-1, 1] with `n` points.
+///
+/// The nodes are the roots of the Chebyshev polynomial Tₙ and all weights are equal:
+///
+/// **∫₋₁¹ g(x)/√(1 - x²) dx ≈ (π/n) Σ g(cos((2i - 1)π/2n))**,  i = 1, ..., n
+///
+/// The rule is exact when g is a polynomial of degree up to `2n - 1`. Only g is
+/// evaluated, never at the endpoints, so the weight's singularities do no harm.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::gauss_chebyshev;
+/// use std::f64::consts::PI;
+///
+/// // ∫₋₁¹ x²/√(1 - x²) dx = π/2
+/// let integral = gauss_chebyshev(|x| x * x, 2);
+/// assert!((integral - PI / 2.0).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `n` is zero.
+pub fn gauss_chebyshev<F>(g: F, n: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if n == 0 {
+        panic!("Gauss-Chebyshev quadrature requires at least one point");
+    }
+
+    let step = PI / n as f64;
+    let sum: f64 = (0..n).map(|i| g(((i as f64 + 0.5) * step).cos())).sum();
+
+    step * sum
+}
+
+/// Positive Kronrod abscissae on [-1, 1] in descending order (the origin is the last
+/// node); the entries with odd index are the 7-point Gauss nodes
+const KRONROD_NODES: [f64; 8] = [
+    0.991_455_371_120_812_6,
+    0.949_107_912_342_758_5,
+    0.864_864_423_359_769_1,
+    0.741_531_185_599_394_5,
+    0.586_087_235_467_691_1,
+    0.405_845_151_377_397_2,
+    0.207_784_955_007_898_48,
+    0.0,
+];
+
+/// 15-point Kronrod weights matching [`KRONROD_NODES`]
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022_935_322_010_529_224,
+    0.063_092_092_629_978_56,
+    0.104_790_010_322_250_19,
+    0.140_653_259_715_525_92,
+    0.169_004_726_639_267_9,
+    0.190_350_578_064_785_42,
+    0.204_432_940_075_298_89,
+    0.209_482_141_084_727_82,
+];
+
+/// 7-point Gauss weights for the nodes `KRONROD_NODES[1]`, `[3]`, `[5]` and `[7]`
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129_484_966_168_869_7,
+    0.279_705_391_489_276_64,
+    0.381_830_050_505_118_9,
+    0.417_959_183_673_469_4,
+];
+
+/// Gauss-Kronrod G7-K15 quadrature of `f` over [a, b].
+///
+/// The 15-point Kronrod rule adds 8 nodes to the 7-point Gauss-Legendre rule, so
+/// both estimates come from the same 15 function evaluations. K15 is exact for
+/// polynomials of degree up to 22 and G7 for degree up to 13.
+///
+/// ## Arguments
+///
+/// * `f` - The integrand.
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+///
+/// ## Returns
+///
+/// A tuple `(estimate, error_estimate)` with the K15 value and `|K15 - G7|`. The
+/// difference is the error of the less accurate G7 rule, so for smooth integrands
+/// it is a pessimistic bound on the error of K15.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::gauss_kronrod;
+///
+/// // ∫₀¹ eˣ dx = e - 1
+/// let (value, error) = gauss_kronrod(|x: f64| x.exp(), 0.0, 1.0);
+///
+/// assert!((value - (std::f64::consts::E - 1.0)).abs() < 1e-15);
+/// assert!(error < 1e-14);
+/// ```
+pub fn gauss_kronrod<F>(f: F, a: f64, b: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    let half_width = 0.5 * (b - a);
+    let center = 0.5 * (a + b);
+
+    let f_center = f(center);
+    let mut kronrod = KRONROD_WEIGHTS[7] * f_center;
+    let mut gauss = GAUSS_WEIGHTS[3] * f_center;
+
+    for (k, (&x, &w)) in KRONROD_NODES[..7].iter().zip(&KRONROD_WEIGHTS).enumerate() {
+        let dx = half_width * x;
+        let pair = f(center - dx) + f(center + dx);
+
+        kronrod += w * pair;
+        if k % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[k / 2] * pair;
+        }
+    }
+
+    let kronrod = kronrod * half_width;
+    let gauss = gauss * half_width;
+
+    (kronrod, (kronrod - gauss).abs())
+}
+
+/// Globally adaptive Gauss-Kronrod integration of `f` over [a, b].
+///
+/// The subintervals are kept in a priority queue ordered by their G7-K15 error
+/// estimate. The worst one is bisected until the summed error estimate drops below
+/// `tol` or `max_intervals` subintervals are in use. Concentrating the work where
+/// the error is largest resolves peaks and endpoint singularities that a fixed
+/// rule misses entirely.
+///
+/// ## Arguments
+///
+/// * `f` - The integrand.
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+/// * `tol` - Requested absolute accuracy.
+/// * `max_intervals` - Maximum number of subintervals. Each bisection costs 30
+///   function evaluations.
+///
+/// ## Returns
+///
+/// A tuple `(estimate, error_estimate)`. If the interval limit is reached first,
+/// `error_estimate` is larger than `tol`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::qag;
+///
+/// // ∫₀¹ 4/(1 + x²) dx = π
+/// let (value, error) = qag(|x: f64| 4.0 / (1.0 + x * x), 0.0, 1.0, 1e-12, 100);
+///
+/// assert!(error < 1e-12);
+/// assert!((value - std::f64::consts::PI).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `tol` is not positive or `max_intervals` is zero.
+pub fn qag<F>(f: F, a: f64, b: f64, tol: f64, max_intervals: usize) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    if tol <= 0.0 {
+        panic!("Integration tolerance must be positive");
+    }
+    if max_intervals == 0 {
+        panic!("Adaptive integration requires at least one interval");
+    }
+
+    let (value, error, _) = adaptive_gauss_kronrod(f, a, b, max_intervals, |_| tol);
+    (value, error)
+}
+
+/// Adaptive Gauss-Kronrod integration configured by [`IterativeOptions`]
+///
+/// `max_iter` limits the number of subintervals and the error estimate must
+/// drop below `tol`, taken relative to the magnitude of the integral when
+/// `relative` is set. `None` selects the default options, which match
+/// `qag(f, a, b, 1e-12, 100)`.
+///
+/// ## Returns
+///
+/// `(estimate, error_estimate)`, or `None` if the tolerance is not met with
+/// `max_iter` subintervals.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::IterativeOptions;
+/// use sophy::methods::integration::qag_with_options;
+///
+/// // ∫₀¹ 10¹²·x² dx, where an absolute error of 10⁻¹² is out of reach
+/// let f = |x: f64| 1e12 * x * x;
+/// let options = IterativeOptions { relative: true, ..IterativeOptions::default() };
+/// let (value, _) = qag_with_options(f, 0.0, 1.0, Some(options)).unwrap();
+/// assert!((value / (1e12 / 3.0) - 1.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the tolerance is not positive or `max_iter` is zero.
+pub fn qag_with_options<F>(
+    f: F,
+    a: f64,
+    b: f64,
+    options: Option<IterativeOptions>,
+) -> Option<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    let options = IterativeOptions::resolve(options);
+    if options.max_iter == 0 {
+        panic!("Adaptive integration requires at least one interval");
+    }
+
+    let (value, error, converged) =
+        adaptive_gauss_kronrod(f, a, b, options.max_iter, |value| options.tolerance(value));
+    converged.then_some((value, error))
+}
+
+/// The bisection loop shared by [`qag`] and [`qag_with_options`], returning
+/// `(estimate, error_estimate, converged)`
+///
+/// `tolerance(estimate)` is the absolute error to reach for the current
+/// estimate of the integral.
+fn adaptive_gauss_kronrod<F, T>(
+    f: F,
+    a: f64,
+    b: f64,
+    max_intervals: usize,
+    tolerance: T,
+) -> (f64, f64, bool)
+where
+    F: Fn(f64) -> f64,
+    T: Fn(f64) -> f64,
+{
+    let (value, error) = gauss_kronrod(&f, a, b);
+    let mut intervals = BinaryHeap::new();
+    intervals.push(Subinterval { a, b, value, error });
+
+    let (mut total_value, mut total_error) = (value, error);
+    while total_error > tolerance(total_value) && intervals.len() < max_intervals {
+        let worst = match intervals.pop() {
+            Some(worst) => worst,
+            None => break,
+        };
+
+        let mid = 0.5 * (worst.a + worst.b);
+        let (left_value, left_error) = gauss_kronrod(&f, worst.a, mid);
+        let (right_value, right_error) = gauss_kronrod(&f, mid, worst.b);
+
+        intervals.push(Subinterval {
+            a: worst.a,
+            b: mid,
+            value: left_value,
+            error: left_error,
+        });
+        intervals.push(Subinterval {
+            a: mid,
+            b: worst.b,
+            value: right_value,
+            error: right_error,
+        });
+
+        // Summing afresh avoids the drift of a running total after many updates
+        total_value = intervals.iter().map(|s| s.value).sum();
+        total_error = intervals.iter().map(|s| s.error).sum();
+    }
+
+    (
+        total_value,
+        total_error,
+        total_error <= tolerance(total_value),
+    )
+}
+
+/// A subinterval of [`qag`] with its local estimate, ordered by its error estimate
+struct Subinterval {
+    a: f64,
+    b: f64,
+    value: f64,
+    error: f64,
+}
+
+impl PartialEq for Subinterval {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Subinterval {}
+
+impl PartialOrd for Subinterval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Subinterval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.error.total_cmp(&other.error)
+    }
+}
+
+/// Adaptive Simpson integration of `f` over [a, b] with an error estimate.
+///
+/// On every subinterval Simpson's rule S₁ is compared with the sum S₂ of Simpson's
+/// rule on the two halves. Their difference estimates the error of S₂:
+///
+/// ```text
+/// |I - S₂| ≈ |S₂ - S₁| / 15
+/// ```
+///
+/// Subintervals whose estimate exceeds their share of `tol` are bisected; accepted
+/// ones contribute the Richardson-extrapolated value S₂ + (S₂ - S₁)/15.
+///
+/// ## Arguments
+///
+/// * `f` - The integrand.
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+/// * `tol` - Requested absolute accuracy.
+///
+/// ## Returns
+///
+/// A tuple `(estimate, error_estimate)`, where `error_estimate` is the sum of the
+/// local error estimates of all accepted subintervals. It can exceed `tol` when
+/// the recursion depth limit is reached, for example near a singularity, so
+/// callers can detect results that should not be trusted.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::integrate_with_error;
+///
+/// // ∫₀¹ eˣ dx = e - 1
+/// let (value, error) = integrate_with_error(|x: f64| x.exp(), 0.0, 1.0, 1e-10);
+///
+/// assert!(error < 1e-10);
+/// assert!((value - (std::f64::consts::E - 1.0)).abs() <= error);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `tol` is not positive.
+pub fn integrate_with_error<F>(f: F, a: f64, b: f64, tol: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    if tol <= 0.0 {
+        panic!("Integration tolerance must be positive");
+    }
+
+    let m = 0.5 * (a + b);
+    let (fa, fm, fb) = (f(a), f(m), f(b));
+    let whole = simpson_panel(a, b, fa, fm, fb);
+
+    adaptive_simpson_step(&f, a, b, fa, fm, fb, whole, tol, 50)
+}
+
+/// Composite trapezoidal rule for ∫ₐᵇ f(x) dx with `n` equal subintervals.
+///
+/// **∫ₐᵇ f(x) dx ≈ h·(f(a)/2 + f(x₁) + ... + f(xₙ₋₁) + f(b)/2)**,  h = (b - a)/n
+///
+/// The error is -(b - a)·h²·f''(ξ)/12 for some ξ in [a, b], so linear functions
+/// are integrated exactly. For smooth periodic integrands over a full period the
+/// rule converges much faster, often to machine precision.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::trapezoidal;
+///
+/// // ∫₀¹ x² dx = 1/3, with error h²/6
+/// let value = trapezoidal(|x| x * x, 0.0, 1.0, 100);
+/// assert!((value - 1.0 / 3.0).abs() < 2e-5);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `n` is zero.
+pub fn trapezoidal<F>(f: F, a: f64, b: f64, n: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if n == 0 {
+        panic!("Composite quadrature requires at least one subinterval");
+    }
+
+    let h = (b - a) / n as f64;
+    let interior: f64 = (1..n).map(|i| f(a + i as f64 * h)).sum();
+    h * (0.5 * (f(a) + f(b)) + interior)
+}
+
+/// Composite Simpson's rule for ∫ₐᵇ f(x) dx with `n` equal subintervals.
+///
+/// **∫ₐᵇ f(x) dx ≈ h/3·(f(a) + 4f(x₁) + 2f(x₂) + 4f(x₃) + ... + 4f(xₙ₋₁) + f(b))**
+///
+/// Simpson's rule fits a parabola through each pair of subintervals, so `n`
+/// must be even; an odd `n` is rounded up to the next even number. The error is
+/// -(b - a)·h⁴·f⁽⁴⁾(ξ)/180, so cubics are integrated exactly.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::simpson;
+///
+/// // ∫₀^π sin x dx = 2
+/// let value = simpson(f64::sin, 0.0, std::f64::consts::PI, 20);
+/// assert!((value - 2.0).abs() < 1e-5);
+///
+/// // Exact for x², up to rounding
+/// assert!((simpson(|x| x * x, 0.0, 1.0, 2) - 1.0 / 3.0).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `n` is zero.
+pub fn simpson<F>(f: F, a: f64, b: f64, n: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if n == 0 {
+        panic!("Composite quadrature requires at least one subinterval");
+    }
+
+    let n = n + n % 2;
+    let h = (b - a) / n as f64;
+    let interior: f64 = (1..n)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * f(a + i as f64 * h)
+        })
+        .sum();
+    h / 3.0 * (f(a) + interior + f(b))
+}
+
+/// Adaptive Simpson integration of `f` over [a, b] with a recursion depth limit.
+///
+/// Simpson's rule S₁ on [a, b] is compared with the sum S₂ of Simpson's rule on
+/// the two halves. If they differ by more than 15·`tol`, so that the error
+/// estimate |S₂ - S₁|/15 of S₂ exceeds `tol`, each half is integrated
+/// recursively with tolerance `tol`/2; otherwise S₂ + (S₂ - S₁)/15 is accepted.
+/// Evaluations therefore concentrate where the integrand varies rapidly, while
+/// smooth stretches are covered by a few wide panels. A feature narrower than
+/// the spacing of the first five samples, at a, b and the quarter points, can
+/// be missed entirely, since S₁ and S₂ then agree.
+///
+/// This is the recursion of [`integrate_with_error`], returning only the value.
+///
+/// ## Arguments
+///
+/// * `f` - The integrand.
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+/// * `tol` - Requested absolute accuracy.
+/// * `max_depth` - Maximum number of bisections of any subinterval. Subintervals
+///   at this depth are accepted whatever their error estimate, which bounds
+///   the cost at about 2^(`max_depth` + 1) evaluations for integrands that never
+///   settle, such as those with singularities.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::adaptive_simpson;
+///
+/// // ∫₋₁₀¹⁰ dx/(1 + x²) = 2·atan(10)
+/// let value = adaptive_simpson(|x| 1.0 / (1.0 + x * x), -10.0, 10.0, 1e-10, 50);
+/// assert!((value - 2.0 * 10.0_f64.atan()).abs() < 1e-10);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `tol` is not positive.
+pub fn adaptive_simpson<F>(f: F, a: f64, b: f64, tol: f64, max_depth: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if tol <= 0.0 {
+        panic!("Integration tolerance must be positive");
+    }
+
+    let m = 0.5 * (a + b);
+    let (fa, fm, fb) = (f(a), f(m), f(b));
+    let whole = simpson_panel(a, b, fa, fm, fb);
+
+    adaptive_simpson_step(&f, a, b, fa, fm, fb, whole, tol, max_depth).0
+}
+
+/// The composite trapezoidal rule under repeated step halving, reusing every
+/// function value already computed.
+///
+/// The first call to [`refine`](TrapezoidRefiner::refine) evaluates the endpoints;
+/// each later call halves the step and adds only the new midpoints, using
+///
+/// ```text
+/// Tₖ = Tₖ₋₁/2 + h·Σ f(new midpoints)
+/// ```
+///
+/// as in Numerical Recipes' `trapzd`. After the first call and `k` further calls,
+/// the integrand has been evaluated at exactly 2ᵏ + 1 points, the same number a
+/// single trapezoidal rule with 2ᵏ intervals would need.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::TrapezoidRefiner;
+///
+/// let mut refiner = TrapezoidRefiner::new(0.0, 1.0);
+/// let f = |x: f64| x * x;
+///
+/// assert_eq!(refiner.refine(f), 0.5);
+/// assert_eq!(refiner.refine(f), 0.375);
+/// assert_eq!(refiner.refine(f), 0.343_75);
+/// assert_eq!(refiner.level(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrapezoidRefiner {
+    a: f64,
+    b: f64,
+    estimate: f64,
+    level: u32,
+}
+
+impl TrapezoidRefiner {
+    /// Creates a refiner for the integral over [a, b]; nothing is evaluated yet
+    pub fn new(a: f64, b: f64) -> Self {
+        TrapezoidRefiner {
+            a,
+            b,
+            estimate: 0.0,
+            level: 0,
+        }
+    }
+
+    /// Number of completed calls to [`refine`](TrapezoidRefiner::refine)
+    pub fn level(&self) -> u32 {
+        self.level
+    }
+
+    /// Halves the step and returns the new trapezoidal estimate.
+    ///
+    /// `f` must be the same integrand on every call, since earlier function
+    /// values are folded into the stored estimate.
+    pub fn refine<F>(&mut self, f: F) -> f64
+    where
+        F: Fn(f64) -> f64,
+    {
+        let width = self.b - self.a;
+
+        if self.level == 0 {
+            self.estimate = 0.5 * width * (f(self.a) + f(self.b));
+        } else {
+            // 2^(level - 1) new midpoints, spaced by the previous step
+            let count = 1u64 << (self.level - 1);
+            let step = width / count as f64;
+            let mut x = self.a + 0.5 * step;
+            let mut sum = 0.0;
+            for _ in 0..count {
+                sum += f(x);
+                x += step;
+            }
+            self.estimate = 0.5 * (self.estimate + step * sum);
+        }
+
+        self.level += 1;
+        self.estimate
+    }
+}
+
+/// Romberg integration of `f` over [a, b] with an error estimate.
+///
+/// Successive trapezoidal estimates with 1, 2, 4, ... intervals come from a
+/// [`TrapezoidRefiner`], so no function value is computed twice. Richardson
+/// extrapolation then cancels the error terms h², h⁴, h⁶, ... of the
+/// Euler-Maclaurin expansion one at a time:
+///
+/// ```text
+/// Rₖ,ⱼ = Rₖ,ⱼ₋₁ + (Rₖ,ⱼ₋₁ - Rₖ₋₁,ⱼ₋₁)/(4ʲ - 1)
+/// ```
+///
+/// The difference between the last two diagonal entries is the error estimate.
+/// Romberg integration converges very fast for smooth integrands, and poorly for
+/// integrands with singularities or kinks, where [`qag`] is the better choice.
+///
+/// ## Arguments
+///
+/// * `f` - The integrand.
+/// * `a` - Lower limit of integration.
+/// * `b` - Upper limit of integration.
+/// * `tol` - Requested absolute accuracy.
+/// * `max_levels` - Maximum number of trapezoidal refinements; level `k` costs
+///   2ᵏ⁻² new function evaluations.
+///
+/// ## Returns
+///
+/// A tuple `(estimate, error_estimate)`. If `max_levels` is reached first,
+/// `error_estimate` is larger than `tol`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::romberg;
+///
+/// // ∫₀^π sin(x) dx = 2
+/// let (value, error) = romberg(f64::sin, 0.0, std::f64::consts::PI, 1e-12, 20);
+///
+/// assert!(error < 1e-12);
+/// assert!((value - 2.0).abs() < 1e-12);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `tol` is not positive or `max_levels` is less than two.
+pub fn romberg<F>(f: F, a: f64, b: f64, tol: f64, max_levels: u32) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    if tol <= 0.0 {
+        panic!("Integration tolerance must be positive");
+    }
+    if max_levels < 2 {
+        panic!("Romberg integration requires at least two levels");
+    }
+
+    let (value, error, _) = romberg_table(f, a, b, max_levels, |_| tol);
+    (value, error)
+}
+
+/// Level cap of [`romberg_with_options`], about 2.7·10⁸ function evaluations
+const MAX_ROMBERG_LEVELS: usize = 30;
+
+/// Romberg integration configured by [`IterativeOptions`]
+///
+/// `max_iter` limits the number of trapezoidal refinements, and the difference
+/// between successive diagonal entries must drop below `tol`, taken relative to
+/// the magnitude of the integral when `relative` is set. Since level k costs
+/// 2ᵏ⁻² evaluations, at most 30 levels are used whatever `max_iter` says.
+/// `None` selects the default options; for smooth integrands the result matches
+/// `romberg(f, a, b, 1e-12, 100)`.
+///
+/// ## Returns
+///
+/// `(estimate, error_estimate)`, or `None` if the tolerance is not met within
+/// `max_iter` levels.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::IterativeOptions;
+/// use sophy::methods::integration::romberg_with_options;
+///
+/// let (value, _) = romberg_with_options(f64::exp, 0.0, 1.0, None).unwrap();
+/// assert!((value - (std::f64::consts::E - 1.0)).abs() < 1e-12);
+///
+/// // √x has an infinite slope at 0, which Romberg handles poorly
+/// let options = IterativeOptions { max_iter: 8, ..IterativeOptions::default() };
+/// assert_eq!(romberg_with_options(f64::sqrt, 0.0, 1.0, Some(options)), None);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the tolerance is not positive or `max_iter` is less than two.
+pub fn romberg_with_options<F>(
+    f: F,
+    a: f64,
+    b: f64,
+    options: Option<IterativeOptions>,
+) -> Option<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    let options = IterativeOptions::resolve(options);
+    if options.max_iter < 2 {
+        panic!("Romberg integration requires at least two levels");
+    }
+
+    let max_levels = options.max_iter.min(MAX_ROMBERG_LEVELS) as u32;
+    let (value, error, converged) =
+        romberg_table(f, a, b, max_levels, |value| options.tolerance(value));
+    converged.then_some((value, error))
+}
+
+/// The Romberg tableau shared by [`romberg`] and [`romberg_with_options`],
+/// returning `(estimate, error_estimate, converged)`
+///
+/// `tolerance(estimate)` is the absolute error to reach for the current
+/// estimate of the integral.
+fn romberg_table<F, T>(f: F, a: f64, b: f64, max_levels: u32, tolerance: T) -> (f64, f64, bool)
+where
+    F: Fn(f64) -> f64,
+    T: Fn(f64) -> f64,
+{
+    let mut refiner = TrapezoidRefiner::new(a, b);
+    let mut previous_row = vec![refiner.refine(&f)];
+    let mut error = f64::INFINITY;
+
+    for _ in 1..max_levels {
+        let mut row = vec![refiner.refine(&f)];
+        let mut factor = 1.0;
+        for (j, &above) in previous_row.iter().enumerate() {
+            factor *= 4.0;
+            row.push(row[j] + (row[j] - above) / (factor - 1.0));
+        }
+
+        error = (row[row.len() - 1] - previous_row[previous_row.len() - 1]).abs();
+        previous_row = row;
+        if error <= tolerance(previous_row[previous_row.len() - 1]) {
+            break;
+        }
+    }
+
+    let value = previous_row[previous_row.len() - 1];
+    (value, error, error <= tolerance(value))
+}
+
+/// Running trapezoidal integral of samples taken at a uniform spacing `dx`.
+///
+/// The first element is 0 and element i is the integral from the first sample to
+/// sample i, so the last element is the composite trapezoidal rule over all the
+/// data. This matches NumPy/SciPy's `cumulative_trapezoid(ys, dx=dx, initial=0)`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::cumulative_trapezoid;
+///
+/// // Samples of y = x at x = 0, 1, 2, 3; the running integral is x²/2
+/// let integral = cumulative_trapezoid(&[0.0, 1.0, 2.0, 3.0], 1.0);
+/// assert_eq!(integral, [0.0, 0.5, 2.0, 4.5]);
+/// ```
+pub fn cumulative_trapezoid(ys: &[f64], dx: f64) -> Vec<f64> {
+    let mut total = 0.0;
+    let mut result = Vec::with_capacity(ys.len());
+
+    if !ys.is_empty() {
+        result.push(0.0);
+    }
+    for pair in ys.windows(2) {
+        total += 0.5 * dx * (pair[0] + pair[1]);
+        result.push(total);
+    }
+
+    result
+}
+
+/// Running trapezoidal integral of samples taken at arbitrary positions `xs`.
+///
+/// Like [`cumulative_trapezoid`], but each interval uses its own width
+/// xᵢ₊₁ - xᵢ. Decreasing positions give negatively oriented contributions.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::integration::cumulative_trapezoid_nonuniform;
+///
+/// let xs = [0.0, 0.5, 2.0];
+/// let integral = cumulative_trapezoid_nonuniform(&xs, &[1.0, 1.0, 1.0]);
+/// assert_eq!(integral, [0.0, 0.5, 2.0]);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `xs` and `ys` differ in length.
+pub fn cumulative_trapezoid_nonuniform(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    if xs.len() != ys.len() {
+        panic!("Cumulative integration requires xs and ys of equal length");
+    }
+
+    let mut total = 0.0;
+    let mut result = Vec::with_capacity(ys.len());
+
+    if !ys.is_empty() {
+        result.push(0.0);
+    }
+    for (x, y) in xs.windows(2).zip(ys.windows(2)) {
+        total += 0.5 * (x[1] - x[0]) * (y[0] + y[1]);
+        result.push(total);
+    }
+
+    result
+}
+
+/// Simpson's rule on [a, b] from the function values at a, the midpoint and b
+fn simpson_panel(a: f64, b: f64, fa: f64, fm: f64, fb: f64) -> f64 {
+    (b - a) / 6.0 * (fa + 4.0 * fm + fb)
+}
+
+/// Recursive step of [`integrate_with_error`] and [`adaptive_simpson`], reusing
+/// the three known samples
+#[allow(clippy::too_many_arguments)]
+fn adaptive_simpson_step<F>(
+    f: &F,
+    a: f64,
+    b: f64,
+    fa: f64,
+    fm: f64,
+    fb: f64,
+    whole: f64,
+    tol: f64,
+    depth: usize,
+) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    let m = 0.5 * (a + b);
+    let (lm, rm) = (0.5 * (a + m), 0.5 * (m + b));
+    let (flm, frm) = (f(lm), f(rm));
+
+    let left = simpson_panel(a, m, fa, flm, fm);
+    let right = simpson_panel(m, b, fm, frm, fb);
+    let delta = left + right - whole;
+
+    if depth == 0 || delta.abs() <= 15.0 * tol {
+        return (left + right + delta / 15.0, delta.abs() / 15.0);
+    }
+
+    let (left_value, left_error) =
+        adaptive_simpson_step(f, a, m, fa, flm, fm, left, 0.5 * tol, depth - 1);
+    let (right_value, right_error) =
+        adaptive_simpson_step(f, m, b, fm, frm, fb, right, 0.5 * tol, depth - 1);
+
+    (left_value + right_value, left_error + right_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_gauss_legendre_nodes_tabulated() {
+        // Abramowitz & Stegun, Table 25.4 (n = 5)
+        let expected_nodes = [
+            -0.906_179_845_938_664,
+            -0.538_469_310_105_683_1,
+            0.0,
+            0.538_469_310_105_683_1,
+            0.906_179_845_938_664,
+        ];
+        let expected_weights = [
+            0.236_926_885_056_189_1,
+            0.478_628_670_499_366_5,
+            0.568_888_888_888_888_9,
+            0.478_628_670_499_366_5,
+            0.236_926_885_056_189_1,
+        ];
+
+        let (nodes, weights) = gauss_legendre_nodes(5);
+        assert_eq!(nodes.len(), 5);
+        for i in 0..5 {
+            assert!((nodes[i] - expected_nodes[i]).abs() < 1e-13);
+            assert!((weights[i] - expected_weights[i]).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_gauss_legendre_empty() {
+        let (nodes, weights) = gauss_legendre_nodes(0);
+        assert!(nodes.is_empty());
+        assert!(weights.is_empty());
+    }
+
+    #[test]
+    fn test_gauss_legendre_high_order_polynomial_exact() {
+        // 20 points integrate degree ≤ 39 exactly: ∫₀¹ x³⁸ dx = 1/39
+        let integral = gauss_legendre(|x: f64| x.powi(38), 0.0, 1.0, 20);
+        assert!((integral - 1.0 / 39.0).abs() < 1e-14);
+
+        // ∫₋₁² (x⁵ - 3x² + 1) dx = 21/2 - 9 + 3 = 4.5
+        let integral = gauss_legendre(|x: f64| x.powi(5) - 3.0 * x * x + 1.0, -1.0, 2.0, 3);
+        assert!((integral - 4.5).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_gauss_legendre_smooth_function() {
+        // ∫₀¹ eˣ dx = e - 1
+        let integral = gauss_legendre(|x: f64| x.exp(), 0.0, 1.0, 8);
+        assert!((integral - (std::f64::consts::E - 1.0)).abs() < 1e-14);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gauss-Legendre quadrature requires at least one point")]
+    fn test_gauss_legendre_zero_points() {
+        gauss_legendre(|x| x, 0.0, 1.0, 0);
+    }
+
+    #[test]
+    fn test_gauss_chebyshev_moments() {
+        assert!((gauss_chebyshev(|_| 1.0, 1) - PI).abs() < 1e-15);
+        assert!((gauss_chebyshev(|x| x * x, 2) - PI / 2.0).abs() < 1e-15);
+
+        // ∫₋₁¹ x²ᵏ/√(1 - x²) dx = π·(2k)!/(2ᵏk!)², exact for 2k ≤ 2n - 1
+        let n = 6;
+        let mut moment = PI;
+        for k in 0..n {
+            let even = gauss_chebyshev(|x| x.powi(2 * k as i32), n);
+            let odd = gauss_chebyshev(|x| x.powi(2 * k as i32 + 1), n);
+            assert!((even - moment).abs() < 1e-14, "k = {k}");
+            assert!(odd.abs() < 1e-15, "k = {k}");
+            moment *= (2 * k + 1) as f64 / (2 * k + 2) as f64;
+        }
+    }
+
+    #[test]
+    fn test_gauss_chebyshev_orthogonality() {
+        // ∫ Tₘ Tₙ / √(1 - x²) = 0 for m ≠ n, with T₃ = 4x³ - 3x and T₅ = 16x⁵ - 20x³ + 5x
+        let t3 = |x: f64| 4.0 * x.powi(3) - 3.0 * x;
+        let t5 = |x: f64| 16.0 * x.powi(5) - 20.0 * x.powi(3) + 5.0 * x;
+        assert!(gauss_chebyshev(|x| t3(x) * t5(x), 5).abs() < 1e-14);
+        assert!((gauss_chebyshev(|x| t3(x) * t3(x), 5) - PI / 2.0).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_gauss_chebyshev_smooth_function() {
+        // ∫₋₁¹ eˣ/√(1 - x²) dx = π·I₀(1)
+        let bessel_i0_1 = 1.266_065_877_752_008_4;
+        let integral = gauss_chebyshev(f64::exp, 10);
+        assert!((integral - PI * bessel_i0_1).abs() < 1e-14);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gauss-Chebyshev quadrature requires at least one point")]
+    fn test_gauss_chebyshev_zero_points() {
+        gauss_chebyshev(|x| x, 0);
+    }
+
+    #[test]
+    fn test_integrate_with_error_smooth() {
+        // ∫₀^π sin(x) dx = 2
+        let (value, error) = integrate_with_error(|x: f64| x.sin(), 0.0, PI, 1e-10);
+
+        assert!(error < 1e-10);
+        assert!((value - 2.0).abs() <= error);
+    }
+
+    #[test]
+    fn test_integrate_with_error_spiky() {
+        // A narrow Lorentzian peak: ∫₀¹ ε/((x - 0.5)² + ε²) dx = 2·atan(0.5/ε)
+        let eps = 1e-2;
+        let spike = |x: f64| eps / ((x - 0.5).powi(2) + eps * eps);
+        let exact = 2.0 * (0.5 / eps).atan();
+
+        // With a loose tolerance the peak is only partly resolved: the estimate is far
+        // larger than for a smooth integrand, and still bounds the true error
+        let (value, error) = integrate_with_error(spike, 0.0, 1.0, 0.1);
+        let (_, smooth_error) = integrate_with_error(|x: f64| x.exp(), 0.0, 1.0, 0.1);
+        assert!((value - exact).abs() <= error);
+        assert!(error > 10.0 * smooth_error);
+
+        // Tightening the tolerance resolves the peak
+        let (value, error) = integrate_with_error(spike, 0.0, 1.0, 1e-10);
+        assert!(error < 1e-10);
+        assert!((value - exact).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_integrate_with_error_reports_unresolved_singularity() {
+        // 1/x is not integrable on [0, 1]; the depth limit stops the bisection and the
+        // error estimate exposes the failure
+        let (_, error) = integrate_with_error(|x: f64| 1.0 / x.max(1e-300), 0.0, 1.0, 1e-8);
+        assert!(error > 1e-8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Integration tolerance must be positive")]
+    fn test_integrate_with_error_invalid_tolerance() {
+        integrate_with_error(|x| x, 0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_polynomials_exact() {
+        // Both rules are exact up to degree 13, so the error estimate vanishes
+        let (value, error) = gauss_kronrod(|x: f64| x.powi(13) - 2.0 * x.powi(6), -1.0, 2.0);
+        let exact = (2.0_f64.powi(14) - 1.0) / 14.0 - 2.0 * (2.0_f64.powi(7) + 1.0) / 7.0;
+        assert!((value - exact).abs() < 1e-11);
+        assert!(error < 1e-11);
+
+        // K15 remains exact up to degree 22, where G7 is not
+        let (value, error) = gauss_kronrod(|x: f64| x.powi(22), 0.0, 1.0);
+        assert!((value - 1.0 / 23.0).abs() < 1e-15);
+        assert!(error > 1e-8);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_error_bounds_true_error() {
+        // ∫₀² cos(5x) dx = sin(10)/5
+        let (value, error) = gauss_kronrod(|x: f64| (5.0 * x).cos(), 0.0, 2.0);
+        let exact = 10.0_f64.sin() / 5.0;
+        assert!((value - exact).abs() <= error);
+        assert!(error < 1e-4);
+    }
+
+    #[test]
+    fn test_gauss_kronrod_weights_sum() {
+        let kronrod: f64 = KRONROD_WEIGHTS[7] + 2.0 * KRONROD_WEIGHTS[..7].iter().sum::<f64>();
+        let gauss: f64 = GAUSS_WEIGHTS[3] + 2.0 * GAUSS_WEIGHTS[..3].iter().sum::<f64>();
+        assert!((kronrod - 2.0).abs() < 1e-15);
+        assert!((gauss - 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_qag_resolves_narrow_peak() {
+        // ∫₀¹ ε/((x - 0.3)² + ε²) dx with a peak of width 1e-4
+        let eps = 1e-4;
+        let spike = |x: f64| eps / ((x - 0.3).powi(2) + eps * eps);
+        let exact = (0.7 / eps).atan() + (0.3 / eps).atan();
+
+        // A single G7-K15 rule does not even see the peak
+        let (fixed, _) = gauss_kronrod(spike, 0.0, 1.0);
+        assert!((fixed - exact).abs() > 1.0);
+
+        let (value, error) = qag(spike, 0.0, 1.0, 1e-10, 1000);
+        assert!(error < 1e-10);
+        assert!((value - exact).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_qag_pi() {
+        let (value, error) = qag(|x: f64| 4.0 / (1.0 + x * x), 0.0, 1.0, 1e-13, 100);
+        assert!(error < 1e-13);
+        assert!((value - PI).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_qag_respects_interval_cap() {
+        let evaluations = std::cell::Cell::new(0);
+        let spike = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            1e-4 / ((x - 0.3).powi(2) + 1e-8)
+        };
+
+        let (_, error) = qag(spike, 0.0, 1.0, 1e-10, 3);
+
+        // One initial rule plus two bisections of two rules each
+        assert_eq!(evaluations.get(), 5 * 15);
+        assert!(error > 1e-10);
+    }
+
+    #[test]
+    fn test_qag_with_options() {
+        let f = |x: f64| 4.0 / (1.0 + x * x);
+        assert_eq!(
+            qag_with_options(f, 0.0, 1.0, None),
+            Some(qag(f, 0.0, 1.0, 1e-12, 100))
+        );
+
+        let spike = |x: f64| 1e-4 / ((x - 0.3).powi(2) + 1e-8);
+        let few = IterativeOptions {
+            max_iter: 3,
+            ..IterativeOptions::default()
+        };
+        assert_eq!(qag_with_options(spike, 0.0, 1.0, Some(few)), None);
+        assert!(qag_with_options(spike, 0.0, 1.0, None).is_some());
+
+        // A large integral meets a relative tolerance that it misses as absolute
+        let large = |x: f64| 1e12 * x.exp();
+        let relative = IterativeOptions {
+            tol: 1e-13,
+            relative: true,
+            ..IterativeOptions::default()
+        };
+        let (value, error) = qag_with_options(large, 0.0, 1.0, Some(relative)).unwrap();
+        assert!(error <= 1e-13 * value);
+        assert!((value / (1e12 * (std::f64::consts::E - 1.0)) - 1.0).abs() < 1e-14);
+    }
+
+    #[test]
+    #[should_panic(expected = "Adaptive integration requires at least one interval")]
+    fn test_qag_zero_intervals() {
+        qag(|x| x, 0.0, 1.0, 1e-8, 0);
+    }
+
+    #[test]
+    fn test_cumulative_trapezoid_constant_is_linear() {
+        let integral = cumulative_trapezoid(&[2.0; 6], 0.5);
+        for (i, value) in integral.iter().enumerate() {
+            assert!((value - i as f64).abs() < 1e-15);
+        }
+        assert!(cumulative_trapezoid(&[], 1.0).is_empty());
+        assert_eq!(cumulative_trapezoid(&[3.0], 1.0), [0.0]);
+    }
+
+    #[test]
+    fn test_cumulative_trapezoid_of_x() {
+        let n = 1000;
+        let dx = 2.0 / n as f64;
+        let ys: Vec<f64> = (0..=n).map(|i| i as f64 * dx).collect();
+
+        // The trapezoidal rule is exact for linear integrands
+        let integral = cumulative_trapezoid(&ys, dx);
+        for (x, value) in ys.iter().zip(&integral) {
+            assert!((value - 0.5 * x * x).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cumulative_trapezoid_last_is_total() {
+        let xs = [0.0, 0.1, 0.35, 0.4, 0.9, 1.0];
+        let ys: Vec<f64> = xs.iter().map(|x: &f64| x.exp()).collect();
+
+        let total: f64 = xs
+            .windows(2)
+            .zip(ys.windows(2))
+            .map(|(x, y)| 0.5 * (x[1] - x[0]) * (y[0] + y[1]))
+            .sum();
+        let integral = cumulative_trapezoid_nonuniform(&xs, &ys);
+        assert_eq!(integral.len(), xs.len());
+        assert!((integral[xs.len() - 1] - total).abs() < 1e-15);
+        assert!((total - (1.0_f64.exp() - 1.0)).abs() < 3e-2);
+
+        // Uniform positions agree with the spacing-based variant
+        let uniform: Vec<f64> = (0..6).map(|i| 0.2 * i as f64).collect();
+        let a = cumulative_trapezoid_nonuniform(&uniform, &ys);
+        let b = cumulative_trapezoid(&ys, 0.2);
+        for (u, v) in a.iter().zip(&b) {
+            assert!((u - v).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cumulative integration requires xs and ys of equal length")]
+    fn test_cumulative_trapezoid_length_mismatch() {
+        cumulative_trapezoid_nonuniform(&[0.0, 1.0], &[1.0]);
+    }
+
+    #[test]
+    fn test_trapezoid_refiner_converges() {
+        let mut refiner = TrapezoidRefiner::new(0.0, 1.0);
+        let f = |x: f64| x * x;
+
+        // The error of the trapezoidal rule for x² is exactly h²/6
+        for k in 0..12 {
+            let h = 1.0 / (1u64 << k) as f64;
+            let estimate = refiner.refine(f);
+            assert!((estimate - (1.0 / 3.0 + h * h / 6.0)).abs() < 1e-15);
+        }
+        assert_eq!(refiner.level(), 12);
+    }
+
+    #[test]
+    fn test_trapezoid_refiner_reuses_evaluations() {
+        let evaluations = Cell::new(0u64);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x.exp()
+        };
+
+        let mut refiner = TrapezoidRefiner::new(-1.0, 2.0);
+        refiner.refine(f);
+        assert_eq!(evaluations.get(), 2);
+
+        for k in 1..=10 {
+            refiner.refine(f);
+            assert_eq!(evaluations.get(), (1 << k) + 1);
+        }
+    }
+
+    #[test]
+    fn test_romberg_smooth_functions() {
+        let (value, error) = romberg(|x: f64| x * x, 0.0, 1.0, 1e-14, 10);
+        assert!((value - 1.0 / 3.0).abs() < 1e-15);
+        assert!(error < 1e-14);
+
+        let (value, error) = romberg(|x: f64| x.exp(), 0.0, 1.0, 1e-13, 20);
+        assert!((value - (std::f64::consts::E - 1.0)).abs() < 1e-13);
+        assert!(error < 1e-13);
+    }
+
+    #[test]
+    fn test_romberg_reports_level_limit() {
+        // √x has an infinite derivative at 0, which spoils the extrapolation
+        let (value, error) = romberg(|x: f64| x.sqrt(), 0.0, 1.0, 1e-15, 4);
+        assert!(error > 1e-15);
+        assert!((value - 2.0 / 3.0).abs() < 1e-1);
+    }
+
+    #[test]
+    fn test_romberg_with_options() {
+        assert_eq!(
+            romberg_with_options(f64::exp, 0.0, 1.0, None),
+            Some(romberg(f64::exp, 0.0, 1.0, 1e-12, 100))
+        );
+
+        let few = IterativeOptions {
+            max_iter: 4,
+            ..IterativeOptions::default()
+        };
+        assert_eq!(romberg_with_options(f64::sqrt, 0.0, 1.0, Some(few)), None);
+        assert_eq!(romberg_with_options(f64::exp, 0.0, 1.0, Some(few)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Romberg integration requires at least two levels")]
+    fn test_romberg_with_options_one_level() {
+        let options = IterativeOptions {
+            max_iter: 1,
+            ..IterativeOptions::default()
+        };
+        romberg_with_options(f64::exp, 0.0, 1.0, Some(options));
+    }
+
+    #[test]
+    #[should_panic(expected = "Romberg integration requires at least two levels")]
+    fn test_romberg_too_few_levels() {
+        romberg(|x: f64| x, 0.0, 1.0, 1e-8, 1);
+    }
+
+    #[test]
+    fn test_trapezoidal_and_simpson_x_squared() {
+        let f = |x: f64| x * x;
+        let exact = 1.0 / 3.0;
+
+        // The trapezoidal error for x² on [0, 1] is exactly h²/6
+        let n = 10;
+        let h = 1.0 / n as f64;
+        assert!((trapezoidal(f, 0.0, 1.0, n) - exact - h * h / 6.0).abs() < 1e-15);
+
+        // Simpson's rule is exact for quadratics
+        assert!((simpson(f, 0.0, 1.0, n) - exact).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_simpson_more_accurate_than_trapezoidal() {
+        for n in [4, 16, 64] {
+            let trapezoid_error = (trapezoidal(f64::sin, 0.0, PI, n) - 2.0).abs();
+            let simpson_error = (simpson(f64::sin, 0.0, PI, n) - 2.0).abs();
+            assert!(
+                simpson_error < 0.05 * trapezoid_error,
+                "n = {n}: {simpson_error:e} vs {trapezoid_error:e}"
+            );
+        }
+
+        // Fourth-order convergence: doubling n divides the error by about 16
+        let ratio = (simpson(f64::sin, 0.0, PI, 16) - 2.0) / (simpson(f64::sin, 0.0, PI, 32) - 2.0);
+        assert!((ratio - 16.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_simpson_rounds_odd_n_up() {
+        let f = |x: f64| x.exp();
+        assert_eq!(simpson(f, 0.0, 1.0, 7), simpson(f, 0.0, 1.0, 8));
+        assert_eq!(simpson(f, 0.0, 1.0, 1), simpson(f, 0.0, 1.0, 2));
+    }
+
+    #[test]
+    fn test_composite_rules_reversed_limits() {
+        let f = |x: f64| x.cos();
+        assert!((trapezoidal(f, 1.0, 0.0, 8) + trapezoidal(f, 0.0, 1.0, 8)).abs() < 1e-15);
+        assert!((simpson(f, 1.0, 0.0, 8) + simpson(f, 0.0, 1.0, 8)).abs() < 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "Composite quadrature requires at least one subinterval")]
+    fn test_trapezoidal_zero_subintervals() {
+        trapezoidal(|x| x, 0.0, 1.0, 0);
+    }
+
+    /// Evaluations fixed-n Simpson needs for an error below `target`, doubling n
+    fn simpson_evaluations_for<F: Fn(f64) -> f64>(
+        f: F,
+        a: f64,
+        b: f64,
+        exact: f64,
+        target: f64,
+    ) -> usize {
+        let mut n = 2;
+        while (simpson(&f, a, b, n) - exact).abs() > target {
+            n *= 2;
+            assert!(n < 1 << 24, "fixed Simpson did not reach {target:e}");
+        }
+        n + 1
+    }
+
+    #[test]
+    fn test_adaptive_simpson_lorentzian() {
+        let lorentzian = |x: f64| 1.0 / (1.0 + x * x);
+        let exact = 2.0 * 10.0_f64.atan();
+        let evaluations = Cell::new(0);
+        let counted = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            lorentzian(x)
+        };
+
+        let value = adaptive_simpson(counted, -10.0, 10.0, 1e-8, 50);
+        let error = (value - exact).abs();
+        assert!(error < 1e-8, "{error:e}");
+
+        // Fewer evaluations than fixed Simpson at the same accuracy, though the
+        // integrand is smooth enough that the saving is modest
+        let fixed = simpson_evaluations_for(lorentzian, -10.0, 10.0, exact, error);
+        assert!(
+            evaluations.get() < fixed,
+            "{} vs {fixed}",
+            evaluations.get()
+        );
+    }
+
+    #[test]
+    fn test_adaptive_simpson_sharp_peak() {
+        // A Gaussian of width 10⁻³, negligible at ±1
+        let peak = |x: f64| (-(x / 1e-3).powi(2)).exp();
+        let exact = 1e-3 * PI.sqrt();
+        let evaluations = Cell::new(0);
+        let counted = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            peak(x)
+        };
+
+        let value = adaptive_simpson(counted, -1.0, 1.0, 1e-8, 50);
+        let error = (value - exact).abs();
+        assert!(error < 1e-8, "{error:e}");
+
+        // Fixed Simpson must resolve the peak everywhere at once
+        let fixed = simpson_evaluations_for(peak, -1.0, 1.0, exact, error);
+        assert!(
+            10 * evaluations.get() < fixed,
+            "{} vs {fixed}",
+            evaluations.get()
+        );
+    }
+
+    #[test]
+    fn test_adaptive_simpson_depth_limit() {
+        let evaluations = Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x.sqrt()
+        };
+
+        // Depth zero is one refinement: Simpson on both halves, extrapolated
+        let value = adaptive_simpson(f, 0.0, 1.0, 1e-15, 0);
+        assert_eq!(evaluations.replace(0), 5);
+        assert!((value - 2.0 / 3.0).abs() < 0.02);
+
+        // The √x endpoint singularity never meets 10⁻¹⁵; the depth bounds the cost
+        adaptive_simpson(f, 0.0, 1.0, 1e-15, 8);
+        assert!(
+            evaluations.get() <= 2usize.pow(10) + 1,
+            "{}",
+            evaluations.get()
+        );
+    }
+
+    #[test]
+    fn test_adaptive_simpson_matches_integrate_with_error() {
+        let f = |x: f64| x.sin() * (-x).exp();
+        let (value, _) = integrate_with_error(f, 0.0, 5.0, 1e-9);
+        assert_eq!(adaptive_simpson(f, 0.0, 5.0, 1e-9, 50), value);
+    }
+}
@@ -0,0 +1,520 @@
+//! # Interpolation
+//!
+//! Methods for constructing functions that pass through a set of data points.
+//!
+//! ## Cubic Splines
+//!
+//! A cubic spline is a piecewise cubic polynomial that interpolates the data and
+//! has continuous first and second derivatives. The *natural* spline additionally
+//! has zero second derivative at both ends.
+//!
+//! ## Polynomial Interpolation
+//!
+//! - [`lagrange`]: The interpolating polynomial through arbitrary nodes
+//! - [`newton_forward`] / [`newton_backward`]: Newton's difference formulas for
+//!   equally spaced nodes, built on [`forward_difference_table`]
+
+use crate::base::matrix::solve_tridiagonal;
+
+/// Evaluates the Lagrange interpolating polynomial through `(xs[i], ys[i])` at `x`
+///
+/// ```text
+/// L(x) = Σᵢ yᵢ · Πⱼ≠ᵢ (x - xⱼ)/(xᵢ - xⱼ)
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::interpolation::lagrange;
+///
+/// // Three points determine the parabola y = x²
+/// let xs = [0.0, 1.0, 3.0];
+/// let ys = [0.0, 1.0, 9.0];
+/// assert!((lagrange(&xs, &ys, 2.0) - 4.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `xs` and `ys` have different lengths or are empty.
+pub fn lagrange(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    check_nodes(xs, ys);
+
+    xs.iter()
+        .zip(ys)
+        .enumerate()
+        .map(|(i, (&xi, &yi))| {
+            let basis: f64 = xs
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, &xj)| (x - xj) / (xi - xj))
+                .product();
+            yi * basis
+        })
+        .sum()
+}
+
+/// Builds the full table of forward differences of `ys`
+///
+/// Row `k` of the result holds the k-th differences Δᵏyᵢ, where
+///
+/// ```text
+/// Δ⁰yᵢ = yᵢ
+/// Δᵏyᵢ = Δᵏ⁻¹yᵢ₊₁ - Δᵏ⁻¹yᵢ
+/// ```
+///
+/// so the rows shrink by one element each, ending with the single value Δⁿ⁻¹y₀.
+/// The same table read along its last column gives the backward differences ∇ᵏyₙ₋₁.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::interpolation::forward_difference_table;
+///
+/// let table = forward_difference_table(&[1.0, 8.0, 27.0, 64.0]);
+///
+/// assert_eq!(table[1], vec![7.0, 19.0, 37.0]);
+/// assert_eq!(table[2], vec![12.0, 18.0]);
+/// assert_eq!(table[3], vec![6.0]);  // third differences of cubes are 3! = 6
+/// ```
+pub fn forward_difference_table(ys: &[f64]) -> Vec<Vec<f64>> {
+    let mut table: Vec<Vec<f64>> = Vec::with_capacity(ys.len());
+    if ys.is_empty() {
+        return table;
+    }
+
+    table.push(ys.to_vec());
+    while table.last().unwrap().len() > 1 {
+        let next = table
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect();
+        table.push(next);
+    }
+
+    table
+}
+
+/// Newton's forward difference interpolation on equally spaced nodes
+///
+/// With h the node spacing and s = (x - x₀)/h,
+///
+/// ```text
+/// P(x) = y₀ + s·Δy₀ + s(s-1)/2!·Δ²y₀ + ... + s(s-1)···(s-n+2)/(n-1)!·Δⁿ⁻¹y₀
+/// ```
+///
+/// This is the same polynomial as [`lagrange`], written in a form that is most
+/// accurate near the start of the table.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::interpolation::newton_forward;
+///
+/// let xs = [1.0, 2.0, 3.0, 4.0];
+/// let ys = [1.0, 8.0, 27.0, 64.0];
+/// assert!((newton_forward(&xs, &ys, 1.5) - 3.375).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `xs` and `ys` have different lengths, are empty, or if the nodes
+/// are not equally spaced.
+pub fn newton_forward(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let h = uniform_spacing(xs, ys);
+    let table = forward_difference_table(ys);
+    let s = (x - xs[0]) / h;
+
+    let mut result = 0.0;
+    let mut coeff = 1.0;
+    for (k, row) in table.iter().enumerate() {
+        result += coeff * row[0];
+        coeff *= (s - k as f64) / (k + 1) as f64;
+    }
+
+    result
+}
+
+/// Newton's backward difference interpolation on equally spaced nodes
+///
+/// With h the node spacing and s = (x - xₙ₋₁)/h,
+///
+/// ```text
+/// P(x) = yₙ₋₁ + s·∇yₙ₋₁ + s(s+1)/2!·∇²yₙ₋₁ + ...
+/// ```
+///
+/// This is the same polynomial as [`newton_forward`], written in a form that is
+/// most accurate near the end of the table.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::interpolation::newton_backward;
+///
+/// let xs = [1.0, 2.0, 3.0, 4.0];
+/// let ys = [1.0, 8.0, 27.0, 64.0];
+/// assert!((newton_backward(&xs, &ys, 3.5) - 42.875).abs() < 1e-13);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `xs` and `ys` have different lengths, are empty, or if the nodes
+/// are not equally spaced.
+pub fn newton_backward(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let h = uniform_spacing(xs, ys);
+    let table = forward_difference_table(ys);
+    let s = (x - xs[xs.len() - 1]) / h;
+
+    let mut result = 0.0;
+    let mut coeff = 1.0;
+    for (k, row) in table.iter().enumerate() {
+        result += coeff * row[row.len() - 1];
+        coeff *= (s + k as f64) / (k + 1) as f64;
+    }
+
+    result
+}
+
+/// Validates matching, non-empty node and value slices
+fn check_nodes(xs: &[f64], ys: &[f64]) {
+    if xs.len() != ys.len() {
+        panic!("Interpolation requires xs and ys of equal length");
+    }
+    if xs.is_empty() {
+        panic!("Interpolation requires at least one point");
+    }
+}
+
+/// Validates the nodes and returns their common spacing
+fn uniform_spacing(xs: &[f64], ys: &[f64]) -> f64 {
+    check_nodes(xs, ys);
+    if xs.len() == 1 {
+        return 1.0;
+    }
+
+    let h = xs[1] - xs[0];
+    if h == 0.0
+        || xs
+            .windows(2)
+            .any(|w| ((w[1] - w[0]) - h).abs() > 1e-9 * h.abs())
+    {
+        panic!("Newton difference interpolation requires equally spaced nodes");
+    }
+    h
+}
+
+/// A natural cubic spline through a set of points
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::methods::interpolation::CubicSpline;
+///
+/// let xs = [0.0, 1.0, 2.0, 3.0];
+/// let ys = [0.0, 1.0, 8.0, 27.0];
+/// let spline = CubicSpline::natural(&xs, &ys);
+///
+/// // The spline passes through every node
+/// for (&x, &y) in xs.iter().zip(&ys) {
+///     assert!((spline.eval(x) - y).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    /// Second derivatives of the spline at the nodes
+    m: Vec<f64>,
+}
+
+impl CubicSpline {
+    /// Builds the natural cubic spline through the points `(xs[i], ys[i])`
+    ///
+    /// The second derivatives at the nodes satisfy a symmetric, diagonally
+    /// dominant tridiagonal system, which is solved in O(n) with the Thomas
+    /// algorithm.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if fewer than two points are given, if `xs` and `ys` have different
+    /// lengths, or if `xs` is not strictly increasing.
+    pub fn natural(xs: &[f64], ys: &[f64]) -> Self {
+        if xs.len() != ys.len() {
+            panic!("Interpolation requires xs and ys of equal length");
+        }
+        if xs.len() < 2 {
+            panic!("Cubic spline requires at least two points");
+        }
+        if xs.windows(2).any(|w| w[1] <= w[0]) {
+            panic!("Interpolation nodes must be strictly increasing");
+        }
+
+        let n = xs.len();
+        let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut m = vec![0.0; n];
+
+        if n > 2 {
+            let interior = n - 2;
+            let diag: Vec<f64> = (1..=interior).map(|i| 2.0 * (h[i - 1] + h[i])).collect();
+            let off: Vec<f64> = (2..=interior).map(|i| h[i - 1]).collect();
+            let rhs: Vec<f64> = (1..=interior)
+                .map(|i| 6.0 * ((ys[i + 1] - ys[i]) / h[i] - (ys[i] - ys[i - 1]) / h[i - 1]))
+                .collect();
+
+            let interior_m = solve_tridiagonal(&off, &diag, &off, &rhs)
+                .expect("spline system is strictly diagonally dominant");
+            m[1..=interior].copy_from_slice(&interior_m);
+        }
+
+        CubicSpline {
+            xs: xs.to_vec(),
+            ys: ys.to_vec(),
+            m,
+        }
+    }
+
+    /// Evaluates the spline at `x`
+    ///
+    /// Points outside the data range are extrapolated with the first or last cubic
+    /// segment.
+    pub fn eval(&self, x: f64) -> f64 {
+        let i = self.segment(x);
+        let (x0, x1) = (self.xs[i], self.xs[i + 1]);
+        let (y0, y1) = (self.ys[i], self.ys[i + 1]);
+        let (m0, m1) = (self.m[i], self.m[i + 1]);
+        let h = x1 - x0;
+
+        let a = x1 - x;
+        let b = x - x0;
+
+        m0 * a.powi(3) / (6.0 * h)
+            + m1 * b.powi(3) / (6.0 * h)
+            + (y0 / h - m0 * h / 6.0) * a
+            + (y1 / h - m1 * h / 6.0) * b
+    }
+
+    /// Integrates the spline exactly over [a, b]
+    ///
+    /// Each cubic segment is integrated in closed form, and partial segments at
+    /// the ends of the interval are handled exactly. As in [`eval`](Self::eval),
+    /// parts of [a, b] outside the data range use the first or last segment.
+    /// Reversing the limits changes the sign of the result.
+    ///
+    /// For smooth data this is usually far more accurate than applying the
+    /// trapezoidal rule to the raw samples.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::methods::interpolation::CubicSpline;
+    ///
+    /// // A natural spline reproduces straight lines exactly
+    /// let spline = CubicSpline::natural(&[0.0, 1.0, 2.0], &[0.0, 2.0, 4.0]);
+    /// assert!((spline.integrate(0.0, 2.0) - 4.0).abs() < 1e-14);
+    /// assert!((spline.integrate(0.5, 1.5) - 2.0).abs() < 1e-14);
+    /// ```
+    pub fn integrate(&self, a: f64, b: f64) -> f64 {
+        if a == b {
+            return 0.0;
+        }
+        if a > b {
+            return -self.integrate(b, a);
+        }
+
+        let last = self.xs.len() - 2;
+        let mut total = 0.0;
+        let mut x = a;
+        while x < b {
+            let i = self.segment(x);
+            let end = if i == last { b } else { self.xs[i + 1].min(b) };
+            total += self.segment_antiderivative(i, end) - self.segment_antiderivative(i, x);
+            x = end;
+        }
+        total
+    }
+
+    /// Antiderivative of the cubic on segment `i`, evaluated at `x`
+    ///
+    /// Only differences between values on the same segment are meaningful.
+    fn segment_antiderivative(&self, i: usize, x: f64) -> f64 {
+        let (x0, x1) = (self.xs[i], self.xs[i + 1]);
+        let (y0, y1) = (self.ys[i], self.ys[i + 1]);
+        let (m0, m1) = (self.m[i], self.m[i + 1]);
+        let h = x1 - x0;
+
+        let a = x1 - x;
+        let b = x - x0;
+
+        -m0 * a.powi(4) / (24.0 * h) + m1 * b.powi(4) / (24.0 * h)
+            - (y0 / h - m0 * h / 6.0) * a * a / 2.0
+            + (y1 / h - m1 * h / 6.0) * b * b / 2.0
+    }
+
+    /// Index of the segment [xs[i], xs[i+1]] used to evaluate at `x`
+    fn segment(&self, x: f64) -> usize {
+        let last = self.xs.len() - 2;
+        self.xs
+            .partition_point(|&xi| xi <= x)
+            .saturating_sub(1)
+            .min(last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_difference_table_cubes() {
+        let table = forward_difference_table(&[1.0, 8.0, 27.0, 64.0, 125.0]);
+        assert_eq!(table.len(), 5);
+        assert_eq!(table[0], vec![1.0, 8.0, 27.0, 64.0, 125.0]);
+        assert!(table[3].iter().all(|&d| d == 6.0));
+        assert_eq!(table[4], vec![0.0]);
+
+        assert!(forward_difference_table(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_newton_reproduces_nodes() {
+        let xs = [0.0, 0.5, 1.0, 1.5, 2.0];
+        let ys = [2.0, -1.0, 0.5, 4.0, 3.0];
+
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert!((newton_forward(&xs, &ys, x) - y).abs() < 1e-13);
+            assert!((newton_backward(&xs, &ys, x) - y).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_newton_matches_lagrange() {
+        let xs: Vec<f64> = (0..6).map(|i| 1.0 + 0.2 * i as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.ln()).collect();
+
+        for x in [1.05, 1.33, 1.5, 1.77, 1.99] {
+            let expected = lagrange(&xs, &ys, x);
+            assert!((newton_forward(&xs, &ys, x) - expected).abs() < 1e-13);
+            assert!((newton_backward(&xs, &ys, x) - expected).abs() < 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_lagrange_exact_for_polynomials() {
+        // Four nodes reproduce any cubic
+        let xs = [-1.0, 0.3, 2.0, 4.5];
+        let f = |x: f64| 2.0 * x.powi(3) - x + 7.0;
+        let ys: Vec<f64> = xs.iter().map(|&x| f(x)).collect();
+
+        for x in [-0.5, 1.0, 3.3] {
+            assert!((lagrange(&xs, &ys, x) - f(x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Newton difference interpolation requires equally spaced nodes")]
+    fn test_newton_non_uniform_nodes() {
+        newton_forward(&[0.0, 1.0, 3.0], &[1.0, 2.0, 3.0], 0.5);
+    }
+
+    #[test]
+    fn test_spline_interpolates_nodes() {
+        let xs = [0.0, 0.5, 1.7, 2.0, 3.5];
+        let ys = [1.0, -2.0, 0.5, 3.0, 2.0];
+        let spline = CubicSpline::natural(&xs, &ys);
+
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert!((spline.eval(x) - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_spline_reproduces_linear_data() {
+        // A straight line has zero curvature, so the natural spline is exact
+        let xs = [0.0, 1.0, 3.0, 4.0];
+        let ys: Vec<f64> = xs.iter().map(|x| 2.0 * x - 1.0).collect();
+        let spline = CubicSpline::natural(&xs, &ys);
+
+        for x in [0.25, 1.5, 2.9, 3.7] {
+            assert!((spline.eval(x) - (2.0 * x - 1.0)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_spline_approximates_smooth_function() {
+        let xs: Vec<f64> = (0..=20)
+            .map(|i| i as f64 * std::f64::consts::PI / 20.0)
+            .collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+        let spline = CubicSpline::natural(&xs, &ys);
+
+        // sin'' vanishes at 0 and π, so the natural end conditions are exact
+        for k in 0..100 {
+            let x = k as f64 * std::f64::consts::PI / 100.0;
+            assert!((spline.eval(x) - x.sin()).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_spline_two_points_is_linear() {
+        let spline = CubicSpline::natural(&[1.0, 3.0], &[2.0, 6.0]);
+        assert!((spline.eval(2.0) - 4.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_spline_integral_of_square() {
+        let xs: Vec<f64> = (0..=20).map(|i| i as f64 * 0.1).collect();
+        let ys: Vec<f64> = xs.iter().map(|x| x * x).collect();
+        let spline = CubicSpline::natural(&xs, &ys);
+
+        // ∫₀² x² dx = 8/3; the natural end condition only perturbs the ends
+        assert!((spline.integrate(0.0, 2.0) - 8.0 / 3.0).abs() < 1e-4);
+        assert!(
+            (spline.integrate(0.5, 1.5) - (1.5_f64.powi(3) - 0.5_f64.powi(3)) / 3.0).abs() < 1e-5
+        );
+        assert_eq!(spline.integrate(1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_spline_integral_agrees_with_trapezoid() {
+        use crate::methods::integration::cumulative_trapezoid_nonuniform;
+
+        let mut previous_gap = f64::INFINITY;
+        for n in [10, 40, 160] {
+            let xs: Vec<f64> = (0..=n).map(|i| i as f64 / n as f64).collect();
+            let ys: Vec<f64> = xs.iter().map(|x| x.exp()).collect();
+            let spline = CubicSpline::natural(&xs, &ys);
+
+            let trapezoid = *cumulative_trapezoid_nonuniform(&xs, &ys).last().unwrap();
+            let gap = (spline.integrate(0.0, 1.0) - trapezoid).abs();
+            assert!(gap < previous_gap);
+            previous_gap = gap;
+        }
+        assert!(previous_gap < 1e-5);
+    }
+
+    #[test]
+    fn test_spline_integral_is_additive() {
+        let xs = [0.0, 0.4, 1.1, 2.0, 2.3, 3.0];
+        let ys = [1.0, -0.5, 2.0, 0.3, 1.7, -1.0];
+        let spline = CubicSpline::natural(&xs, &ys);
+
+        let whole = spline.integrate(0.0, 3.0);
+        let split =
+            spline.integrate(0.0, 0.7) + spline.integrate(0.7, 2.15) + spline.integrate(2.15, 3.0);
+        assert!((whole - split).abs() < 1e-13);
+        assert!((spline.integrate(3.0, 0.0) + whole).abs() < 1e-15);
+
+        // Extrapolated ends use the outer segments
+        let extended = spline.integrate(-0.5, 3.5);
+        let pieces = spline.integrate(-0.5, 0.0) + whole + spline.integrate(3.0, 3.5);
+        assert!((extended - pieces).abs() < 1e-13);
+    }
+
+    #[test]
+    #[should_panic(expected = "Interpolation nodes must be strictly increasing")]
+    fn test_spline_unsorted_nodes() {
+        CubicSpline::natural(&[0.0, 2.0, 1.0], &[0.0, 1.0, 2.0]);
+    }
+}
@@ -6,7 +6,59 @@
 //! ## Available Methods
 //!
 //! ### Root Finding
-//! - [`raphson()`]: Newton-Raphson method for finding roots of equations
+//! - [`raphson()`]: Newton-Raphson method for finding roots of equations, with
+//!   [`try_raphson`] reporting failure as a [`RootError`] instead of panicking and
+//!   [`raphson_auto`](raphson::raphson_auto) estimating the derivative numerically
+//! - [`raphson_complex_analytic`](raphson::raphson_complex_analytic): Newton-Raphson
+//!   for analytic complex functions, reporting failure as a [`SolverError`]
+//! - [`bisection()`]: Bisection method for a root bracketed by a sign change,
+//!   without derivatives
+//! - [`brent()`]: Brent's method, bracketed like bisection but converging superlinearly
+//! - [`halley()`]: Halley's method, converging cubically using the second derivative
+//! - [`secant()`]: Secant method, Newton's iteration with a finite-difference slope
+//! - [`poly_roots`]: Closed-form roots of quadratics and cubics
+//! - [`roots`]: Multiplicity of a located root, to anticipate solver convergence
+//! - [`aberth`]: Aberth-Ehrlich method for all roots of a complex polynomial
+//! - [`chebfun`]: All real roots on an interval via a Chebyshev interpolant
+//! - [`bench`]: Side-by-side comparison of root finders by evaluation count
+//! - [`search`]: Bisection for the threshold where a monotone predicate flips
+//! - [`testing`]: Assertions and convergence-rate estimates for verifying solvers
+//!
+//! ### Optimization
+//! - [`optimize`]: Brent's method for the minimum of a function on an interval,
+//!   and the Nelder-Mead simplex method, gradient descent with momentum and BFGS for
+//!   functions of several variables
+//!
+//! ### Series
+//! - [`accel`]: Sums of slowly converging series by Aitken, Euler and Richardson acceleration
+//!
+//! ### Integration
+//! - [`integration`]: Composite trapezoidal and Simpson rules, Gauss-Legendre and
+//!   Gauss-Chebyshev quadrature, Romberg, adaptive Simpson and adaptive
+//!   Gauss-Kronrod integration with error estimates
+//!
+//! ### Interpolation
+//! - [`interpolation`]: Natural cubic splines, Lagrange and Newton difference interpolation
+//!
+//! ### Differentiation
+//! - [`diff`]: Finite-difference and Richardson derivatives of functions, gradients
+//!   and Hessians of multivariate functions, and derivatives of sampled data on
+//!   non-uniform grids
+//!
+//! ### Approximation
+//! - [`approx`]: Minimax polynomial approximation by the Remez exchange algorithm
+//! - [`fit`]: Weighted and unweighted least-squares polynomial fitting
+//!
+//! ### Signal Processing
+//! - [`signal`]: Window functions for spectral analysis
+//! - [`fourier`]: Fourier series coefficients of periodic functions
+//!
+//! ### Physics
+//! - [`physics`]: Exact pendulum period beyond the small-angle approximation
+//!
+//! ### Options
+//! - [`IterativeOptions`]: Tolerance and iteration limit shared by the `*_with_options`
+//!   entry points of the iterative solvers and integrators
 //!
 //! ## Usage Examples
 //!
@@ -20,14 +72,33 @@
 //! let root = raphson(1.5, f, df, 1e-12, 100);
 //! println!("Root: {:.12}", root);
 //! ```
-//!
-//! ## Future Methods
-//!
-//! Planned additions include:
-//! - Bisection method
-//! - Secant method  
-//! - Numerical integration (Simpson's rule, trapezoidal rule)
-//! - Interpolation methods (Lagrange, spline)
 
+pub mod aberth;
+pub mod accel;
+pub mod approx;
+pub mod bench;
+pub mod bisection;
+pub mod brent;
+pub mod chebfun;
+pub mod diff;
+pub mod fit;
+pub mod fourier;
+pub mod halley;
+pub mod integration;
+pub mod interpolation;
+pub mod optimize;
+pub mod options;
+pub mod physics;
+pub mod poly_roots;
 pub mod raphson;
-pub use raphson::raphson;
+pub mod roots;
+pub mod search;
+pub mod secant;
+pub mod signal;
+pub mod testing;
+pub use bisection::{RootError, bisection};
+pub use brent::brent;
+pub use halley::halley;
+pub use options::IterativeOptions;
+pub use raphson::{SolverError, raphson, raphson_with_progress, try_raphson};
+pub use secant::secant;
@@ -0,0 +1,896 @@
+//! # Minimization
+//!
+//! Derivative-free minimization of a function of one variable on a bracketing
+//! interval. Brent's method combines the guaranteed progress of golden-section
+//! search with the fast convergence of successive parabolic interpolation.
+//!
+//! ## Multivariate Minimization
+//!
+//! The Nelder-Mead simplex method needs only function values of f: ℝⁿ → ℝ. It
+//! moves a simplex of n + 1 points downhill by reflecting, expanding and
+//! contracting its worst vertex, and shrinks the whole simplex toward the best
+//! vertex when none of these improve on it.
+//!
+//! When the gradient is available, gradient descent with momentum follows it
+//! downhill, accumulating a velocity that damps the zig-zagging of plain descent
+//! across narrow valleys. The BFGS quasi-Newton method goes further, building an
+//! approximation to the inverse Hessian from successive gradients so that its
+//! steps approach Newton steps without second derivatives.
+
+use crate::base::matrix::Matrix;
+use crate::base::numbers::EPSILON;
+use crate::methods::options::IterativeOptions;
+
+/// (3 - √5)/2, the golden-section fraction of the larger of the two sub-intervals
+const GOLDEN: f64 = 0.381_966_011_250_105_1;
+
+/// √ε, the best relative accuracy attainable for a minimizer, since f is flat to
+/// first order near it
+const SQRT_EPSILON: f64 = 1.490_116_119_384_765_6e-8;
+
+/// Iteration cap; with golden-section steps as a fallback the interval shrinks
+/// by at least a constant factor every few iterations, so this is never reached
+/// for reasonable tolerances
+const MAX_ITERATIONS: usize = 500;
+
+/// Nelder-Mead reflection coefficient; this and the three below are the
+/// standard choice from the original paper
+const REFLECTION: f64 = 1.0;
+/// Nelder-Mead expansion coefficient
+const EXPANSION: f64 = 2.0;
+/// Nelder-Mead contraction coefficient
+const CONTRACTION: f64 = 0.5;
+/// Nelder-Mead shrink coefficient
+const SHRINK: f64 = 0.5;
+
+/// Sufficient-decrease constant c₁ of the Armijo condition in the BFGS line search
+const ARMIJO: f64 = 1e-4;
+
+/// Step reductions in the BFGS line search before giving up; each at least
+/// halves the step, and 2⁻⁶⁰ of a quasi-Newton step is below rounding error for
+/// any reasonable scaling
+const MAX_BACKTRACKS: usize = 60;
+
+/// Minimizes `f` on `[a, b]` with Brent's method, returning `(x_min, f_min)`.
+///
+/// Each step fits a parabola through the three best points found so far and
+/// jumps to its vertex. When the parabolic step is unreliable — it falls outside
+/// the bracket, or fails to shrink the step compared with two iterations ago —
+/// a golden-section step into the larger sub-interval is taken instead. For
+/// smooth functions convergence is superlinear, and it is never much slower
+/// than pure golden-section search.
+///
+/// ## Arguments
+///
+/// * `f` - The function to minimize. If it has several local minima in
+///   `[a, b]`, any one of them may be returned.
+/// * `a`, `b` - Interval endpoints with `a < b`.
+/// * `tol` - Absolute tolerance on the position of the minimum; must be positive.
+///   A relative tolerance of √ε is always added, since f(x) cannot resolve x
+///   any more finely near a minimum.
+///
+/// ## Returns
+///
+/// The best point found and the function value there. The point always lies
+/// strictly inside `[a, b]`; if the minimum of `f` over `[a, b]` is at an
+/// endpoint, the result approaches that endpoint to within the tolerance.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::optimize::brent_min;
+///
+/// let (x, fx) = brent_min(|x| (x - 2.0) * (x - 2.0) + 1.0, 0.0, 5.0, 1e-10);
+/// assert!((x - 2.0).abs() < 1e-7);
+/// assert!((fx - 1.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a >= b` or if `tol` is not positive.
+pub fn brent_min<F>(f: F, a: f64, b: f64, tol: f64) -> (f64, f64)
+where
+    F: Fn(f64) -> f64,
+{
+    if a >= b {
+        panic!("Minimization requires an interval with a < b");
+    }
+    if tol <= 0.0 {
+        panic!("Minimization tolerance must be positive");
+    }
+
+    let (x, fx, _) = brent(f, a, b, MAX_ITERATIONS, |_| tol);
+    (x, fx)
+}
+
+/// Brent minimization configured by [`IterativeOptions`]
+///
+/// `tol` bounds the uncertainty in the position of the minimum, relative to
+/// |x| when `relative` is set, and each iteration is one evaluation of `f`.
+/// `None` selects the default options; they converge in far fewer than 100
+/// iterations for smooth functions, matching `brent_min(f, a, b, 1e-12)`.
+///
+/// ## Returns
+///
+/// `(x_min, f_min)`, or `None` if the bracket has not shrunk to the tolerance
+/// within `max_iter` iterations.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::IterativeOptions;
+/// use sophy::methods::optimize::brent_min_with_options;
+///
+/// let (x, _) = brent_min_with_options(f64::cos, 2.0, 4.5, None).unwrap();
+/// assert!((x - std::f64::consts::PI).abs() < 1e-7);
+///
+/// let options = IterativeOptions { max_iter: 3, ..IterativeOptions::default() };
+/// assert_eq!(brent_min_with_options(f64::cos, 2.0, 4.5, Some(options)), None);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a >= b` or if the tolerance is not positive.
+pub fn brent_min_with_options<F>(
+    f: F,
+    a: f64,
+    b: f64,
+    options: Option<IterativeOptions>,
+) -> Option<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    if a >= b {
+        panic!("Minimization requires an interval with a < b");
+    }
+    let options = IterativeOptions::resolve(options);
+
+    let (x, fx, converged) = brent(f, a, b, options.max_iter, |x| options.tolerance(x));
+    converged.then_some((x, fx))
+}
+
+/// Brent's method shared by the entry points, returning `(x, f(x), converged)`
+///
+/// `tolerance(x)` is the absolute tolerance on the position of the minimum near
+/// the current best point x.
+fn brent<F, T>(f: F, a: f64, b: f64, max_iterations: usize, tolerance: T) -> (f64, f64, bool)
+where
+    F: Fn(f64) -> f64,
+    T: Fn(f64) -> f64,
+{
+    let (mut a, mut b) = (a, b);
+
+    // x: best point so far, w: second best, v: previous value of w
+    let mut x = a + GOLDEN * (b - a);
+    let (mut w, mut v) = (x, x);
+    let mut fx = f(x);
+    let (mut fw, mut fv) = (fx, fx);
+
+    // d: the latest step, e: the step before it
+    let mut d: f64 = 0.0;
+    let mut e: f64 = 0.0;
+
+    for _ in 0..max_iterations {
+        let midpoint = 0.5 * (a + b);
+        let tol1 = SQRT_EPSILON * x.abs() + tolerance(x) / 3.0;
+        let tol2 = 2.0 * tol1;
+
+        if (x - midpoint).abs() <= tol2 - 0.5 * (b - a) {
+            return (x, fx, true);
+        }
+
+        let mut golden = true;
+        if e.abs() > tol1 {
+            // Vertex of the parabola through (x, fx), (w, fw), (v, fv) is x + p/q
+            let r = (x - w) * (fx - fv);
+            let mut q = (x - v) * (fx - fw);
+            let mut p = (x - v) * q - (x - w) * r;
+            q = 2.0 * (q - r);
+            if q > 0.0 {
+                p = -p;
+            }
+            q = q.abs();
+
+            let previous = e;
+            e = d;
+
+            // Accept the parabolic step only if it lands inside the bracket and
+            // moves less than half the step before last
+            if p.abs() < (0.5 * q * previous).abs() && p > q * (a - x) && p < q * (b - x) {
+                d = p / q;
+                let u = x + d;
+                // Do not evaluate too close to the bracket ends
+                if u - a < tol2 || b - u < tol2 {
+                    d = tol1.copysign(midpoint - x);
+                }
+                golden = false;
+            }
+        }
+        if golden {
+            e = if x >= midpoint { a - x } else { b - x };
+            d = GOLDEN * e;
+        }
+
+        // Never step by less than tol1, since the values could not be told apart
+        let u = if d.abs() >= tol1 {
+            x + d
+        } else {
+            x + tol1.copysign(d)
+        };
+        let fu = f(u);
+
+        if fu <= fx {
+            if u >= x {
+                a = x;
+            } else {
+                b = x;
+            }
+            (v, fv) = (w, fw);
+            (w, fw) = (x, fx);
+            (x, fx) = (u, fu);
+        } else {
+            if u < x {
+                a = u;
+            } else {
+                b = u;
+            }
+            if fu <= fw || w == x {
+                (v, fv) = (w, fw);
+                (w, fw) = (u, fu);
+            } else if fu <= fv || v == x || v == w {
+                (v, fv) = (u, fu);
+            }
+        }
+    }
+
+    (x, fx, false)
+}
+
+/// Minimizes a function of several variables with the Nelder-Mead simplex method.
+///
+/// The initial simplex is `x0` together with the n points obtained by moving
+/// `step` along each coordinate axis. Each iteration replaces the worst vertex
+/// by its reflection through the centroid of the others, extended further if
+/// that gives a new best point, or contracted toward the centroid if the
+/// reflection is no improvement; if even the contracted point is worse, every
+/// vertex moves halfway toward the best one. No derivatives are needed, so f
+/// may be noisy or non-smooth, but convergence is slow in many dimensions and
+/// not guaranteed to reach a stationary point.
+///
+/// ## Arguments
+///
+/// * `f` - The function to minimize.
+/// * `x0` - Starting point.
+/// * `step` - Edge length of the initial simplex, roughly the distance to the
+///   minimum or the scale on which f varies.
+/// * `tol` - The iteration stops once the function values at the vertices
+///   differ by at most `tol` and every vertex lies within `tol` of the best in
+///   each coordinate.
+/// * `max_iter` - Maximum number of iterations, each evaluating f at most
+///   n + 2 times.
+///
+/// ## Returns
+///
+/// The best vertex of the final simplex, whether or not the tolerance was met.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::optimize::nelder_mead;
+///
+/// let f = |x: &[f64]| (x[0] - 1.0).powi(2) + 4.0 * (x[1] + 2.0).powi(2);
+/// let x = nelder_mead(f, &[0.0, 0.0], 1.0, 1e-12, 1000);
+/// assert!((x[0] - 1.0).abs() < 1e-6 && (x[1] + 2.0).abs() < 1e-6);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `x0` is empty, if `step` is zero or not finite, or if `tol` is not
+/// positive.
+pub fn nelder_mead<F>(f: F, x0: &[f64], step: f64, tol: f64, max_iter: usize) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    if x0.is_empty() {
+        panic!("Nelder-Mead requires at least one variable");
+    }
+    if step == 0.0 || !step.is_finite() {
+        panic!("Nelder-Mead initial step must be nonzero and finite");
+    }
+    if tol <= 0.0 {
+        panic!("Minimization tolerance must be positive");
+    }
+
+    let n = x0.len();
+    let mut simplex: Vec<(Vec<f64>, f64)> = Vec::with_capacity(n + 1);
+    simplex.push((x0.to_vec(), f(x0)));
+    for i in 0..n {
+        let mut vertex = x0.to_vec();
+        vertex[i] += step;
+        let value = f(&vertex);
+        simplex.push((vertex, value));
+    }
+
+    for _ in 0..max_iter {
+        simplex.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let (best, f_best) = &simplex[0];
+        let spread = simplex[n].1 - f_best;
+        let size = simplex[1..]
+            .iter()
+            .flat_map(|(vertex, _)| vertex.iter().zip(best).map(|(v, b)| (v - b).abs()))
+            .fold(0.0, f64::max);
+        if spread <= tol && size <= tol {
+            break;
+        }
+
+        // Centroid of every vertex but the worst
+        let mut centroid = vec![0.0; n];
+        for (vertex, _) in &simplex[..n] {
+            for (c, v) in centroid.iter_mut().zip(vertex) {
+                *c += v / n as f64;
+            }
+        }
+
+        let f_best = *f_best;
+        let f_second_worst = simplex[n - 1].1;
+        let (worst, f_worst) = simplex[n].clone();
+
+        let reflected = toward(&centroid, &worst, -REFLECTION);
+        let f_reflected = f(&reflected);
+
+        if f_reflected < f_best {
+            let expanded = toward(&centroid, &reflected, EXPANSION);
+            let f_expanded = f(&expanded);
+            simplex[n] = if f_expanded < f_reflected {
+                (expanded, f_expanded)
+            } else {
+                (reflected, f_reflected)
+            };
+            continue;
+        }
+        if f_reflected < f_second_worst {
+            simplex[n] = (reflected, f_reflected);
+            continue;
+        }
+
+        // Contract outside the simplex if the reflection improved on the worst
+        // vertex, inside otherwise
+        let (target, f_target) = if f_reflected < f_worst {
+            (&reflected, f_reflected)
+        } else {
+            (&worst, f_worst)
+        };
+        let contracted = toward(&centroid, target, CONTRACTION);
+        let f_contracted = f(&contracted);
+        if f_contracted < f_target {
+            simplex[n] = (contracted, f_contracted);
+            continue;
+        }
+
+        let best = simplex[0].0.clone();
+        for (vertex, value) in &mut simplex[1..] {
+            *vertex = toward(&best, vertex, SHRINK);
+            *value = f(vertex);
+        }
+    }
+
+    simplex
+        .into_iter()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(vertex, _)| vertex)
+        .unwrap()
+}
+
+/// Minimizes a smooth function of several variables with the BFGS quasi-Newton
+/// method.
+///
+/// Each iteration steps along pₖ = -Hₖ·∇f(xₖ), where Hₖ approximates the inverse
+/// Hessian. The step length α starts at 1 and is reduced by quadratic
+/// interpolation, which is exact along a line for quadratic f, until the Armijo
+/// condition
+///
+/// **f(xₖ + α·pₖ) ≤ f(xₖ) + c₁·α·∇f(xₖ)·pₖ**
+///
+/// holds. With sₖ = xₖ₊₁ - xₖ, yₖ = ∇f(xₖ₊₁) - ∇f(xₖ) and ρₖ = 1/(yₖ·sₖ), the
+/// approximation is updated by the BFGS formula
+///
+/// **Hₖ₊₁ = (I - ρₖ·sₖyₖᵀ)·Hₖ·(I - ρₖ·yₖsₖᵀ) + ρₖ·sₖsₖᵀ**
+///
+/// which keeps it symmetric positive definite whenever yₖ·sₖ > 0; updates
+/// violating that curvature condition are skipped. H₀ is the identity, so the
+/// first step is a steepest-descent line search. Near a minimum with positive
+/// definite Hessian convergence is superlinear.
+///
+/// ## Arguments
+///
+/// * `f` - The function to minimize.
+/// * `grad` - Its gradient ∇f, returning one component per variable;
+///   [`gradient_fd`](crate::methods::diff::gradient_fd) can supply one.
+/// * `x0` - Starting point.
+/// * `tol` - The iteration stops once the Euclidean norm of the gradient is
+///   below `tol`.
+/// * `max_iter` - Maximum number of iterations.
+///
+/// ## Returns
+///
+/// The last iterate. This may not meet the tolerance if `max_iter` is reached
+/// or if the line search can no longer decrease f, which happens when `tol` is
+/// below the rounding error of the gradient.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::optimize::bfgs;
+///
+/// // Rosenbrock's function, minimized at (1, 1)
+/// let f = |x: &[f64]| (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2);
+/// let grad = |x: &[f64]| {
+///     vec![
+///         -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0]),
+///         200.0 * (x[1] - x[0] * x[0]),
+///     ]
+/// };
+/// let x = bfgs(f, grad, &[-1.2, 1.0], 1e-10, 200);
+/// assert!((x[0] - 1.0).abs() < 1e-8 && (x[1] - 1.0).abs() < 1e-8);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `x0` is empty or if `grad` returns a vector of the wrong length.
+pub fn bfgs<F, G>(f: F, grad: G, x0: &[f64], tol: f64, max_iter: usize) -> Vec<f64>
+where
+    F: Fn(&[f64]) -> f64,
+    G: Fn(&[f64]) -> Vec<f64>,
+{
+    if x0.is_empty() {
+        panic!("BFGS requires at least one variable");
+    }
+
+    let n = x0.len();
+    let gradient = |x: &[f64]| {
+        let g = grad(x);
+        if g.len() != n {
+            panic!("Gradient must have one component per variable");
+        }
+        g
+    };
+
+    let mut x = x0.to_vec();
+    let mut fx = f(&x);
+    let mut g = gradient(&x);
+    let mut h = Matrix::identity(n);
+
+    for _ in 0..max_iter {
+        if dot(&g, &g).sqrt() < tol {
+            break;
+        }
+
+        let mut p: Vec<f64> = h.mul_vec(&g).iter().map(|v| -v).collect();
+        let mut slope = dot(&g, &p);
+        if slope >= 0.0 {
+            // Rounding has cost positive definiteness; restart from steepest descent
+            h = Matrix::identity(n);
+            p = g.iter().map(|v| -v).collect();
+            slope = -dot(&g, &g);
+        }
+
+        let mut alpha = 1.0;
+        let mut accepted = None;
+        for _ in 0..MAX_BACKTRACKS {
+            let trial: Vec<f64> = x.iter().zip(&p).map(|(xi, pi)| xi + alpha * pi).collect();
+            let f_trial = f(&trial);
+            if f_trial <= fx + ARMIJO * alpha * slope {
+                accepted = Some((trial, f_trial));
+                break;
+            }
+            // Minimizer of the parabola through f(x), its slope and f(x + α·p),
+            // kept within [α/10, α/2] to guarantee progress; max discards the
+            // NaN from an infinite trial value
+            let curvature = f_trial - fx - slope * alpha;
+            let interpolated = -0.5 * slope * alpha * alpha / curvature;
+            alpha = interpolated.max(0.1 * alpha).min(0.5 * alpha);
+        }
+        let Some((x_new, f_new)) = accepted else {
+            break;
+        };
+
+        let g_new = gradient(&x_new);
+        let s: Vec<f64> = x_new.iter().zip(&x).map(|(a, b)| a - b).collect();
+        let y: Vec<f64> = g_new.iter().zip(&g).map(|(a, b)| a - b).collect();
+        (x, fx, g) = (x_new, f_new, g_new);
+
+        let sy = dot(&s, &y);
+        if sy <= EPSILON * dot(&s, &s).sqrt() * dot(&y, &y).sqrt() {
+            continue;
+        }
+
+        // H - ρ(Hy·sᵀ + s·(Hy)ᵀ) + (ρ²·yᵀHy + ρ)·s·sᵀ, expanded from the product form
+        let rho = 1.0 / sy;
+        let hy = h.mul_vec(&y);
+        let coefficient = rho * rho * dot(&y, &hy) + rho;
+        for i in 0..n {
+            for j in 0..n {
+                h[(i, j)] += coefficient * s[i] * s[j] - rho * (hy[i] * s[j] + s[i] * hy[j]);
+            }
+        }
+    }
+
+    x
+}
+
+/// Dot product of two equally long vectors
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// The point origin + t·(target - origin)
+fn toward(origin: &[f64], target: &[f64], t: f64) -> Vec<f64> {
+    origin
+        .iter()
+        .zip(target)
+        .map(|(o, p)| o + t * (p - o))
+        .collect()
+}
+
+/// Minimizes a differentiable function by gradient descent with momentum.
+///
+/// Uses the heavy-ball iteration
+///
+/// **vₖ₊₁ = μ·vₖ - η·∇f(xₖ),  xₖ₊₁ = xₖ + vₖ₊₁**
+///
+/// with learning rate η and momentum μ, starting from rest. With `momentum`
+/// zero this is plain gradient descent. For a quadratic with Hessian
+/// eigenvalues in [m, L], plain descent needs η < 2/L and its error shrinks by
+/// about 1 - η·m per step, while momentum close to 1 can bring this near
+/// 1 - √(m/L), a large gain when the problem is ill-conditioned.
+///
+/// ## Arguments
+///
+/// * `grad` - The gradient ∇f, returning one component per variable.
+/// * `x0` - Starting point.
+/// * `learning_rate` - Step size η; must be positive.
+/// * `momentum` - Fraction μ of the previous step carried over, in [0, 1).
+/// * `tol` - The iteration stops once the Euclidean norm of the gradient is
+///   below `tol`.
+/// * `max_iter` - Maximum number of gradient evaluations.
+///
+/// ## Returns
+///
+/// The last iterate, whether or not the tolerance was met.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::optimize::gradient_descent;
+///
+/// // f(x, y) = (x - 1)² + 2(y + 3)²
+/// let grad = |x: &[f64]| vec![2.0 * (x[0] - 1.0), 4.0 * (x[1] + 3.0)];
+/// let x = gradient_descent(grad, &[0.0, 0.0], 0.1, 0.5, 1e-10, 1000);
+/// assert!((x[0] - 1.0).abs() < 1e-9 && (x[1] + 3.0).abs() < 1e-9);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `x0` is empty, if `learning_rate` is not positive, if `momentum`
+/// lies outside [0, 1), or if `grad` returns a vector of the wrong length.
+pub fn gradient_descent<G>(
+    grad: G,
+    x0: &[f64],
+    learning_rate: f64,
+    momentum: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Vec<f64>
+where
+    G: Fn(&[f64]) -> Vec<f64>,
+{
+    if x0.is_empty() {
+        panic!("Gradient descent requires at least one variable");
+    }
+    if learning_rate <= 0.0 {
+        panic!("Gradient descent learning rate must be positive");
+    }
+    if !(0.0..1.0).contains(&momentum) {
+        panic!("Gradient descent momentum must lie in [0, 1)");
+    }
+
+    let mut x = x0.to_vec();
+    let mut velocity = vec![0.0; x.len()];
+
+    for _ in 0..max_iter {
+        let g = grad(&x);
+        if g.len() != x.len() {
+            panic!("Gradient must have one component per variable");
+        }
+        if g.iter().map(|gi| gi * gi).sum::<f64>().sqrt() < tol {
+            break;
+        }
+
+        for ((xi, vi), gi) in x.iter_mut().zip(&mut velocity).zip(&g) {
+            *vi = momentum * *vi - learning_rate * gi;
+            *xi += *vi;
+        }
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_brent_min_quadratic() {
+        let (x, fx) = brent_min(|x| (x - 2.0) * (x - 2.0), -10.0, 10.0, 1e-10);
+        assert!((x - 2.0).abs() < 1e-7);
+        assert!(fx.abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_brent_min_trig_few_evaluations() {
+        let evaluations = Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x.cos()
+        };
+
+        let (x, fx) = brent_min(f, 2.0, 4.5, 1e-8);
+        assert!((x - std::f64::consts::PI).abs() < 1e-7);
+        assert!((fx + 1.0).abs() < 1e-14);
+
+        // Golden-section search alone would need about 40 evaluations
+        assert!(evaluations.get() < 15, "{} evaluations", evaluations.get());
+    }
+
+    #[test]
+    fn test_brent_min_stays_in_interval() {
+        // Decreasing on the whole interval, so the minimum is at the right end
+        let (x, fx) = brent_min(|x| -x, 0.0, 1.0, 1e-9);
+        assert!(x > 1.0 - 1e-7 && x <= 1.0);
+        assert_eq!(fx, -x);
+
+        // Increasing, so the minimum is at the left end
+        let (x, _) = brent_min(|x| x.exp(), -3.0, 2.0, 1e-9);
+        assert!((-3.0..-3.0 + 1e-7).contains(&x));
+    }
+
+    #[test]
+    fn test_brent_min_non_smooth() {
+        // A kink defeats parabolic steps; the golden-section fallback still converges
+        let (x, fx) = brent_min(|x| (x - 0.3).abs(), -1.0, 1.0, 1e-10);
+        assert!((x - 0.3).abs() < 1e-9);
+        assert!(fx < 1e-9);
+    }
+
+    #[test]
+    fn test_brent_min_with_options_defaults_match() {
+        let f = |x: f64| (x - 0.7).powi(2) + x.sin();
+        let (x, fx) = brent_min(f, -2.0, 3.0, 1e-12);
+        assert_eq!(brent_min_with_options(f, -2.0, 3.0, None), Some((x, fx)));
+    }
+
+    #[test]
+    fn test_brent_min_with_options_reports_non_convergence() {
+        // The kink forces slow golden-section steps
+        let f = |x: f64| (x - 0.3).abs();
+        let few = IterativeOptions {
+            max_iter: 10,
+            ..IterativeOptions::default()
+        };
+        assert_eq!(brent_min_with_options(f, -1.0, 1.0, Some(few)), None);
+
+        let (x, _) = brent_min_with_options(f, -1.0, 1.0, None).unwrap();
+        assert!((x - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Minimization requires an interval with a < b")]
+    fn test_brent_min_empty_interval() {
+        brent_min(|x| x * x, 1.0, 1.0, 1e-8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Minimization tolerance must be positive")]
+    fn test_brent_min_invalid_tolerance() {
+        brent_min(|x| x * x, -1.0, 1.0, 0.0);
+    }
+
+    fn rosenbrock(x: &[f64]) -> f64 {
+        (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2)
+    }
+
+    #[test]
+    fn test_nelder_mead_rosenbrock() {
+        let x = nelder_mead(rosenbrock, &[-1.2, 1.0], 0.5, 1e-12, 5000);
+        assert!((x[0] - 1.0).abs() < 1e-5, "{x:?}");
+        assert!((x[1] - 1.0).abs() < 1e-5, "{x:?}");
+    }
+
+    #[test]
+    fn test_nelder_mead_separable_quadratic() {
+        let f = |x: &[f64]| {
+            x.iter()
+                .enumerate()
+                .map(|(i, &xi)| (i + 1) as f64 * (xi - i as f64).powi(2))
+                .sum::<f64>()
+        };
+        let x = nelder_mead(f, &[5.0, 5.0, 5.0], 1.0, 1e-12, 5000);
+        for (i, xi) in x.iter().enumerate() {
+            assert!((xi - i as f64).abs() < 1e-6, "{x:?}");
+        }
+    }
+
+    #[test]
+    fn test_nelder_mead_one_dimension() {
+        let x = nelder_mead(|x| x[0].cos(), &[3.0], 0.1, 1e-12, 500);
+        assert!((x[0] - std::f64::consts::PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_nelder_mead_respects_max_iter() {
+        let evaluations = Cell::new(0);
+        let f = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            rosenbrock(x)
+        };
+
+        let x = nelder_mead(f, &[-1.2, 1.0], 0.5, 1e-12, 10);
+        // n + 1 evaluations for the initial simplex, at most n + 2 per iteration
+        assert!(
+            evaluations.get() <= 3 + 10 * 4,
+            "{} evaluations",
+            evaluations.get()
+        );
+        // Not converged, but no worse than the start
+        assert!(rosenbrock(&x) <= rosenbrock(&[-1.2, 1.0]));
+        assert!((x[0] - 1.0).abs() > 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Nelder-Mead requires at least one variable")]
+    fn test_nelder_mead_no_variables() {
+        nelder_mead(|_| 0.0, &[], 1.0, 1e-8, 10);
+    }
+
+    #[test]
+    fn test_gradient_descent_convex_quadratic() {
+        // f(x) = (x - 1)ᵀA(x - 1) with A = [[3, 1], [1, 2]]
+        let grad = |x: &[f64]| {
+            let (u, v) = (x[0] - 1.0, x[1] - 1.0);
+            vec![2.0 * (3.0 * u + v), 2.0 * (u + 2.0 * v)]
+        };
+        let x = gradient_descent(grad, &[-4.0, 6.0], 0.1, 0.0, 1e-12, 10_000);
+        assert!(
+            (x[0] - 1.0).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12,
+            "{x:?}"
+        );
+    }
+
+    #[test]
+    fn test_gradient_descent_momentum_ill_conditioned() {
+        // f(x, y) = (x² + 100y²)/2 has condition number 100
+        let evaluations = Cell::new(0);
+        let grad = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            vec![x[0], 100.0 * x[1]]
+        };
+
+        let plain = gradient_descent(grad, &[1.0, 1.0], 0.015, 0.0, 1e-8, 100_000);
+        let plain_evaluations = evaluations.replace(0);
+        let heavy = gradient_descent(grad, &[1.0, 1.0], 0.015, 0.9, 1e-8, 100_000);
+        let heavy_evaluations = evaluations.get();
+
+        for x in [&plain, &heavy] {
+            assert!(x[0].abs() < 1e-8 && x[1].abs() < 1e-10, "{x:?}");
+        }
+        assert!(
+            2 * heavy_evaluations < plain_evaluations,
+            "{heavy_evaluations} vs {plain_evaluations}"
+        );
+    }
+
+    #[test]
+    fn test_gradient_descent_respects_max_iter() {
+        let evaluations = Cell::new(0);
+        let grad = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            vec![2.0 * x[0]]
+        };
+
+        let x = gradient_descent(grad, &[1.0], 0.1, 0.0, 1e-12, 5);
+        assert_eq!(evaluations.get(), 5);
+        // Each step multiplies x by 1 - 2η
+        assert!((x[0] - 0.8_f64.powi(5)).abs() < 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "Gradient descent momentum must lie in [0, 1)")]
+    fn test_gradient_descent_invalid_momentum() {
+        gradient_descent(|x| x.to_vec(), &[1.0], 0.1, 1.0, 1e-8, 10);
+    }
+
+    fn rosenbrock_gradient(x: &[f64]) -> Vec<f64> {
+        vec![
+            -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0]),
+            200.0 * (x[1] - x[0] * x[0]),
+        ]
+    }
+
+    #[test]
+    fn test_bfgs_rosenbrock_beats_gradient_descent() {
+        let evaluations = Cell::new(0);
+        let grad = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            rosenbrock_gradient(x)
+        };
+
+        let x = bfgs(rosenbrock, grad, &[-1.2, 1.0], 1e-8, 1000);
+        let bfgs_evaluations = evaluations.replace(0);
+        assert!(
+            (x[0] - 1.0).abs() < 1e-7 && (x[1] - 1.0).abs() < 1e-7,
+            "{x:?}"
+        );
+
+        let x = gradient_descent(grad, &[-1.2, 1.0], 1e-3, 0.9, 1e-8, 100_000);
+        let descent_evaluations = evaluations.get();
+        assert!((x[0] - 1.0).abs() < 1e-5, "{x:?}");
+
+        assert!(
+            20 * bfgs_evaluations < descent_evaluations,
+            "{bfgs_evaluations} vs {descent_evaluations}"
+        );
+    }
+
+    #[test]
+    fn test_bfgs_quadratic_in_few_steps() {
+        // f(x) = Σ dᵢ(xᵢ - 1)² with curvatures spanning two orders of magnitude
+        let d = [1.0, 4.0, 20.0, 100.0];
+        let f = |x: &[f64]| (0..4).map(|i| d[i] * (x[i] - 1.0).powi(2)).sum::<f64>();
+        let evaluations = Cell::new(0);
+        let grad = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            (0..4)
+                .map(|i| 2.0 * d[i] * (x[i] - 1.0))
+                .collect::<Vec<f64>>()
+        };
+
+        let x = bfgs(f, grad, &[0.0; 4], 1e-10, 100);
+        for xi in &x {
+            assert!((xi - 1.0).abs() < 1e-10, "{x:?}");
+        }
+        // One gradient per iteration plus the initial one: about n iterations,
+        // against hundreds for plain descent at this conditioning
+        assert!(
+            evaluations.get() <= 2 * d.len() + 1,
+            "{}",
+            evaluations.get()
+        );
+    }
+
+    #[test]
+    fn test_bfgs_gradient_norm_below_tol() {
+        let f = |x: &[f64]| x[0].exp() - x[0] + (x[1] - 0.5).powi(4) + x[1] * x[1];
+        let grad = |x: &[f64]| vec![x[0].exp() - 1.0, 4.0 * (x[1] - 0.5).powi(3) + 2.0 * x[1]];
+
+        for tol in [1e-4, 1e-8, 1e-12] {
+            let x = bfgs(f, grad, &[2.0, -3.0], tol, 200);
+            let g = grad(&x);
+            assert!((g[0] * g[0] + g[1] * g[1]).sqrt() < tol, "{tol:e}: {g:?}");
+        }
+    }
+
+    #[test]
+    fn test_bfgs_respects_max_iter() {
+        let evaluations = Cell::new(0);
+        let grad = |x: &[f64]| {
+            evaluations.set(evaluations.get() + 1);
+            rosenbrock_gradient(x)
+        };
+
+        let x = bfgs(rosenbrock, grad, &[-1.2, 1.0], 1e-12, 3);
+        assert_eq!(evaluations.get(), 4);
+        assert!(rosenbrock(&x) < rosenbrock(&[-1.2, 1.0]));
+    }
+}
@@ -0,0 +1,154 @@
+//! # Iteration Options
+//!
+//! [`IterativeOptions`] bundles the tolerance and iteration limit shared by the
+//! iterative solvers and integrators. Their `*_with_options` entry points take
+//! an `Option<IterativeOptions>`, where `None` selects the defaults, and report
+//! non-convergence as `None` instead of returning an unconverged estimate.
+//!
+//! - [`raphson_with_options`](crate::methods::raphson::raphson_with_options)
+//! - [`brent_min_with_options`](crate::methods::optimize::brent_min_with_options)
+//! - [`romberg_with_options`](crate::methods::integration::romberg_with_options)
+//! - [`qag_with_options`](crate::methods::integration::qag_with_options)
+
+/// Tolerance and iteration limit for an iterative method
+///
+/// What counts as an iteration depends on the method: a Newton step, a Brent
+/// step, a Romberg level, or a subinterval of adaptive quadrature.
+///
+/// # Examples
+///
+/// ```rust
+/// use sophy::methods::IterativeOptions;
+/// use sophy::methods::raphson::raphson_with_options;
+///
+/// let options = IterativeOptions {
+///     max_iter: 3,
+///     ..IterativeOptions::default()
+/// };
+///
+/// // x²⁰ = 1 from x = 3 converges slowly, far beyond three steps
+/// let f = |x: f64| x.powi(20) - 1.0;
+/// let df = |x: f64| 20.0 * x.powi(19);
+/// assert_eq!(raphson_with_options(3.0, f, df, Some(options)), None);
+/// assert!(raphson_with_options(3.0, f, df, None).is_some());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterativeOptions {
+    /// Convergence tolerance; must be positive
+    pub tol: f64,
+    /// Maximum number of iterations
+    pub max_iter: usize,
+    /// Whether `tol` is relative to the magnitude of the current estimate
+    /// rather than absolute; below magnitude 1 it acts as an absolute
+    /// tolerance, so that estimates converging to zero can still converge
+    pub relative: bool,
+}
+
+impl Default for IterativeOptions {
+    /// An absolute tolerance of 10⁻¹² and at most 100 iterations
+    fn default() -> Self {
+        IterativeOptions {
+            tol: 1e-12,
+            max_iter: 100,
+            relative: false,
+        }
+    }
+}
+
+impl IterativeOptions {
+    /// The absolute tolerance for an estimate of magnitude `scale`: `tol` itself,
+    /// or `tol·max(|scale|, 1)` when `relative` is set
+    ///
+    /// The floor of `tol` in relative mode keeps the tolerance positive when the
+    /// estimate is zero, where a purely relative test could never be met.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sophy::methods::IterativeOptions;
+    ///
+    /// let absolute = IterativeOptions::default();
+    /// assert_eq!(absolute.tolerance(1e6), 1e-12);
+    ///
+    /// let relative = IterativeOptions { relative: true, ..absolute };
+    /// assert_eq!(relative.tolerance(-1e6), 1e-6);
+    /// assert_eq!(relative.tolerance(0.0), 1e-12);
+    /// ```
+    pub fn tolerance(&self, scale: f64) -> f64 {
+        if self.relative {
+            self.tol * scale.abs().max(1.0)
+        } else {
+            self.tol
+        }
+    }
+
+    /// Resolves `None` to the defaults and validates the tolerance
+    pub(crate) fn resolve(options: Option<Self>) -> Self {
+        let options = options.unwrap_or_default();
+        if options.tol <= 0.0 {
+            panic!("Iteration tolerance must be positive");
+        }
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_options() {
+        let options = IterativeOptions::resolve(None);
+        assert_eq!(options, IterativeOptions::default());
+        assert_eq!(options.tol, 1e-12);
+        assert_eq!(options.max_iter, 100);
+        assert!(!options.relative);
+    }
+
+    #[test]
+    fn test_relative_tolerance_scales() {
+        let options = IterativeOptions {
+            tol: 1e-8,
+            relative: true,
+            ..IterativeOptions::default()
+        };
+        assert_eq!(options.tolerance(-200.0), 2e-6);
+        // Absolute below magnitude 1, so that zero can be reached
+        assert_eq!(options.tolerance(0.0), 1e-8);
+        assert_eq!(options.tolerance(0.25), 1e-8);
+    }
+
+    #[test]
+    fn test_relative_tolerance_converges_to_zero() {
+        use crate::methods::integration::qag_with_options;
+        use crate::methods::optimize::brent_min_with_options;
+        use crate::methods::raphson::raphson_with_options;
+
+        let relative = Some(IterativeOptions {
+            relative: true,
+            ..IterativeOptions::default()
+        });
+
+        assert_eq!(
+            raphson_with_options(1.0, |x| x, |_| 1.0, relative),
+            Some(0.0)
+        );
+        let root = raphson_with_options(0.5, f64::sin, f64::cos, relative).unwrap();
+        assert!(root.abs() < 1e-12);
+
+        let (x, _) = brent_min_with_options(|x| x * x, -1.0, 2.0, relative).unwrap();
+        assert!(x.abs() < 1e-7);
+
+        let (value, _) = qag_with_options(f64::sin, -1.0, 1.0, relative).unwrap();
+        assert!(value.abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Iteration tolerance must be positive")]
+    fn test_non_positive_tolerance() {
+        IterativeOptions::resolve(Some(IterativeOptions {
+            tol: 0.0,
+            ..IterativeOptions::default()
+        }));
+    }
+}
@@ -0,0 +1,305 @@
+//! # Closed-Form Polynomial Roots
+//!
+//! Exact formulas for the roots of quadratics and cubics with real coefficients,
+//! arranged to avoid the cancellation that makes the textbook formulas
+//! inaccurate. For higher degrees, use the iterative [`aberth`](super::aberth)
+//! solver.
+//!
+//! Roots are returned as [`Complex64`] values, with multiple roots repeated.
+//! Real roots come first in ascending order, followed by any complex-conjugate
+//! pair with the negative imaginary part first.
+
+use crate::base::complex::Complex64;
+use crate::base::numbers::PI;
+
+/// Roots of the quadratic ax² + bx + c
+///
+/// ## Implementation
+///
+/// The textbook formula (-b ± √(b² - 4ac)) / 2a subtracts nearly equal numbers
+/// for the smaller root when b² ≫ |4ac|. Instead the root of larger magnitude is
+/// computed first, with the sign of the square root chosen to match b,
+///
+/// ```text
+/// q = -(b + sign(b)·√(b² - 4ac)) / 2,   r₁ = q / a,   r₂ = c / q = c / (a·r₁)
+/// ```
+///
+/// and the other from Vieta's formula r₁r₂ = c/a. A negative discriminant gives
+/// the conjugate pair -b/2a ± i·√(4ac - b²)/2|a|.
+///
+/// The coefficients are first divided by the power of two nearest below the
+/// largest of them. This leaves the roots and their rounding unchanged, but keeps
+/// b² - 4ac from overflowing when a coefficient exceeds about 10¹⁵⁴.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::methods::poly_roots::solve_quadratic;
+///
+/// // x² - 5x + 6 = (x - 2)(x - 3)
+/// let roots = solve_quadratic(1.0, -5.0, 6.0);
+/// assert_eq!(roots, vec![Complex64::new(2.0, 0.0), Complex64::new(3.0, 0.0)]);
+///
+/// // x² + 1 has the roots ±i
+/// let roots = solve_quadratic(1.0, 0.0, 1.0);
+/// assert_eq!(roots, vec![Complex64::new(0.0, -1.0), Complex64::new(0.0, 1.0)]);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a` is zero.
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<Complex64> {
+    if a == 0.0 {
+        panic!("Polynomial leading coefficient must be nonzero");
+    }
+
+    // Scaling by a power of two is exact, so only the discriminant's range changes
+    let largest = a.abs().max(b.abs()).max(c.abs());
+    let (a, b, c) = if largest.is_finite() {
+        let scale = 2.0_f64.powi(largest.log2().floor().clamp(-1022.0, 1023.0) as i32);
+        (a / scale, b / scale, c / scale)
+    } else {
+        (a, b, c)
+    };
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        let re = -b / (2.0 * a);
+        let im = (-discriminant).sqrt() / (2.0 * a.abs());
+        return vec![Complex64::new(re, -im), Complex64::new(re, im)];
+    }
+
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    let (r1, r2) = if q == 0.0 {
+        // b = 0 and c = 0: a double root at zero
+        (0.0, 0.0)
+    } else {
+        (q / a, c / q)
+    };
+
+    vec![
+        Complex64::new(r1.min(r2), 0.0),
+        Complex64::new(r1.max(r2), 0.0),
+    ]
+}
+
+/// Roots of the cubic ax³ + bx² + cx + d
+///
+/// ## Implementation
+///
+/// The substitution x = t - b/3a gives the depressed cubic t³ + pt + q, whose
+/// discriminant Δ = (q/2)² + (p/3)³ decides the method:
+///
+/// - Δ < 0: three distinct real roots, by Viète's trigonometric formula
+///   t = 2√(-p/3)·cos(θ/3 - 2πk/3), which avoids the complex arithmetic of
+///   Cardano's formula in this case
+/// - Δ ≥ 0: Cardano's formula t = u + v with u = ∛(-q/2 - sign(q)√Δ) and
+///   v = -p/3u, the sign chosen so that u does not suffer cancellation. The
+///   complex pair is -(u + v)/2 ± i·(√3/2)(u - v)
+///
+/// Each real root is finally polished with one Newton step on the original
+/// cubic.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::poly_roots::solve_cubic;
+///
+/// // (x - 1)(x - 2)(x - 3) = x³ - 6x² + 11x - 6
+/// let roots = solve_cubic(1.0, -6.0, 11.0, -6.0);
+/// for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+///     assert!((root.re - expected).abs() < 1e-14);
+///     assert_eq!(root.im, 0.0);
+/// }
+///
+/// // x³ - 1 has the real root 1 and the complex cube roots of unity
+/// let roots = solve_cubic(1.0, 0.0, 0.0, -1.0);
+/// assert!((roots[0].re - 1.0).abs() < 1e-15);
+/// assert!((roots[2].re + 0.5).abs() < 1e-15);
+/// assert!((roots[2].im - 3.0_f64.sqrt() / 2.0).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a` is zero.
+pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<Complex64> {
+    if a == 0.0 {
+        panic!("Polynomial leading coefficient must be nonzero");
+    }
+
+    // Monic form x³ + bx² + cx + d and its depressed form t³ + pt + q
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = b / 3.0;
+    let p = c - b * shift;
+    let q = d + shift * (2.0 * shift * shift - c);
+
+    let half_q = 0.5 * q;
+    let third_p = p / 3.0;
+    let discriminant = half_q * half_q + third_p * third_p * third_p;
+
+    let polish = |x: f64| {
+        let value = ((x + b) * x + c) * x + d;
+        let slope = (3.0 * x + 2.0 * b) * x + c;
+        if slope != 0.0 { x - value / slope } else { x }
+    };
+
+    if discriminant < 0.0 {
+        // p < 0 here, so the square roots are real
+        let radius = (-third_p).sqrt();
+        let cos_theta = (-half_q / (radius * radius * radius)).clamp(-1.0, 1.0);
+        let theta = cos_theta.acos();
+
+        let mut roots: Vec<f64> = (0..3)
+            .map(|k| polish(2.0 * radius * ((theta - 2.0 * PI * k as f64) / 3.0).cos() - shift))
+            .collect();
+        roots.sort_by(f64::total_cmp);
+        return roots.into_iter().map(|r| Complex64::new(r, 0.0)).collect();
+    }
+
+    let sign = if q < 0.0 { -1.0 } else { 1.0 };
+    let u = (-half_q - sign * discriminant.sqrt()).cbrt();
+    let v = if u == 0.0 { 0.0 } else { -third_p / u };
+
+    let real = polish(u + v - shift);
+    let re = -0.5 * (u + v) - shift;
+    let im = 0.5 * 3.0_f64.sqrt() * (u - v).abs();
+
+    if im == 0.0 {
+        // Δ = 0: a double root alongside the simple one
+        let double = polish(re);
+        let mut roots = [real, double, double];
+        roots.sort_by(f64::total_cmp);
+        return roots.into_iter().map(|r| Complex64::new(r, 0.0)).collect();
+    }
+
+    vec![
+        Complex64::new(real, 0.0),
+        Complex64::new(re, -im),
+        Complex64::new(re, im),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn real_parts(roots: &[Complex64]) -> Vec<f64> {
+        assert!(roots.iter().all(|r| r.im == 0.0), "{roots:?}");
+        roots.iter().map(|r| r.re).collect()
+    }
+
+    #[test]
+    fn test_quadratic_real_roots() {
+        assert_eq!(real_parts(&solve_quadratic(1.0, -5.0, 6.0)), vec![2.0, 3.0]);
+        assert_eq!(
+            real_parts(&solve_quadratic(-2.0, 0.0, 8.0)),
+            vec![-2.0, 2.0]
+        );
+        assert_eq!(
+            real_parts(&solve_quadratic(1.0, 2.0, 1.0)),
+            vec![-1.0, -1.0]
+        );
+        assert_eq!(real_parts(&solve_quadratic(3.0, 0.0, 0.0)), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_quadratic_complex_roots() {
+        let roots = solve_quadratic(1.0, 0.0, 1.0);
+        assert_eq!(
+            roots,
+            vec![Complex64::new(0.0, -1.0), Complex64::new(0.0, 1.0)]
+        );
+
+        // 2x² + 4x + 10 = 0 has roots -1 ± 2i
+        let roots = solve_quadratic(2.0, 4.0, 10.0);
+        assert_eq!(
+            roots,
+            vec![Complex64::new(-1.0, -2.0), Complex64::new(-1.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn test_quadratic_avoids_cancellation() {
+        // x² - 10⁸x + 1 has roots ≈ 10⁸ and 10⁻⁸ (1 + 10⁻¹⁶)
+        let roots = real_parts(&solve_quadratic(1.0, -1e8, 1.0));
+        assert!((roots[0] - 1e-8).abs() < 1e-22);
+        assert_eq!(roots[1], 1e8);
+
+        // The textbook formula loses most digits of the small root
+        let naive = (1e8 - (1e16_f64 - 4.0).sqrt()) / 2.0;
+        assert!((naive - 1e-8).abs() > 1e-10);
+    }
+
+    #[test]
+    fn test_quadratic_huge_coefficients() {
+        // b² overflows f64 for all of these without scaling
+        let roots = real_parts(&solve_quadratic(1e200, -5e200, 6e200));
+        assert!((roots[0] - 2.0).abs() < 1e-14 && (roots[1] - 3.0).abs() < 1e-14);
+
+        let roots = real_parts(&solve_quadratic(1.0, -1e200, 1.0));
+        assert!((roots[0] - 1e-200).abs() < 1e-215);
+        assert_eq!(roots[1], 1e200);
+
+        let roots = solve_quadratic(1e300, 2e300, 5e300);
+        assert!((roots[1] - Complex64::new(-1.0, 2.0)).norm() < 1e-14);
+
+        // Powers of two keep exact roots exact, and tiny coefficients do not underflow
+        let huge = 2.0_f64.powi(600);
+        assert_eq!(
+            real_parts(&solve_quadratic(huge, -5.0 * huge, 6.0 * huge)),
+            vec![2.0, 3.0]
+        );
+        let tiny = 2.0_f64.powi(-600);
+        assert_eq!(
+            real_parts(&solve_quadratic(tiny, -5.0 * tiny, 6.0 * tiny)),
+            vec![2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_cubic_three_real_roots() {
+        // 2(x + 1)(x - 0.5)(x - 4) = 2x³ - 7x² - 5x + 4
+        let roots = real_parts(&solve_cubic(2.0, -7.0, -5.0, 4.0));
+        for (root, expected) in roots.iter().zip([-1.0, 0.5, 4.0]) {
+            assert!((root - expected).abs() < 1e-14, "{roots:?}");
+        }
+
+        // Roots clustered close together
+        let roots = real_parts(&solve_cubic(1.0, -3.0, 2.9999, -0.9999));
+        for (root, expected) in roots.iter().zip([0.99, 1.0, 1.01]) {
+            assert!((root - expected).abs() < 1e-9, "{roots:?}");
+        }
+    }
+
+    #[test]
+    fn test_cubic_repeated_roots() {
+        // (x - 1)²(x + 2) = x³ - 3x + 2
+        let roots = real_parts(&solve_cubic(1.0, 0.0, -3.0, 2.0));
+        assert_eq!(roots, vec![-2.0, 1.0, 1.0]);
+
+        // x³ has a triple root at zero
+        assert_eq!(real_parts(&solve_cubic(5.0, 0.0, 0.0, 0.0)), vec![0.0; 3]);
+    }
+
+    #[test]
+    fn test_cubic_complex_pair() {
+        // (x - 2)(x² + 2x + 5) = x³ + x - 10, roots 2 and -1 ± 2i
+        let roots = solve_cubic(1.0, 0.0, 1.0, -10.0);
+        let expected = [
+            Complex64::new(2.0, 0.0),
+            Complex64::new(-1.0, -2.0),
+            Complex64::new(-1.0, 2.0),
+        ];
+        for (root, expected) in roots.iter().zip(expected) {
+            assert!((*root - expected).norm() < 1e-14, "{roots:?}");
+        }
+        assert_eq!(roots[0].im, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Polynomial leading coefficient must be nonzero")]
+    fn test_zero_leading_coefficient() {
+        solve_cubic(0.0, 1.0, 2.0, 3.0);
+    }
+}
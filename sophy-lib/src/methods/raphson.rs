@@ -16,6 +16,86 @@
 //!
 //! Given a function f(x), we want to find x such that f(x) = 0. Starting with an initial
 //! guess x₀, we iteratively apply the Newton-Raphson formula until convergence.
+//!
+//! [`raphson_complex_analytic`] applies the same iteration to analytic functions of
+//! a complex variable, reporting failure as a [`SolverError`] instead of panicking.
+
+use crate::base::complex::Complex64;
+use crate::base::numbers::EPSILON;
+use crate::methods::bisection::RootError;
+use crate::methods::diff::central_difference;
+use crate::methods::options::IterativeOptions;
+use std::fmt;
+
+/// The reason an iterative solver failed to produce a root
+///
+/// Each variant records where the iteration stopped, and `Display` combines that
+/// diagnostic with the remediation from [`SolverError::hint`].
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::methods::raphson::SolverError;
+///
+/// let error = SolverError::DerivativeTooSmall { at: Complex64::new(1.25, 0.0), tol: 1e-12 };
+/// assert_eq!(
+///     error.to_string(),
+///     "derivative too small (|f'(x)| < 1e-12) at x = 1.25 — try a different initial guess or use bisection"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SolverError {
+    /// The derivative became too small to take a Newton step
+    DerivativeTooSmall {
+        /// The iterate at which the derivative fell below the tolerance
+        at: Complex64,
+        /// The tolerance the derivative fell below
+        tol: f64,
+    },
+    /// The iteration did not converge within the iteration limit
+    NoConvergence {
+        /// The number of iterations performed
+        iterations: usize,
+        /// The size of the last step, still at least the tolerance
+        step: f64,
+    },
+}
+
+impl SolverError {
+    /// A suggestion for how to avoid the failure
+    pub fn hint(&self) -> &'static str {
+        match self {
+            SolverError::DerivativeTooSmall { .. } => {
+                "try a different initial guess or use bisection"
+            }
+            SolverError::NoConvergence { .. } => {
+                "raise the iteration limit, loosen the tolerance or start closer to the root"
+            }
+        }
+    }
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::DerivativeTooSmall { at, tol } => {
+                write!(f, "derivative too small (|f'(x)| < {tol:e}) at x = ")?;
+                // Real solvers report their iterate without the zero imaginary part
+                if at.im == 0.0 {
+                    write!(f, "{}", at.re)?;
+                } else {
+                    write!(f, "{at}")?;
+                }
+            }
+            SolverError::NoConvergence { iterations, step } => write!(
+                f,
+                "iteration did not converge within {iterations} iterations (last step {step:e})"
+            )?,
+        }
+        write!(f, " — {}", self.hint())
+    }
+}
+
+impl std::error::Error for SolverError {}
 
 /// Newton-Raphson root-finding method.
 ///
@@ -41,12 +121,13 @@
 /// ## Returns
 ///
 /// Returns a `f64` representing the approximated root of the equation `f(x) = 0`.
+/// If the iteration has not converged after `max_iter` steps, the last iterate is
+/// returned; NaN from `f` or `df` propagates to the result. Use [`try_raphson`] to
+/// detect these cases.
 ///
 /// ## Panics
 ///
-/// This function will panic if:
-/// * The derivative is too close to zero (`|f'(x)| < tol`)
-/// * Maximum iterations are reached without convergence
+/// This function will panic if the derivative is too close to zero (`|f'(x)| < tol`).
 ///
 /// ## Examples
 ///
@@ -101,27 +182,659 @@
 /// - The root is simple (multiplicity 1)
 ///
 /// For functions with multiple roots, different initial guesses may converge to different roots.
-pub fn raphson<F, DF>(mut x: f64, f: F, df: DF, tol: f64, max_iter: usize) -> f64
+pub fn raphson<F, DF>(x: f64, f: F, df: DF, tol: f64, max_iter: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+{
+    raphson_with_progress(x, f, df, tol, max_iter, |_, _| {})
+}
+
+/// Newton-Raphson root finding with a numerically estimated derivative
+///
+/// The same iteration as [`raphson`], with f'(x) replaced by the
+/// [`central_difference`] at step ε^(1/3)·max(1, |x|), which balances truncation
+/// and rounding error to about ε^(2/3) relative accuracy. Convergence is still
+/// quadratic until the step approaches that accuracy, at the cost of two extra
+/// evaluations of `f` per iteration.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::raphson::raphson_auto;
+///
+/// // cos x = x, without differentiating by hand
+/// let root = raphson_auto(1.0, |x: f64| x.cos() - x, 1e-12, 100);
+/// assert!((root.cos() - root).abs() < 1e-12);
+/// ```
+///
+/// ## Panics
+///
+/// Panics under the same conditions as [`raphson`].
+pub fn raphson_auto<F>(x: f64, f: F, tol: f64, max_iter: usize) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    let step = EPSILON.cbrt();
+    let df = |x: f64| central_difference(&f, x, step * x.abs().max(1.0));
+    raphson(x, &f, df, tol, max_iter)
+}
+
+/// Newton-Raphson root finding that reports failure instead of panicking
+///
+/// The same iteration as [`raphson`], for use where a panic is unacceptable.
+///
+/// ## Returns
+///
+/// The root, or
+/// - [`RootError::DerivativeTooSmall`] if |f'(x)| < `tol` at some iterate
+/// - [`RootError::MaxIterationsReached`] with the last iterate if the step is still at
+///   least `tol` after `max_iter` iterations
+/// - [`RootError::NanEncountered`] if `f` or `df` returns NaN or an iterate
+///   overflows
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::bisection::RootError;
+/// use sophy::methods::raphson::try_raphson;
+///
+/// let root = try_raphson(1.0, |x| x * x - 2.0, |x| 2.0 * x, 1e-12, 100);
+/// assert!((root.unwrap() - std::f64::consts::SQRT_2).abs() < 1e-12);
+///
+/// // x³ has a flat derivative at its root
+/// let error = try_raphson(0.0, |x| x.powi(3), |x| 3.0 * x * x, 1e-12, 100);
+/// assert_eq!(error, Err(RootError::DerivativeTooSmall { x: 0.0 }));
+/// ```
+pub fn try_raphson<F, DF>(x: f64, f: F, df: DF, tol: f64, max_iter: usize) -> Result<f64, RootError>
 where
     F: Fn(f64) -> f64,
     DF: Fn(f64) -> f64,
 {
+    try_raphson_counted(x, &f, &df, tol, max_iter).0
+}
+
+/// [`try_raphson`], also returning the number of Newton steps taken
+pub(crate) fn try_raphson_counted<F, DF>(
+    mut x: f64,
+    f: &F,
+    df: &DF,
+    tol: f64,
+    max_iter: usize,
+) -> (Result<f64, RootError>, usize)
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+{
+    let mut steps = 0;
+    let result = newton(&mut x, f, df, max_iter, |_| tol, |step, _| steps = step);
+    (result.map(|()| x), steps)
+}
+
+/// Newton-Raphson root finding with a progress callback.
+///
+/// Identical to [`raphson`], but calls `on_iter(step, estimate)` after every
+/// iteration with the 1-based step number and the new estimate. The callback can
+/// log progress, update a progress bar, or abort a long computation by panicking.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::raphson::raphson_with_progress;
+///
+/// let mut history = Vec::new();
+/// let root = raphson_with_progress(
+///     1.0,
+///     |x: f64| x * x - 2.0,
+///     |x: f64| 2.0 * x,
+///     1e-12,
+///     100,
+///     |step, estimate| history.push((step, estimate)),
+/// );
+///
+/// assert_eq!(history.last(), Some(&(history.len(), root)));
+/// ```
+///
+/// ## Panics
+///
+/// Panics under the same conditions as [`raphson`].
+pub fn raphson_with_progress<F, DF, P>(
+    mut x: f64,
+    f: F,
+    df: DF,
+    tol: f64,
+    max_iter: usize,
+    mut on_iter: P,
+) -> f64
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+    P: FnMut(usize, f64),
+{
+    let result = newton(&mut x, f, df, max_iter, |_| tol, &mut on_iter);
+    root_or_panic(result, x)
+}
+
+/// Newton-Raphson root finding configured by [`IterativeOptions`]
+///
+/// Iterates until the step |x_{n+1} - x_n| drops below the tolerance, which is
+/// relative to |x_{n+1}| when `relative` is set. `None` selects the default
+/// options, which match `raphson(x, f, df, 1e-12, 100)`.
+///
+/// ## Returns
+///
+/// The root, or `None` if it has not converged within `max_iter` steps, the
+/// derivative became too small (`|f'(x)| < tol`) or NaN was encountered.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::IterativeOptions;
+/// use sophy::methods::raphson::raphson_with_options;
+///
+/// let f = |x: f64| x * x - 2.0;
+/// let df = |x: f64| 2.0 * x;
+///
+/// let root = raphson_with_options(1.0, f, df, None).unwrap();
+/// assert!((root - std::f64::consts::SQRT_2).abs() < 1e-12);
+///
+/// // Root near 10⁸, where an absolute tolerance of 10⁻¹² is below one ULP
+/// let options = IterativeOptions { relative: true, ..IterativeOptions::default() };
+/// let g = |x: f64| x - 1e8;
+/// assert_eq!(raphson_with_options(3.0, g, |_| 1.0, Some(options)), Some(1e8));
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the tolerance is not positive.
+pub fn raphson_with_options<F, DF>(
+    mut x: f64,
+    f: F,
+    df: DF,
+    options: Option<IterativeOptions>,
+) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+{
+    let options = IterativeOptions::resolve(options);
+    let result = newton(
+        &mut x,
+        f,
+        df,
+        options.max_iter,
+        |estimate| options.tolerance(estimate),
+        |_, _| {},
+    );
+    result.ok().map(|()| x)
+}
+
+/// Newton-Raphson root finding for each value of a parameter, by continuation
+///
+/// Solves f(x, p) = 0 for every p in `params` in turn. The first solve starts at
+/// `x0` and every later one at the root for the previous parameter, so when the
+/// root moves smoothly with p and the parameters are closely spaced, each solve
+/// starts close to its root and needs only a few iterations.
+///
+/// ## Arguments
+///
+/// * `params` - Parameter values, ideally ordered so that neighbours are close.
+/// * `x0` - Initial guess for the first parameter.
+/// * `f` - The function f(x, p) whose root in x is sought.
+/// * `df` - Its derivative ∂f/∂x(x, p).
+/// * `tol`, `max_iter` - As for [`raphson`], applied to every solve.
+///
+/// ## Returns
+///
+/// The roots, one per parameter, in the order of `params`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::raphson::raphson_sweep;
+///
+/// // √a as the root of x² - a
+/// let params = [1.0, 2.0, 3.0, 4.0];
+/// let roots = raphson_sweep(&params, 1.0, |x, a| x * x - a, |x, _| 2.0 * x, 1e-12, 50);
+/// for (root, a) in roots.iter().zip(params) {
+///     assert!((root - a.sqrt()).abs() < 1e-12);
+/// }
+/// ```
+///
+/// ## Panics
+///
+/// Panics under the same conditions as [`raphson`].
+pub fn raphson_sweep<F, DF>(
+    params: &[f64],
+    x0: f64,
+    f: F,
+    df: DF,
+    tol: f64,
+    max_iter: usize,
+) -> Vec<f64>
+where
+    F: Fn(f64, f64) -> f64,
+    DF: Fn(f64, f64) -> f64,
+{
+    let mut x = x0;
+    params
+        .iter()
+        .map(|&p| {
+            let result = newton(
+                &mut x,
+                |x| f(x, p),
+                |x| df(x, p),
+                max_iter,
+                |_| tol,
+                |_, _| {},
+            );
+            root_or_panic(result, x)
+        })
+        .collect()
+}
+
+/// Newton-Raphson root finding for analytic functions of a complex variable
+///
+/// Iterates z_{n+1} = z_n - f(z_n) / f'(z_n) with the complex derivative `df`,
+/// until the step |z_{n+1} - z_n|, measured by the complex modulus, drops below
+/// `tol`. Convergence is quadratic near a simple root, and a complex starting
+/// point can reach roots that no real iteration reaches.
+///
+/// ## Returns
+///
+/// The root, or
+/// - [`SolverError::DerivativeTooSmall`] if |f'(z)| < `tol` at some iterate
+/// - [`SolverError::NoConvergence`] if the step is still at least `tol` after
+///   `max_iter` iterations
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::methods::raphson::{SolverError, raphson_complex_analytic};
+///
+/// // z² + 1 has no real roots
+/// let f = |z: Complex64| z * z + Complex64::new(1.0, 0.0);
+/// let df = |z: Complex64| Complex64::new(2.0, 0.0) * z;
+///
+/// let root = raphson_complex_analytic(Complex64::new(0.3, 0.8), f, df, 1e-14, 50).unwrap();
+/// assert!((root - Complex64::new(0.0, 1.0)).norm() < 1e-14);
+///
+/// // f'(0) = 0
+/// let error = raphson_complex_analytic(Complex64::new(0.0, 0.0), f, df, 1e-14, 50);
+/// assert_eq!(error, Err(SolverError::DerivativeTooSmall { at: Complex64::ZERO, tol: 1e-14 }));
+/// ```
+pub fn raphson_complex_analytic<F, DF>(
+    mut z: Complex64,
+    f: F,
+    df: DF,
+    tol: f64,
+    max_iter: usize,
+) -> Result<Complex64, SolverError>
+where
+    F: Fn(Complex64) -> Complex64,
+    DF: Fn(Complex64) -> Complex64,
+{
+    let mut step = f64::INFINITY;
     for _ in 0..max_iter {
-        let y = f(x);
-        let y_prime = df(x);
+        let slope = df(z);
+        if slope.norm() < tol {
+            return Err(SolverError::DerivativeTooSmall { at: z, tol });
+        }
+
+        let z_new = z - f(z) / slope;
+        step = (z_new - z).norm();
+        z = z_new;
+        if step < tol {
+            return Ok(z);
+        }
+    }
+
+    Err(SolverError::NoConvergence {
+        iterations: max_iter,
+        step,
+    })
+}
+
+/// Newton iteration shared by the entry points, updating `x` in place
+///
+/// `tolerance(x_new)` gives the step size below which the iteration has
+/// converged; its value at x_new = 1 also bounds the derivative from below.
+/// On failure `x` holds the last iterate reached.
+fn newton<F, DF, T, P>(
+    x: &mut f64,
+    f: F,
+    df: DF,
+    max_iter: usize,
+    tolerance: T,
+    mut on_iter: P,
+) -> Result<(), RootError>
+where
+    F: Fn(f64) -> f64,
+    DF: Fn(f64) -> f64,
+    T: Fn(f64) -> f64,
+    P: FnMut(usize, f64),
+{
+    let derivative_floor = tolerance(1.0);
+
+    for step in 1..=max_iter {
+        let y = f(*x);
+        let y_prime = df(*x);
+
+        if y.is_nan() || y_prime.is_nan() {
+            return Err(RootError::NanEncountered);
+        }
+        if y_prime.abs() < derivative_floor {
+            return Err(RootError::DerivativeTooSmall { x: *x });
+        }
+
+        let x_new = *x - y / y_prime;
+        on_iter(step, x_new);
+
+        let step_size = (x_new - *x).abs();
+        *x = x_new;
+        if !x_new.is_finite() {
+            return Err(RootError::NanEncountered);
+        }
+        if step_size < tolerance(x_new) {
+            return Ok(());
+        }
+    }
+
+    Err(RootError::MaxIterationsReached {
+        last: *x,
+        iterations: max_iter,
+    })
+}
+
+/// The result of the panicking entry points: a vanishing derivative panics, NaN
+/// propagates and an unconverged iteration returns its last iterate
+fn root_or_panic(result: Result<(), RootError>, x: f64) -> f64 {
+    match result {
+        Err(RootError::DerivativeTooSmall { .. }) => panic!("Derivative too small"),
+        Err(RootError::NanEncountered) => f64::NAN,
+        _ => x,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raphson_auto_matches_raphson() {
+        let f = |x: f64| x.powi(3) - x - 1.0;
+        let exact = raphson(1.5, f, |x| 3.0 * x * x - 1.0, 1e-12, 100);
+        let auto = raphson_auto(1.5, f, 1e-12, 100);
+        assert!((auto - exact).abs() < 1e-14);
+
+        let root = raphson_auto(3.0, |x: f64| x.ln() - 1.0, 1e-12, 100);
+        assert!((root - std::f64::consts::E).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Derivative too small")]
+    fn test_raphson_auto_flat() {
+        raphson_auto(0.0, |x: f64| x.cos() - 2.0, 1e-12, 100);
+    }
+
+    #[test]
+    fn test_raphson_with_progress_reports_every_step() {
+        let mut steps = Vec::new();
+        let mut last = f64::NAN;
+        let root = raphson_with_progress(
+            1.0,
+            |x: f64| x * x - 2.0,
+            |x: f64| 2.0 * x,
+            1e-12,
+            100,
+            |step, estimate| {
+                steps.push(step);
+                last = estimate;
+            },
+        );
+
+        // Quadratic convergence from 1.0 takes a handful of steps, numbered 1, 2, ...
+        assert!(!steps.is_empty() && steps.len() < 10);
+        assert!(steps.iter().enumerate().all(|(i, &step)| step == i + 1));
+        assert_eq!(last, root);
+        assert_eq!(root, raphson(1.0, |x| x * x - 2.0, |x| 2.0 * x, 1e-12, 100));
+    }
+
+    #[test]
+    fn test_raphson_complex_analytic_finds_i() {
+        let one = Complex64::new(1.0, 0.0);
+        let f = |z: Complex64| z * z + one;
+        let df = |z: Complex64| Complex64::new(2.0, 0.0) * z;
+
+        let root = raphson_complex_analytic(Complex64::new(0.2, 1.3), f, df, 1e-15, 50).unwrap();
+        assert!((root - Complex64::new(0.0, 1.0)).norm() < 1e-15);
 
-        if y_prime.abs() < tol {
-            panic!("Derivative too small");
+        // The lower half-plane belongs to the basin of -i
+        let root = raphson_complex_analytic(Complex64::new(-0.4, -0.7), f, df, 1e-15, 50).unwrap();
+        assert!((root - Complex64::new(0.0, -1.0)).norm() < 1e-15);
+    }
+
+    #[test]
+    fn test_raphson_complex_analytic_refines_cos_root() {
+        // cos has only the real roots π/2 + kπ, also for complex starting points
+        let root = raphson_complex_analytic(
+            Complex64::new(1.4, 0.2),
+            |z: Complex64| z.cos(),
+            |z: Complex64| -z.sin(),
+            1e-14,
+            50,
+        )
+        .unwrap();
+        assert!((root.re - 0.5 * std::f64::consts::PI).abs() < 1e-14);
+        assert!(root.im.abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_raphson_complex_analytic_errors() {
+        let f = |z: Complex64| z * z + Complex64::new(1.0, 0.0);
+        let df = |z: Complex64| Complex64::new(2.0, 0.0) * z;
+
+        // From a real start the iterates stay real and never settle
+        assert!(matches!(
+            raphson_complex_analytic(Complex64::new(0.5, 0.0), f, df, 1e-12, 30),
+            Err(SolverError::NoConvergence { iterations: 30, step }) if step >= 1e-12
+        ));
+        assert_eq!(
+            raphson_complex_analytic(Complex64::new(0.0, 0.0), f, df, 1e-12, 30),
+            Err(SolverError::DerivativeTooSmall {
+                at: Complex64::ZERO,
+                tol: 1e-12
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_raphson_converges() {
+        let root = try_raphson(1.0, |x| x * x - 2.0, |x| 2.0 * x, 1e-12, 100).unwrap();
+        assert_eq!(root, raphson(1.0, |x| x * x - 2.0, |x| 2.0 * x, 1e-12, 100));
+    }
+
+    #[test]
+    fn test_try_raphson_errors() {
+        let f = |x: f64| x.powi(3);
+        let df = |x: f64| 3.0 * x * x;
+
+        // f'(0) = 0 at the starting point
+        assert_eq!(
+            try_raphson(0.0, f, df, 1e-12, 100),
+            Err(RootError::DerivativeTooSmall { x: 0.0 })
+        );
+
+        // The triple root is approached linearly, x shrinking by 2/3 per step
+        let error = try_raphson(1.0, f, df, 1e-12, 10);
+        let expected_last = (2.0_f64 / 3.0).powi(10);
+        assert!(matches!(
+            error,
+            Err(RootError::MaxIterationsReached { last, iterations: 10 })
+                if (last - expected_last).abs() < 1e-15
+        ));
+
+        assert_eq!(
+            try_raphson(-1.0, f64::ln, |x| 1.0 / x, 1e-12, 100),
+            Err(RootError::NanEncountered)
+        );
+    }
+
+    #[test]
+    fn test_raphson_keeps_legacy_behaviour() {
+        // Unconverged: the last iterate, no panic
+        let last = raphson(1.0, |x: f64| x.powi(3), |x| 3.0 * x * x, 1e-12, 10);
+        assert!((last - (2.0_f64 / 3.0).powi(10)).abs() < 1e-15);
+
+        assert!(raphson(-1.0, f64::ln, |x| 1.0 / x, 1e-12, 100).is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "Derivative too small")]
+    fn test_raphson_flat_derivative_panics() {
+        raphson(0.0, |x: f64| x.powi(3), |x| 3.0 * x * x, 1e-12, 100);
+    }
+
+    #[test]
+    fn test_raphson_sweep_square_roots() {
+        let params: Vec<f64> = (1..=100).map(f64::from).collect();
+        let roots = raphson_sweep(&params, 1.0, |x, a| x * x - a, |x, _| 2.0 * x, 1e-12, 100);
+
+        assert_eq!(roots.len(), params.len());
+        for (root, a) in roots.iter().zip(&params) {
+            assert!((root - a.sqrt()).abs() < 1e-12, "a = {a}");
         }
+        assert!(raphson_sweep(&[], 1.0, |x, a| x - a, |_, _| 1.0, 1e-12, 100).is_empty());
+    }
+
+    #[test]
+    fn test_raphson_sweep_warm_start_saves_iterations() {
+        use std::cell::Cell;
+
+        let params: Vec<f64> = (1..=100).map(f64::from).collect();
+        let evaluations = Cell::new(0);
+        let f = |x: f64, a: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x * x - a
+        };
+        let df = |x: f64, _: f64| 2.0 * x;
 
-        let x_new = x - y / y_prime;
+        let warm = raphson_sweep(&params, 1.0, f, df, 1e-12, 100);
+        let warm_evaluations = evaluations.replace(0);
 
-        if (x_new - x).abs() < tol {
-            return x_new;
+        let cold: Vec<f64> = params
+            .iter()
+            .map(|&a| raphson(1.0, |x| f(x, a), |x| df(x, a), 1e-12, 100))
+            .collect();
+        let cold_evaluations = evaluations.get();
+
+        for (w, c) in warm.iter().zip(&cold) {
+            assert!((w - c).abs() < 1e-12);
         }
+        // About half as many: 4 iterations per solve instead of 8
+        assert!(
+            warm_evaluations < cold_evaluations * 3 / 5,
+            "{warm_evaluations} warm vs {cold_evaluations} cold"
+        );
+    }
+
+    #[test]
+    fn test_solver_error_messages() {
+        let error = SolverError::DerivativeTooSmall {
+            at: Complex64::new(1.23, 0.0),
+            tol: 1e-10,
+        };
+        let message = error.to_string();
+        assert!(message.starts_with("derivative too small (|f'(x)| < 1e-10) at x = 1.23 — "));
+        assert!(message.ends_with(error.hint()));
 
-        x = x_new;
+        let error = SolverError::DerivativeTooSmall {
+            at: Complex64::new(0.5, -2.0),
+            tol: 1e-10,
+        };
+        assert!(error.to_string().contains("at x = 0.5-2i"));
+
+        let error = SolverError::NoConvergence {
+            iterations: 40,
+            step: 0.25,
+        };
+        let message = error.to_string();
+        assert!(
+            message.starts_with(
+                "iteration did not converge within 40 iterations (last step 2.5e-1) — "
+            )
+        );
+        assert!(message.ends_with(error.hint()));
+    }
+
+    #[test]
+    fn test_solver_error_hints() {
+        let errors = [
+            SolverError::DerivativeTooSmall {
+                at: Complex64::ZERO,
+                tol: 1e-12,
+            },
+            SolverError::NoConvergence {
+                iterations: 1,
+                step: 1.0,
+            },
+        ];
+        for error in errors {
+            assert!(!error.hint().is_empty());
+        }
+        assert_ne!(errors[0].hint(), errors[1].hint());
+    }
+
+    #[test]
+    fn test_raphson_with_options_defaults_match_raphson() {
+        let f = |x: f64| x.powi(3) - x - 1.0;
+        let df = |x: f64| 3.0 * x * x - 1.0;
+        assert_eq!(
+            raphson_with_options(1.5, f, df, None),
+            Some(raphson(1.5, f, df, 1e-12, 100))
+        );
     }
 
-    x
+    #[test]
+    fn test_raphson_with_options_reports_non_convergence() {
+        // Newton on x²⁰ - 1 from x = 3 shrinks x by only about 5% per step
+        let f = |x: f64| x.powi(20) - 1.0;
+        let df = |x: f64| 20.0 * x.powi(19);
+        let options = IterativeOptions {
+            max_iter: 5,
+            ..IterativeOptions::default()
+        };
+        assert_eq!(raphson_with_options(3.0, f, df, Some(options)), None);
+
+        let root = raphson_with_options(3.0, f, df, None).unwrap();
+        assert!((root - 1.0).abs() < 1e-12);
+
+        // No real root at all
+        assert_eq!(
+            raphson_with_options(0.5, |x| x * x + 1.0, |x| 2.0 * x, None),
+            None
+        );
+
+        // A vanishing derivative is reported, not panicked on
+        assert_eq!(
+            raphson_with_options(0.0, |x| x * x + 1.0, |x| 2.0 * x, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_raphson_with_progress_stops_at_max_iter() {
+        // x² + 1 has no real root, so the iteration never converges
+        let mut count = 0;
+        raphson_with_progress(
+            0.5,
+            |x| x * x + 1.0,
+            |x| 2.0 * x,
+            1e-12,
+            7,
+            |_, _| count += 1,
+        );
+        assert_eq!(count, 7);
+    }
 }
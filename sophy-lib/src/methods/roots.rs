@@ -0,0 +1,148 @@
+//! # Root Diagnostics
+//!
+//! Properties of a root that has already been located. The multiplicity decides
+//! how an iterative solver behaves near it: Newton's method converges
+//! quadratically to a simple root but only linearly to a multiple one.
+
+use crate::base::numbers::{EPSILON, binomial};
+
+/// Highest multiplicity [`estimate_multiplicity`] reports; beyond it the finite
+/// difference estimates of the derivatives are dominated by rounding error
+const MAX_MULTIPLICITY: u32 = 8;
+
+/// Estimates the multiplicity of a root of f.
+///
+/// A root r has multiplicity m when f(r) = f'(r) = ... = f⁽ᵐ⁻¹⁾(r) = 0 but
+/// f⁽ᵐ⁾(r) ≠ 0. The derivatives are estimated in turn by central finite
+/// differences and the first one larger than `tol` in magnitude gives m.
+///
+/// ## Arguments
+///
+/// * `f` - The function whose root is examined.
+/// * `root` - The root, as accurately as it is known.
+/// * `tol` - Magnitude below which a derivative counts as zero.
+///
+/// ## Returns
+///
+/// The estimated multiplicity: 0 if |f(root)| > `tol`, so that `root` is not a
+/// root at all, and at most 8 (returned also when every derivative up to the
+/// eighth appears to vanish, as for f ≡ 0).
+///
+/// ## Sensitivity
+///
+/// The result depends on `tol` relative to the scale of f. A derivative of
+/// size c at a simple root is only recognized if c > `tol`, so a `tol` that is
+/// too large overstates the multiplicity. Conversely, finite difference
+/// estimates of the k-th derivative carry errors that grow with k, and an error
+/// in `root` of δ leaves f⁽ᵏ⁾(root) ≈ f⁽ᵐ⁾(r)·δᵐ⁻ᵏ/(m - k)!, so a `tol` below
+/// these errors understates the multiplicity. Values around 10⁻⁶ times the
+/// scale of f suit roots accurate to near machine precision.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::roots::estimate_multiplicity;
+///
+/// // (x - 1)²(x + 2) has a double root at 1 and a simple root at -2
+/// let f = |x: f64| (x - 1.0).powi(2) * (x + 2.0);
+/// assert_eq!(estimate_multiplicity(f, 1.0, 1e-6), 2);
+/// assert_eq!(estimate_multiplicity(f, -2.0, 1e-6), 1);
+/// assert_eq!(estimate_multiplicity(f, 0.0, 1e-6), 0);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `tol` is not positive.
+pub fn estimate_multiplicity<F>(f: F, root: f64, tol: f64) -> u32
+where
+    F: Fn(f64) -> f64,
+{
+    if tol <= 0.0 {
+        panic!("Multiplicity tolerance must be positive");
+    }
+
+    (0..MAX_MULTIPLICITY)
+        .find(|&order| nth_derivative(&f, root, order).abs() > tol)
+        .unwrap_or(MAX_MULTIPLICITY)
+}
+
+/// Central finite difference estimate of the k-th derivative of f at x
+///
+/// ```text
+/// f⁽ᵏ⁾(x) ≈ h⁻ᵏ Σⱼ (-1)ʲ C(k, j) f(x + (k/2 - j)·h)
+/// ```
+///
+/// with the step h = ε^(1/(k+2))·max(1, |x|) balancing the O(h²) truncation
+/// error against the O(ε/hᵏ) rounding error.
+fn nth_derivative<F>(f: &F, x: f64, order: u32) -> f64
+where
+    F: Fn(f64) -> f64,
+{
+    if order == 0 {
+        return f(x);
+    }
+
+    let h = EPSILON.powf(1.0 / (order + 2) as f64) * x.abs().max(1.0);
+    let half = 0.5 * order as f64;
+    let sum: f64 = (0..=order as u64)
+        .map(|j| {
+            let sign = if j % 2 == 0 { 1.0 } else { -1.0 };
+            sign * binomial(order as u64, j) as f64 * f(x + (half - j as f64) * h)
+        })
+        .sum();
+
+    sum / h.powi(order as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_root() {
+        let root = 2.0_f64.sqrt();
+        assert_eq!(estimate_multiplicity(|x| x * x - 2.0, root, 1e-6), 1);
+        assert_eq!(estimate_multiplicity(f64::sin, 0.0, 1e-6), 1);
+    }
+
+    #[test]
+    fn test_multiple_roots() {
+        assert_eq!(estimate_multiplicity(|x| (x - 2.0).powi(2), 2.0, 1e-6), 2);
+        assert_eq!(estimate_multiplicity(|x| (x - 2.0).powi(3), 2.0, 1e-6), 3);
+        assert_eq!(estimate_multiplicity(|x| (x - 2.0).powi(4), 2.0, 1e-6), 4);
+
+        // 1 - cos x has a double root at 0
+        assert_eq!(estimate_multiplicity(|x| 1.0 - x.cos(), 0.0, 1e-6), 2);
+    }
+
+    #[test]
+    fn test_root_away_from_origin() {
+        // Step sizes scale with |x|
+        let f = |x: f64| (x - 1e4).powi(2);
+        assert_eq!(estimate_multiplicity(f, 1e4, 1e-6), 2);
+    }
+
+    #[test]
+    fn test_not_a_root() {
+        assert_eq!(estimate_multiplicity(|x| x * x - 2.0, 1.0, 1e-6), 0);
+    }
+
+    #[test]
+    fn test_identically_zero() {
+        assert_eq!(estimate_multiplicity(|_| 0.0, 1.0, 1e-6), MAX_MULTIPLICITY);
+    }
+
+    #[test]
+    fn test_tolerance_sensitivity() {
+        // f'(0) = 10⁻³ is only seen as nonzero with a smaller tolerance
+        let f = |x: f64| 1e-3 * x + x * x;
+        assert_eq!(estimate_multiplicity(f, 0.0, 1e-6), 1);
+        assert_eq!(estimate_multiplicity(f, 0.0, 1e-2), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Multiplicity tolerance must be positive")]
+    fn test_invalid_tolerance() {
+        estimate_multiplicity(|x| x, 0.0, 0.0);
+    }
+}
@@ -0,0 +1,108 @@
+//! # Threshold Search on the Reals
+//!
+//! Bisection on a monotone boolean predicate: find the point where `pred(x)`
+//! switches from false to true. This generalizes root bracketing, where the
+//! predicate is "f(x) has changed sign", to feasibility and capacity questions
+//! such as "is a buffer of size x large enough?".
+
+/// Finds the point in `[lo, hi]` where a monotone predicate flips from false to true.
+///
+/// The interval is halved, keeping `pred` false at the lower end and true at the
+/// upper end, until it is shorter than `tol`. The midpoint of the final interval
+/// is returned, so it lies within `tol / 2` of the transition.
+///
+/// ## Arguments
+///
+/// * `pred` - A predicate that is false below some threshold and true above it.
+/// * `lo`, `hi` - Interval endpoints with `lo < hi`.
+/// * `tol` - Width of the final interval; must be positive.
+///
+/// ## Returns
+///
+/// The estimated transition point. If `pred` is already true at `lo` the result
+/// approaches `lo`; if it is still false at `hi` the result approaches `hi`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::search::binary_search_real;
+///
+/// // Smallest x with x² > 2
+/// let x = binary_search_real(|x| x * x > 2.0, 0.0, 2.0, 1e-12);
+/// assert!((x - 2.0_f64.sqrt()).abs() < 1e-12);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `lo >= hi` or if `tol` is not positive.
+pub fn binary_search_real<P>(pred: P, lo: f64, hi: f64, tol: f64) -> f64
+where
+    P: Fn(f64) -> bool,
+{
+    if lo >= hi {
+        panic!("Binary search requires an interval with lo < hi");
+    }
+    if tol <= 0.0 {
+        panic!("Binary search tolerance must be positive");
+    }
+
+    let (mut lo, mut hi) = (lo, hi);
+
+    while hi - lo > tol {
+        let mid = 0.5 * (lo + hi);
+        // Stop once the interval cannot be split further in floating point
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    0.5 * (lo + hi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_sqrt2() {
+        let x = binary_search_real(|x| x * x > 2.0, 0.0, 2.0, 1e-12);
+        assert!((x - 2.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_search_step_function() {
+        let step = |x: f64| if x >= 0.3 { 1.0 } else { 0.0 };
+        let x = binary_search_real(|x| step(x) > 0.5, -1.0, 1.0, 1e-9);
+        assert!((x - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_search_tiny_tolerance_terminates() {
+        // A tolerance below the spacing of floats near the answer still stops
+        let x = binary_search_real(|x| x > 1e6, 0.0, 2e6, 1e-30);
+        assert!((x - 1e6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_search_predicate_constant() {
+        assert!(binary_search_real(|_| true, 0.0, 1.0, 1e-10) < 1e-10);
+        assert!(binary_search_real(|_| false, 0.0, 1.0, 1e-10) > 1.0 - 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search requires an interval with lo < hi")]
+    fn test_search_invalid_interval() {
+        binary_search_real(|x| x > 0.0, 1.0, 1.0, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary search tolerance must be positive")]
+    fn test_search_invalid_tolerance() {
+        binary_search_real(|x| x > 0.0, 0.0, 1.0, 0.0);
+    }
+}
@@ -0,0 +1,181 @@
+//! # Secant Method
+//!
+//! Newton's iteration with the derivative replaced by the slope of the secant
+//! through the last two iterates:
+//!
+//! **x_{n+1} = x_n - f(x_n)·(x_n - x_{n-1}) / (f(x_n) - f(x_{n-1}))**
+//!
+//! Only function values are needed. Near a simple root the convergence is
+//! superlinear, of order (1 + √5)/2 ≈ 1.618; per function evaluation this is
+//! faster than Newton's method, which needs f and f' at every step.
+
+use crate::base::numbers::EPSILON;
+use crate::methods::bisection::RootError;
+
+/// Secant root-finding method.
+///
+/// Iterates from the two starting points until the step |x_{n+1} - x_n| drops
+/// below `tol`. Unlike [`bisection`](super::bisection()), the root need not be
+/// bracketed by the starting points, but convergence is only assured close to it.
+///
+/// ## Arguments
+///
+/// * `x0`, `x1` - Two distinct initial guesses near the root.
+/// * `f` - The function whose root is sought.
+/// * `tol` - Step size below which the iteration has converged.
+/// * `max_iter` - Maximum number of iterations.
+///
+/// ## Returns
+///
+/// The root, or
+/// - [`RootError::FlatSecant`] if f(x_n) - f(x_{n-1}) vanishes to rounding
+///   error, where the next iterate would be infinite or NaN
+/// - [`RootError::MaxIterationsReached`] if the step is still at least `tol` after
+///   `max_iter` iterations
+/// - [`RootError::NanEncountered`] if f returns NaN or an iterate overflows
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::secant::secant;
+///
+/// // √2 as the root of x² - 2, without a derivative
+/// let root = secant(1.0, 2.0, |x| x * x - 2.0, 1e-12, 100).unwrap();
+/// assert!((root - std::f64::consts::SQRT_2).abs() < 1e-12);
+/// ```
+pub fn secant<F>(x0: f64, x1: f64, f: F, tol: f64, max_iter: usize) -> Result<f64, RootError>
+where
+    F: Fn(f64) -> f64,
+{
+    secant_counted(x0, x1, &f, tol, max_iter).0
+}
+
+/// [`secant`], also returning the number of iterations performed
+pub(crate) fn secant_counted<F>(
+    x0: f64,
+    x1: f64,
+    f: &F,
+    tol: f64,
+    max_iter: usize,
+) -> (Result<f64, RootError>, usize)
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut x_prev, mut x) = (x0, x1);
+    let (mut f_prev, mut f_x) = (f(x_prev), f(x));
+
+    for iteration in 1..=max_iter {
+        if f_x.is_nan() || f_prev.is_nan() {
+            return (Err(RootError::NanEncountered), iteration);
+        }
+        if f_x == 0.0 {
+            return (Ok(x), iteration);
+        }
+
+        let denominator = f_x - f_prev;
+        if denominator.abs() <= EPSILON * f_x.abs().max(f_prev.abs()) {
+            return (Err(RootError::FlatSecant { at: x }), iteration);
+        }
+
+        let x_new = x - f_x * (x - x_prev) / denominator;
+        if !x_new.is_finite() {
+            return (Err(RootError::NanEncountered), iteration);
+        }
+        let step = (x_new - x).abs();
+        (x_prev, f_prev) = (x, f_x);
+        x = x_new;
+        f_x = f(x);
+
+        if step < tol {
+            return (Ok(x), iteration);
+        }
+    }
+
+    (
+        Err(RootError::MaxIterationsReached {
+            last: x,
+            iterations: max_iter,
+        }),
+        max_iter,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::raphson::raphson;
+    use crate::methods::testing::assert_converges;
+
+    #[test]
+    fn test_secant_sqrt2() {
+        let f = |x: f64| x * x - 2.0;
+        let root = secant(1.0, 2.0, f, 1e-10, 100).unwrap();
+        let newton = raphson(1.0, f, |x| 2.0 * x, 1e-10, 100);
+
+        // As accurate as Newton's method with the same tolerance
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-14);
+        assert!((root - newton).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_secant_without_bracket() {
+        // Both starting points lie on the same side of the root
+        let root = secant(3.0, 2.5, |x: f64| x.powi(3) - 27.0 / 8.0, 1e-12, 100);
+        assert_converges(root, 1.5, 1e-12);
+    }
+
+    #[test]
+    fn test_secant_transcendental() {
+        // cos x = x near 0.739
+        let root = secant(0.0, 1.0, |x: f64| x.cos() - x, 1e-14, 100).unwrap();
+        assert!((root.cos() - root).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_secant_exact_root() {
+        assert_eq!(secant(0.0, 3.0, |x| x - 3.0, 1e-12, 10), Ok(3.0));
+    }
+
+    #[test]
+    fn test_secant_flat() {
+        // x² - 2 takes the same value at ±1
+        assert_eq!(
+            secant(-1.0, 1.0, |x| x * x - 2.0, 1e-12, 100),
+            Err(RootError::FlatSecant { at: 1.0 })
+        );
+        assert_eq!(
+            RootError::FlatSecant { at: 1.0 }.to_string(),
+            "secant slope vanished at x = 1"
+        );
+    }
+
+    #[test]
+    fn test_secant_nan() {
+        // From 9 and 8 the first secant step of √x - 1 lands near -2.6, where
+        // √x is NaN; this is reported at once rather than after max_iter steps
+        let evaluations = std::cell::Cell::new(0);
+        let f = |x: f64| {
+            evaluations.set(evaluations.get() + 1);
+            x.sqrt() - 1.0
+        };
+        assert_eq!(
+            secant(9.0, 8.0, f, 1e-12, 100),
+            Err(RootError::NanEncountered)
+        );
+        assert_eq!(evaluations.get(), 3);
+
+        assert_eq!(
+            secant(-1.0, 1.0, |x: f64| x.sqrt(), 1e-12, 100),
+            Err(RootError::NanEncountered)
+        );
+    }
+
+    #[test]
+    fn test_secant_max_iterations() {
+        let result = secant(1.0, 2.0, |x| x * x - 2.0, 1e-12, 2);
+        assert!(matches!(
+            result,
+            Err(RootError::MaxIterationsReached { iterations: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,9 @@
+//! # Signal Processing
+//!
+//! Building blocks for spectral analysis of sampled signals.
+//!
+//! ## Available Tools
+//!
+//! - [`window`]: Hann, Hamming, Blackman and Bartlett window functions
+
+pub mod window;
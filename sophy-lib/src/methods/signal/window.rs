@@ -0,0 +1,115 @@
+//! # Window Functions
+//!
+//! Tapering windows applied to a finite block of samples before a spectral
+//! transform. Truncating a signal abruptly spreads the energy of every frequency
+//! over the whole spectrum (spectral leakage); multiplying by a window that falls
+//! smoothly to the block edges trades a wider main lobe for much lower side lobes.
+//!
+//! All windows here are symmetric: `w[k] = w[n - 1 - k]`, with the endpoints at
+//! k = 0 and k = n - 1. A window of length 1 is `[1.0]` and a window of length 0
+//! is empty.
+
+use crate::base::numbers::PI;
+
+/// Builds a symmetric window from its shape on the normalized position t = k/(n - 1) ∈ [0, 1]
+fn symmetric_window<F>(n: usize, shape: F) -> Vec<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    match n {
+        0 => Vec::new(),
+        1 => vec![1.0],
+        _ => (0..n).map(|k| shape(k as f64 / (n - 1) as f64)).collect(),
+    }
+}
+
+/// Hann window w[k] = 0.5 - 0.5·cos(2πk/(n - 1))
+///
+/// Falls to zero at both ends, so the windowed block joins continuously with its
+/// periodic extension.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::signal::window::hann;
+///
+/// let w = hann(5);
+/// assert_eq!(w.len(), 5);
+/// assert_eq!(w[0], 0.0);
+/// assert!((w[2] - 1.0).abs() < 1e-15);
+/// ```
+pub fn hann(n: usize) -> Vec<f64> {
+    symmetric_window(n, |t| 0.5 - 0.5 * (2.0 * PI * t).cos())
+}
+
+/// Hamming window w[k] = 0.54 - 0.46·cos(2πk/(n - 1))
+///
+/// Does not reach zero at the ends (w = 0.08), which cancels the first side lobe
+/// of the Hann window.
+pub fn hamming(n: usize) -> Vec<f64> {
+    symmetric_window(n, |t| 0.54 - 0.46 * (2.0 * PI * t).cos())
+}
+
+/// Blackman window w[k] = 0.42 - 0.5·cos(2πk/(n - 1)) + 0.08·cos(4πk/(n - 1))
+///
+/// Lower side lobes than Hann or Hamming at the price of a wider main lobe.
+pub fn blackman(n: usize) -> Vec<f64> {
+    symmetric_window(n, |t| {
+        0.42 - 0.5 * (2.0 * PI * t).cos() + 0.08 * (4.0 * PI * t).cos()
+    })
+}
+
+/// Bartlett (triangular) window w[k] = 1 - |2k/(n - 1) - 1|
+pub fn bartlett(n: usize) -> Vec<f64> {
+    symmetric_window(n, |t| 1.0 - (2.0 * t - 1.0).abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOWS: [fn(usize) -> Vec<f64>; 4] = [hann, hamming, blackman, bartlett];
+
+    #[test]
+    fn test_hann_endpoints_and_peak() {
+        let w = hann(9);
+        assert_eq!(w[0], 0.0);
+        assert!(w[8].abs() < 1e-15);
+        assert!((w[4] - 1.0).abs() < 1e-15);
+        assert!(w.iter().all(|&v| v <= 1.0));
+    }
+
+    #[test]
+    fn test_windows_symmetric() {
+        for window in WINDOWS {
+            for n in [6, 7, 32] {
+                let w = window(n);
+                for k in 0..n {
+                    assert!((w[k] - w[n - 1 - k]).abs() < 1e-15);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_windows_length() {
+        for window in WINDOWS {
+            for n in [0, 1, 2, 10, 257] {
+                assert_eq!(window(n).len(), n);
+            }
+            assert_eq!(window(1), vec![1.0]);
+        }
+    }
+
+    #[test]
+    fn test_window_values() {
+        // Endpoint values distinguish the window families
+        assert!((hamming(11)[0] - 0.08).abs() < 1e-15);
+        assert!(blackman(11)[0].abs() < 1e-15);
+        assert_eq!(bartlett(5), vec![0.0, 0.5, 1.0, 0.5, 0.0]);
+
+        // Peaks at the center for odd lengths
+        assert!((hamming(11)[5] - 1.0).abs() < 1e-15);
+        assert!((blackman(11)[5] - 1.0).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,172 @@
+//! # Solver Test Helpers
+//!
+//! Shared checks for verifying iterative solvers: [`assert_converges`] compares a
+//! solver result against a known root, and [`measure_convergence_rate`] estimates
+//! the empirical order of convergence from the errors of successive iterates.
+
+use std::fmt;
+
+/// Asserts that a solver succeeded with a value within `tol` of `expected`.
+///
+/// Any error type that can be displayed is accepted, such as
+/// [`RootError`](crate::methods::bisection::RootError) from the real root
+/// finders or [`SolverError`](crate::methods::raphson::SolverError).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::brent::brent;
+/// use sophy::methods::testing::assert_converges;
+///
+/// let root = brent(1.0, 2.0, |x| x * x - 2.0, 1e-15, 100);
+/// assert_converges(root, 2.0_f64.sqrt(), 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics with the error if `result` is `Err`, or with the value, the expected
+/// value and the difference if they are further apart than `tol`.
+#[track_caller]
+pub fn assert_converges<E: fmt::Display>(result: Result<f64, E>, expected: f64, tol: f64) {
+    let value = match result {
+        Ok(value) => value,
+        Err(error) => panic!("Solver failed to converge to {expected}: {error}"),
+    };
+
+    let difference = (value - expected).abs();
+    if difference > tol || difference.is_nan() {
+        panic!(
+            "Solver converged to {value}, expected {expected} (difference {difference:e} exceeds tolerance {tol:e})"
+        );
+    }
+}
+
+/// Estimates the order of convergence p from a sequence of iterate errors.
+///
+/// For errors behaving like eₖ₊₁ ≈ C·eₖᵖ, the order follows from three
+/// consecutive errors as
+///
+/// ```text
+/// p ≈ ln(eₖ₊₁ / eₖ) / ln(eₖ / eₖ₋₁)
+/// ```
+///
+/// The last three errors are used, since the asymptotic rate is only reached
+/// near the root. Linear convergence gives 1, Newton's method 2 and the secant
+/// method about 1.618.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::methods::testing::measure_convergence_rate;
+///
+/// // Errors of Newton's method for √2 starting from 1
+/// let errors = [4.1e-1, 8.6e-2, 2.5e-3, 2.1e-6];
+/// let rate = measure_convergence_rate(&errors);
+/// assert!((rate - 2.0).abs() < 0.1);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if fewer than three errors are given or if any of the last three is
+/// not positive and finite.
+pub fn measure_convergence_rate(errors: &[f64]) -> f64 {
+    if errors.len() < 3 {
+        panic!("Convergence rate requires at least three errors");
+    }
+
+    let last = &errors[errors.len() - 3..];
+    if last.iter().any(|e| !(e.is_finite() && *e > 0.0)) {
+        panic!("Convergence rate requires positive finite errors");
+    }
+
+    (last[2] / last[1]).ln() / (last[1] / last[0]).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::methods::bisection::RootError;
+    use crate::methods::raphson::SolverError;
+
+    #[test]
+    fn test_assert_converges_accepts_close_value() {
+        assert_converges(Ok::<_, RootError>(2.0 + 1e-13), 2.0, 1e-12);
+        assert_converges(Ok::<_, SolverError>(-3.0), -3.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Solver converged to 2.1, expected 2 (difference")]
+    fn test_assert_converges_rejects_wrong_value() {
+        assert_converges(Ok::<_, RootError>(2.1), 2.0, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Solver converged to NaN")]
+    fn test_assert_converges_rejects_nan() {
+        assert_converges(Ok::<_, RootError>(f64::NAN), 2.0, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Solver failed to converge to 0: no sign change on the interval")]
+    fn test_assert_converges_rejects_root_error() {
+        let error = RootError::NoSignChange { f_a: 1.0, f_b: 2.0 };
+        assert_converges(Err(error), 0.0, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Solver failed to converge to 2: iteration did not converge within 50 iterations"
+    )]
+    fn test_assert_converges_rejects_error() {
+        let error = SolverError::NoConvergence {
+            iterations: 50,
+            step: 0.1,
+        };
+        assert_converges(Err(error), 2.0, 1e-6);
+    }
+
+    #[test]
+    fn test_rate_quadratic() {
+        // eₖ₊₁ = eₖ²
+        let errors = [1e-1, 1e-2, 1e-4, 1e-8];
+        assert!((measure_convergence_rate(&errors) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rate_linear_and_superlinear() {
+        let linear: Vec<f64> = (0..10).map(|k| 0.5_f64.powi(k)).collect();
+        assert!((measure_convergence_rate(&linear) - 1.0).abs() < 1e-12);
+
+        // Secant-like errors eₖ₊₁ = eₖ·eₖ₋₁ converge with the golden ratio
+        let mut secant = vec![0.5, 0.4];
+        for k in 2..8 {
+            secant.push(secant[k - 1] * secant[k - 2]);
+        }
+        let golden = 0.5 * (1.0 + 5.0_f64.sqrt());
+        assert!((measure_convergence_rate(&secant) - golden).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_rate_of_newton_iteration() {
+        let root = 2.0_f64.sqrt();
+        let mut x = 1.0;
+        let mut errors = Vec::new();
+        for _ in 0..4 {
+            x -= (x * x - 2.0) / (2.0 * x);
+            errors.push((x - root).abs());
+        }
+        assert!((measure_convergence_rate(&errors) - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Convergence rate requires at least three errors")]
+    fn test_rate_too_few_errors() {
+        measure_convergence_rate(&[1e-1, 1e-2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Convergence rate requires positive finite errors")]
+    fn test_rate_zero_error() {
+        measure_convergence_rate(&[1e-1, 1e-2, 0.0]);
+    }
+}
@@ -0,0 +1,57 @@
+//! # Prelude
+//!
+//! The most commonly used items of Sophy, for a single glob import:
+//!
+//! ```rust
+//! use sophy::prelude::*;
+//! ```
+//!
+//! The prelude is deliberately small: the mathematical constants, the core value
+//! types, one entry point per kind of numerical method and the everyday special
+//! functions. Everything else stays at its full path, e.g.
+//! `sophy::specials::safe` or `sophy::methods::integration::gauss_kronrod`, and
+//! every item here remains available at its original path too.
+//!
+//! ## Contents
+//!
+//! - Constants: [`PI`], [`EULER`], [`PHI`]
+//! - Types: [`Complex64`], [`Polynomial`], [`Matrix`]
+//! - Root finding and optimization: [`raphson`], [`binary_search_real`], [`brent_min`]
+//! - Integration: [`integrate_with_error`], [`qag`], [`romberg`], [`gauss_legendre`]
+//! - Special functions: [`gamma`], [`lgamma_sign`], [`zeta`], [`erf`], [`normal_cdf`]
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use sophy::prelude::*;
+//!
+//! // √2 by Newton-Raphson
+//! let root = raphson(1.0, |x| x * x - 2.0, |x| 2.0 * x, 1e-12, 100);
+//! assert!((root * root - 2.0).abs() < 1e-12);
+//!
+//! // Minimum of cos on [2, 4.5] is at π
+//! let (x_min, _) = brent_min(f64::cos, 2.0, 4.5, 1e-10);
+//! assert!((x_min - PI).abs() < 1e-7);
+//!
+//! // ∫₀¹ eˣ dx = e - 1
+//! let (value, _) = qag(f64::exp, 0.0, 1.0, 1e-12, 50);
+//! assert!((value - (EULER - 1.0)).abs() < 1e-12);
+//!
+//! // Γ(5) = 4! and ζ(2) = π²/6
+//! assert!((gamma(5.0) - 24.0).abs() < 1e-10);
+//! assert!((zeta(2.0) - PI * PI / 6.0).abs() < 1e-6);
+//! assert_eq!(erf(0.0), 0.0);
+//!
+//! let z = Complex64::new(3.0, 4.0);
+//! assert_eq!(z.norm(), 5.0);
+//! ```
+
+pub use crate::base::complex::Complex64;
+pub use crate::base::matrix::Matrix;
+pub use crate::base::numbers::{EULER, PHI, PI};
+pub use crate::base::poly::Polynomial;
+pub use crate::methods::integration::{gauss_legendre, integrate_with_error, qag, romberg};
+pub use crate::methods::optimize::brent_min;
+pub use crate::methods::raphson::raphson;
+pub use crate::methods::search::binary_search_real;
+pub use crate::specials::{erf, gamma, lgamma_sign, normal_cdf, zeta};
@@ -0,0 +1,282 @@
+//! Airy functions implementation
+//!
+//! The Airy functions Ai(x) and Bi(x) are the two independent solutions of the
+//! Airy equation y'' = x·y. They describe the behaviour of wave functions near a
+//! classical turning point and are central to the WKB approximation.
+
+use crate::base::numbers::PI;
+use crate::methods::integration::gauss_legendre;
+use crate::specials::gamma::gamma;
+
+/// Beyond this |x| the asymptotic expansions replace the Maclaurin series
+const ASYMPTOTIC_THRESHOLD: f64 = 7.0;
+
+/// Airy function of the first kind Ai(x)
+///
+/// ## Mathematical Definition
+///
+/// Ai(x) = (1/π) ∫₀^∞ cos(t³/3 + x·t) dt
+///
+/// ## Properties
+///
+/// - Ai(0) = 1/(3^(2/3)·Γ(2/3)) ≈ 0.355028
+/// - Decays like e^(-2x^(3/2)/3) as x → +∞
+/// - Oscillates with slowly decreasing amplitude as x → -∞
+/// - First zero at x ≈ -2.338107
+///
+/// ## Implementation
+///
+/// - **x > 2**: the exponentially scaled integral representation (DLMF 9.5.6)
+///   Ai(x) = (e^(-ζ)/π) ∫₀^∞ exp(-√x·t²)·cos(t³/3) dt, ζ = 2x^(3/2)/3,
+///   evaluated with 40-point Gauss-Legendre quadrature. This avoids the
+///   cancellation that ruins the power series for positive arguments.
+/// - **-7 ≤ x ≤ 2**: the Maclaurin series
+/// - **x < -7**: the oscillatory asymptotic expansion
+///
+/// Relative accuracy is about 1e-13 or better away from the zeros.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::{ai, gamma};
+///
+/// let ai0 = 1.0 / (3.0_f64.powf(2.0 / 3.0) * gamma(2.0 / 3.0));
+/// assert!((ai(0.0) - ai0).abs() < 1e-15);
+///
+/// assert!((ai(1.0) - 0.135_292_416_312_881_4).abs() < 1e-14);
+/// ```
+pub fn ai(x: f64) -> f64 {
+    if x > 2.0 {
+        let zeta = 2.0 / 3.0 * x.powf(1.5);
+        let sqrt_x = x.sqrt();
+
+        // The integrand is below 1e-17 beyond exp(-√x·t²) = e^(-40)
+        let upper = (40.0 / sqrt_x).sqrt();
+        let integral = gauss_legendre(
+            |t| (-sqrt_x * t * t).exp() * (t.powi(3) / 3.0).cos(),
+            0.0,
+            upper,
+            40,
+        );
+        return (-zeta).exp() / PI * integral;
+    }
+
+    if x < -ASYMPTOTIC_THRESHOLD {
+        return oscillatory_asymptotic(x).0;
+    }
+
+    let (f, g) = maclaurin_series(x);
+    let (c1, c2) = origin_constants();
+    c1 * f - c2 * g
+}
+
+/// Airy function of the second kind Bi(x)
+///
+/// ## Mathematical Definition
+///
+/// Bi(x) = (1/π) ∫₀^∞ [exp(-t³/3 + x·t) + sin(t³/3 + x·t)] dt
+///
+/// ## Properties
+///
+/// - Bi(0) = 1/(3^(1/6)·Γ(2/3)) ≈ 0.614927
+/// - Grows like e^(2x^(3/2)/3) as x → +∞ (overflows beyond x ≈ 104)
+/// - Oscillates with slowly decreasing amplitude as x → -∞, a quarter period out
+///   of phase with Ai
+///
+/// ## Implementation
+///
+/// - **|x| ≤ 7**: the Maclaurin series (no cancellation occurs for Bi with x > 0)
+/// - **x > 7**: the exponentially growing asymptotic expansion
+/// - **x < -7**: the oscillatory asymptotic expansion
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::{bi, gamma};
+///
+/// let bi0 = 1.0 / (3.0_f64.powf(1.0 / 6.0) * gamma(2.0 / 3.0));
+/// assert!((bi(0.0) - bi0).abs() < 1e-15);
+///
+/// assert!((bi(1.0) - 1.207_423_594_952_871).abs() < 1e-14);
+/// ```
+pub fn bi(x: f64) -> f64 {
+    if x > ASYMPTOTIC_THRESHOLD {
+        let zeta = 2.0 / 3.0 * x.powf(1.5);
+        let sum: f64 = asymptotic_terms(zeta).iter().sum();
+        return zeta.exp() * sum / (PI.sqrt() * x.powf(0.25));
+    }
+
+    if x < -ASYMPTOTIC_THRESHOLD {
+        return oscillatory_asymptotic(x).1;
+    }
+
+    let (f, g) = maclaurin_series(x);
+    let (c1, c2) = origin_constants();
+    3.0_f64.sqrt() * (c1 * f + c2 * g)
+}
+
+/// Returns (Ai(0), -Ai'(0))
+fn origin_constants() -> (f64, f64) {
+    (
+        1.0 / (3.0_f64.powf(2.0 / 3.0) * gamma(2.0 / 3.0)),
+        1.0 / (3.0_f64.powf(1.0 / 3.0) * gamma(1.0 / 3.0)),
+    )
+}
+
+/// The two power series solutions f(x) = Σ 3ᵏ(1/3)ₖ x³ᵏ/(3k)! and
+/// g(x) = Σ 3ᵏ(2/3)ₖ x³ᵏ⁺¹/(3k+1)!
+fn maclaurin_series(x: f64) -> (f64, f64) {
+    let x3 = x.powi(3);
+    let (mut f, mut g) = (1.0, x);
+    let (mut tf, mut tg) = (1.0, x);
+
+    for k in 1..200 {
+        let k = k as f64;
+        tf *= x3 / ((3.0 * k - 1.0) * (3.0 * k));
+        tg *= x3 / ((3.0 * k) * (3.0 * k + 1.0));
+        f += tf;
+        g += tg;
+
+        if tf.abs() <= 1e-17 * f.abs() && tg.abs() <= 1e-17 * g.abs() {
+            break;
+        }
+    }
+
+    (f, g)
+}
+
+/// Terms uₖ/ζᵏ of the Airy asymptotic series, truncated at the smallest term
+fn asymptotic_terms(zeta: f64) -> Vec<f64> {
+    let mut terms: Vec<f64> = vec![1.0];
+    let mut u = 1.0;
+
+    for k in 1..60 {
+        let kf = k as f64;
+        u *= (6.0 * kf - 5.0) * (6.0 * kf - 3.0) * (6.0 * kf - 1.0)
+            / ((2.0 * kf - 1.0) * 216.0 * kf);
+        let term = u / zeta.powi(k);
+        let previous = *terms.last().unwrap();
+
+        if term.abs() > previous.abs() || term.abs() < 1e-17 {
+            break;
+        }
+        terms.push(term);
+    }
+
+    terms
+}
+
+/// Returns (Ai(x), Bi(x)) from the asymptotic expansion for large negative x
+fn oscillatory_asymptotic(x: f64) -> (f64, f64) {
+    let y = -x;
+    let zeta = 2.0 / 3.0 * y.powf(1.5);
+
+    // Split the series into the even and odd terms with alternating signs
+    let (mut p, mut q) = (0.0, 0.0);
+    for (k, term) in asymptotic_terms(zeta).into_iter().enumerate() {
+        let sign = if (k / 2).is_multiple_of(2) { 1.0 } else { -1.0 };
+        if k.is_multiple_of(2) {
+            p += sign * term;
+        } else {
+            q += sign * term;
+        }
+    }
+
+    let theta = zeta + PI / 4.0;
+    let (sin, cos) = theta.sin_cos();
+    let prefactor = 1.0 / (PI.sqrt() * y.powf(0.25));
+
+    (
+        prefactor * (sin * p - cos * q),
+        prefactor * (cos * p + sin * q),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rel(actual: f64, expected: f64, tol: f64) {
+        assert!(
+            ((actual - expected) / expected).abs() < tol,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn test_airy_origin() {
+        let g = gamma(2.0 / 3.0);
+        assert_rel(ai(0.0), 1.0 / (3.0_f64.powf(2.0 / 3.0) * g), 1e-15);
+        assert_rel(bi(0.0), 1.0 / (3.0_f64.powf(1.0 / 6.0) * g), 1e-15);
+    }
+
+    #[test]
+    fn test_airy_reference_values() {
+        // Reference values computed with mpmath at 25 digits
+        let cases = [
+            (1.0, 0.135_292_416_312_881_4, 1.207_423_594_952_871_3),
+            (-1.0, 0.535_560_883_292_352_1, 0.103_997_389_496_944_6),
+            (3.0, 0.006_591_139_357_460_719, 14.037_328_963_730_232),
+            (6.0, 9.947_694_360_252_89e-6, 6_536.446_104_809_863),
+            (-5.0, 0.350_761_009_024_114_3, -0.138_369_134_901_600_6),
+            (10.0, 1.104_753_255_289_868_6e-10, 455_641_153.548_225_1),
+            (-10.0, 0.040_241_238_486_443_19, -0.314_679_829_643_833_1),
+            (-20.0, -0.176_406_127_077_984_7, -0.200_139_309_322_651_3),
+        ];
+
+        for (x, expected_ai, expected_bi) in cases {
+            assert_rel(ai(x), expected_ai, 1e-12);
+            assert_rel(bi(x), expected_bi, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ai_decays_for_positive_x() {
+        let mut previous = ai(0.0);
+        for k in 1..40 {
+            let value = ai(k as f64 * 0.5);
+            assert!(value > 0.0 && value < previous);
+            previous = value;
+        }
+        assert!(ai(20.0) < 1e-25);
+    }
+
+    #[test]
+    fn test_airy_oscillate_for_negative_x() {
+        // Count sign changes of Ai on [-30, -1]
+        let mut sign_changes = 0;
+        let mut previous = ai(-1.0);
+        for k in 1..=290 {
+            let value = ai(-1.0 - k as f64 * 0.1);
+            if value.signum() != previous.signum() {
+                sign_changes += 1;
+            }
+            previous = value;
+        }
+        assert!(sign_changes >= 10);
+
+        // Amplitude stays bounded by the envelope 1/(√π |x|^(1/4))
+        for &x in &[-8.0_f64, -15.0, -40.0] {
+            let envelope = 1.0 / (PI.sqrt() * (-x).powf(0.25));
+            assert!(ai(x).abs() <= envelope * 1.01);
+            assert!(bi(x).abs() <= envelope * 1.01);
+        }
+    }
+
+    #[test]
+    fn test_airy_first_zero() {
+        assert!(ai(-2.338_107_410_459_767).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_airy_wronskian() {
+        // W[Ai, Bi] = Ai·Bi' - Ai'·Bi = 1/π, checked with central differences
+        let h = 1e-5;
+        for &x in &[-9.0, -3.0, -0.5, 0.7, 2.5, 5.0] {
+            let dai = (ai(x + h) - ai(x - h)) / (2.0 * h);
+            let dbi = (bi(x + h) - bi(x - h)) / (2.0 * h);
+            let wronskian = ai(x) * dbi - dai * bi(x);
+            assert!((wronskian - 1.0 / PI).abs() < 1e-7);
+        }
+    }
+}
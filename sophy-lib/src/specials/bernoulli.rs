@@ -0,0 +1,227 @@
+//! Bernoulli numbers and Bernoulli polynomials
+//!
+//! The Bernoulli numbers Bₙ appear in the Euler-Maclaurin summation formula, in
+//! the Taylor series of tan and coth, and in the values of the Riemann zeta
+//! function at even integers. The Bernoulli polynomials Bₙ(x) generalize them,
+//! with Bₙ(0) = Bₙ.
+
+use crate::base::numbers::PI;
+
+/// B₀ to B₂₀ as exact fractions (numerator, denominator)
+const SMALL_BERNOULLI: [(f64, f64); 21] = [
+    (1.0, 1.0),
+    (-1.0, 2.0),
+    (1.0, 6.0),
+    (0.0, 1.0),
+    (-1.0, 30.0),
+    (0.0, 1.0),
+    (1.0, 42.0),
+    (0.0, 1.0),
+    (-1.0, 30.0),
+    (0.0, 1.0),
+    (5.0, 66.0),
+    (0.0, 1.0),
+    (-691.0, 2730.0),
+    (0.0, 1.0),
+    (7.0, 6.0),
+    (0.0, 1.0),
+    (-3617.0, 510.0),
+    (0.0, 1.0),
+    (43867.0, 798.0),
+    (0.0, 1.0),
+    (-174611.0, 330.0),
+];
+
+/// Bernoulli number Bₙ
+///
+/// Uses the convention B₁ = -1/2. All odd Bernoulli numbers beyond B₁ vanish.
+///
+/// ## Implementation
+///
+/// B₀ to B₂₀ come from a table of exact fractions. Larger even indices use
+///
+/// ```text
+/// Bₙ = (-1)^(n/2 + 1) · 2·n! · ζ(n) / (2π)ⁿ
+/// ```
+///
+/// where ζ(n) is summed directly; for n > 20 the series converges after a
+/// handful of terms. The factor n!/(2π)ⁿ is accumulated as a running product,
+/// so the result only overflows to infinity once |Bₙ| itself exceeds `f64::MAX`
+/// (from n = 260).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::bernoulli::bernoulli;
+///
+/// assert_eq!(bernoulli(1), -0.5);
+/// assert_eq!(bernoulli(2), 1.0 / 6.0);
+/// assert_eq!(bernoulli(7), 0.0);
+/// assert!((bernoulli(22) - 6_192.123_188_405_797).abs() < 1e-11);
+/// ```
+pub fn bernoulli(n: u32) -> f64 {
+    if let Some(&(numer, denom)) = SMALL_BERNOULLI.get(n as usize) {
+        return numer / denom;
+    }
+    if n % 2 == 1 {
+        return 0.0;
+    }
+
+    let s = n as f64;
+    let mut zeta = 1.0;
+    let mut k = 2.0_f64;
+    loop {
+        let term = k.powf(-s);
+        if term < f64::EPSILON * zeta {
+            break;
+        }
+        zeta += term;
+        k += 1.0;
+    }
+
+    // 2·n!/(2π)ⁿ, one factor k/(2π) at a time
+    let mut scale = 2.0;
+    for k in 1..=n {
+        scale *= k as f64 / (2.0 * PI);
+    }
+
+    let sign = if (n / 2).is_multiple_of(2) { -1.0 } else { 1.0 };
+    sign * scale * zeta
+}
+
+/// Bernoulli polynomial Bₙ(x)
+///
+/// ## Mathematical Definition
+///
+/// ```text
+/// Bₙ(x) = Σₖ₌₀ⁿ C(n, k) · Bₖ · xⁿ⁻ᵏ
+/// ```
+///
+/// with the Bernoulli numbers Bₖ of [`bernoulli`]. The first few are
+///
+/// - B₀(x) = 1
+/// - B₁(x) = x - 1/2
+/// - B₂(x) = x² - x + 1/6
+///
+/// ## Properties
+///
+/// - Bₙ(0) = Bₙ, and Bₙ(1) = Bₙ for n ≠ 1
+/// - Bₙ(x + 1) - Bₙ(x) = n·xⁿ⁻¹
+/// - B'ₙ(x) = n·Bₙ₋₁(x)
+///
+/// ## Accuracy
+///
+/// The sum alternates in sign, so for large `n` and |x| ≤ 1 the result loses
+/// roughly the digits by which the largest term exceeds it. The binomial
+/// coefficients are carried as `f64` so that no degree overflows an integer.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::bernoulli::bernoulli_poly;
+///
+/// assert_eq!(bernoulli_poly(0, 3.7), 1.0);
+/// assert_eq!(bernoulli_poly(1, 0.75), 0.25);
+///
+/// // B₂(x) = x² - x + 1/6
+/// let x = 0.3;
+/// assert!((bernoulli_poly(2, x) - (x * x - x + 1.0 / 6.0)).abs() < 1e-15);
+/// ```
+pub fn bernoulli_poly(n: u32, x: f64) -> f64 {
+    let mut sum = 0.0;
+    let mut binomial = 1.0;
+
+    for k in 0..=n {
+        let b = bernoulli(k);
+        if b != 0.0 {
+            sum += binomial * b * x.powi((n - k) as i32);
+        }
+        // C(n, k + 1) = C(n, k)·(n - k)/(k + 1); multiplying first keeps it exact
+        // while the coefficients stay below 2⁵³
+        binomial = binomial * (n - k) as f64 / (k + 1) as f64;
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bernoulli_numbers() {
+        assert_eq!(bernoulli(0), 1.0);
+        assert_eq!(bernoulli(1), -0.5);
+        assert_eq!(bernoulli(12), -691.0 / 2730.0);
+        for n in [3, 5, 21, 99] {
+            assert_eq!(bernoulli(n), 0.0);
+        }
+
+        // Computed with mpmath at 40 digits
+        let cases = [
+            (22, 6_192.123_188_405_797),
+            (30, 601_580_873.900_642_4),
+            (50, 7.500_866_746_076_964e24),
+            (100, -2.838_224_957_069_370_7e78),
+        ];
+        for (n, expected) in cases {
+            let value = bernoulli(n);
+            assert!(
+                (value - expected).abs() < 1e-14 * expected.abs(),
+                "B({n}) = {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bernoulli_matches_table_at_switch() {
+        // The ζ formula applied at n = 20 reproduces the tabulated fraction
+        let s = 20.0;
+        let zeta: f64 = (1..100).map(|k| (k as f64).powf(-s)).sum();
+        let scale: f64 = (1..=20).map(|k| k as f64 / (2.0 * PI)).product();
+        assert!((-2.0 * scale * zeta - bernoulli(20)).abs() < 1e-14 * bernoulli(20).abs());
+    }
+
+    #[test]
+    fn test_low_degree_polynomials() {
+        for x in [-1.5, 0.0, 0.3, 1.0, 2.25] {
+            assert_eq!(bernoulli_poly(0, x), 1.0);
+            assert!((bernoulli_poly(1, x) - (x - 0.5)).abs() < 1e-15);
+            assert!((bernoulli_poly(2, x) - (x * x - x + 1.0 / 6.0)).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_polynomial_at_zero_is_bernoulli_number() {
+        for n in 0..40 {
+            assert_eq!(bernoulli_poly(n, 0.0), bernoulli(n), "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_polynomial_reference_values() {
+        // Computed with mpmath at 40 digits
+        let cases = [
+            (5, 0.3, -0.02282),
+            (10, 0.7, -0.023_445_859_342_424_262),
+            (7, -1.2, -20.927_244_799_999_997),
+            (20, 0.25, 0.000_504_611_237_716_524_2),
+        ];
+        for (n, x, expected) in cases {
+            let value = bernoulli_poly(n, x);
+            assert!((value - expected).abs() < 1e-12, "B{n}({x}) = {value}");
+        }
+    }
+
+    #[test]
+    fn test_polynomial_difference_identity() {
+        // Bₙ(x + 1) - Bₙ(x) = n·xⁿ⁻¹
+        for n in 1..12 {
+            for x in [-0.8_f64, 0.1, 0.5, 1.3] {
+                let difference = bernoulli_poly(n, x + 1.0) - bernoulli_poly(n, x);
+                let expected = n as f64 * x.powi(n as i32 - 1);
+                assert!((difference - expected).abs() < 1e-11, "n = {n}, x = {x}");
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Spherical Bessel functions implementation
+//!
+//! The spherical Bessel functions jₙ(x) and yₙ(x) solve the radial part of the
+//! Helmholtz equation in spherical coordinates and appear in scattering theory and
+//! in the quantum mechanics of a particle in a spherical well.
+
+/// Below this magnitude the power series replaces the closed forms, which lose
+/// accuracy to cancellation near the origin
+const SERIES_THRESHOLD: f64 = 1.0;
+
+/// Power series xⁿ · Σ (-x²/2)ᵏ / (k!·(2n + 2k + 1)!!) for jₙ(x)
+fn series(n: u32, x: f64) -> f64 {
+    let double_factorial: f64 = (1..=n).map(|k| (2 * k + 1) as f64).product();
+    let mut term = x.powi(n as i32) / double_factorial;
+    let mut sum = term;
+    let mut k = 0.0;
+
+    while term.abs() > 1e-17 * sum.abs() {
+        term *= -0.5 * x * x / ((k + 1.0) * (2.0 * (n as f64 + k) + 3.0));
+        sum += term;
+        k += 1.0;
+    }
+
+    sum
+}
+
+/// Spherical Bessel function of the first kind j₀(x) = sin(x)/x
+///
+/// The removable singularity at the origin is handled by the power series
+/// j₀(x) = 1 - x²/6 + x⁴/120 - ..., so j₀(0) = 1.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sph_j0;
+/// use std::f64::consts::PI;
+///
+/// assert_eq!(sph_j0(0.0), 1.0);
+/// assert!(sph_j0(PI).abs() < 1e-16);
+/// ```
+pub fn sph_j0(x: f64) -> f64 {
+    if x.abs() < SERIES_THRESHOLD {
+        return series(0, x);
+    }
+    x.sin() / x
+}
+
+/// Spherical Bessel function of the first kind j₁(x) = sin(x)/x² - cos(x)/x
+///
+/// The two terms cancel as x → 0, so small arguments use the power series
+/// j₁(x) = x/3 - x³/30 + ..., which gives j₁(0) = 0 and full relative accuracy near
+/// the origin.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sph_j1;
+///
+/// assert_eq!(sph_j1(0.0), 0.0);
+/// assert!((sph_j1(1e-8) - 1e-8 / 3.0).abs() < 1e-24);
+/// ```
+pub fn sph_j1(x: f64) -> f64 {
+    if x.abs() < SERIES_THRESHOLD {
+        return series(1, x);
+    }
+    (x.sin() / x - x.cos()) / x
+}
+
+/// Spherical Bessel function of the second kind y₀(x) = -cos(x)/x
+///
+/// Singular at the origin, where it returns -∞ (or +∞ for x = -0.0).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sph_y0;
+/// use std::f64::consts::PI;
+///
+/// assert!((sph_y0(PI) - 1.0 / PI).abs() < 1e-16);
+/// ```
+pub fn sph_y0(x: f64) -> f64 {
+    -x.cos() / x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::float::approx_eq;
+    use crate::base::numbers::PI;
+
+    #[test]
+    fn test_sph_bessel_at_origin() {
+        assert_eq!(sph_j0(0.0), 1.0);
+        assert_eq!(sph_j1(0.0), 0.0);
+        assert_eq!(sph_y0(0.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_sph_j0_zeros() {
+        // The zeros of j₀ are the multiples of π
+        for k in 1..=5 {
+            assert!(sph_j0(k as f64 * PI).abs() < 1e-16);
+        }
+    }
+
+    #[test]
+    fn test_sph_j0_matches_sinc() {
+        let sinc = |x: f64| x.sin() / x;
+        for x in [0.01, 0.3, 0.999, 1.0, 2.5, 10.0, -7.3] {
+            assert!(approx_eq(sph_j0(x), sinc(x), 1e-15, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_sph_j1_reference_values() {
+        // Computed with mpmath at 30 digits
+        let cases = [
+            (1e-3, 3.333_333_000_000_012e-4),
+            (0.5, 0.162_537_030_636_066_56),
+            (1.0, 0.301_168_678_939_756_8),
+            (5.0, -0.095_089_408_079_170_8),
+        ];
+        for (x, expected) in cases {
+            assert!((sph_j1(x) - expected).abs() < 1e-15 * expected.abs().max(1e-3));
+        }
+
+        // Odd function
+        assert_eq!(sph_j1(-2.0), -sph_j1(2.0));
+    }
+
+    #[test]
+    fn test_sph_bessel_wronskian() {
+        // j₁(x)·y₀(x) - j₀(x)·y₁(x) = 1/x², with y₁(x) = -cos(x)/x² - sin(x)/x
+        for x in [0.5_f64, 1.7, 4.0, 12.0] {
+            let y1 = -x.cos() / (x * x) - x.sin() / x;
+            let wronskian = sph_j1(x) * sph_y0(x) - sph_j0(x) * y1;
+            assert!((wronskian - 1.0 / (x * x)).abs() < 1e-15 / (x * x));
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Digamma and trigamma functions
+//!
+//! The polygamma functions are the logarithmic derivatives of the gamma
+//! function: ψ(x) = Γ'(x)/Γ(x) and its derivative ψ'(x). They appear in the
+//! maximum-likelihood equations of the gamma and beta distributions and in
+//! series for harmonic numbers, Hₙ = ψ(n + 1) + γ.
+
+use crate::base::numbers::PI;
+use crate::specials::bernoulli::bernoulli;
+use crate::specials::domain::require_not_pole;
+
+/// Below this argument the recurrence shifts x upward before the asymptotic
+/// series is applied
+const ASYMPTOTIC_THRESHOLD: f64 = 10.0;
+
+/// Number of Bernoulli terms in the asymptotic series; at x ≥ 10 the first
+/// omitted term is below 10⁻¹⁷ relative
+const ASYMPTOTIC_TERMS: u32 = 7;
+
+/// Digamma function ψ(x) = d/dx ln Γ(x)
+///
+/// ## Properties
+///
+/// - ψ(1) = -γ, the negated Euler-Mascheroni constant
+/// - ψ(x + 1) = ψ(x) + 1/x
+/// - ψ(1 - x) - ψ(x) = π·cot(πx)
+///
+/// ## Implementation
+///
+/// Negative arguments are reflected to positive ones. The recurrence then
+/// raises x to at least 10, where the asymptotic series
+///
+/// ```text
+/// ψ(x) ~ ln x - 1/(2x) - Σₖ B₂ₖ / (2k·x²ᵏ)
+/// ```
+///
+/// is accurate to rounding with seven terms.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::digamma::digamma;
+///
+/// // ψ(1) = -γ
+/// assert!((digamma(1.0) + 0.577_215_664_901_532_9).abs() < 1e-15);
+/// // Hₙ = ψ(n + 1) + γ, so ψ(4) = 1 + 1/2 + 1/3 - γ
+/// assert!((digamma(4.0) - (11.0 / 6.0 - 0.577_215_664_901_532_9)).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if x is a non-positive integer, where ψ has a pole.
+pub fn digamma(x: f64) -> f64 {
+    require_not_pole(x, "digamma");
+
+    if x < 0.0 {
+        // ψ(x) = ψ(1 - x) - π·cot(πx)
+        return digamma(1.0 - x) - PI / (PI * x).tan();
+    }
+
+    let (mut x, mut shift) = (x, 0.0);
+    while x < ASYMPTOTIC_THRESHOLD {
+        shift += 1.0 / x;
+        x += 1.0;
+    }
+
+    let inv_x2 = 1.0 / (x * x);
+    let mut power = inv_x2;
+    let mut series = 0.0;
+    for k in 1..=ASYMPTOTIC_TERMS {
+        series += bernoulli(2 * k) / (2 * k) as f64 * power;
+        power *= inv_x2;
+    }
+
+    x.ln() - 0.5 / x - series - shift
+}
+
+/// Trigamma function ψ'(x) = d²/dx² ln Γ(x)
+///
+/// ## Properties
+///
+/// - ψ'(1) = π²/6
+/// - ψ'(x + 1) = ψ'(x) - 1/x²
+/// - ψ'(1 - x) + ψ'(x) = π² / sin²(πx)
+///
+/// ψ'(x) is positive everywhere, which makes ln Γ convex on x > 0.
+///
+/// ## Implementation
+///
+/// As for [`digamma`], with the asymptotic series
+///
+/// ```text
+/// ψ'(x) ~ 1/x + 1/(2x²) + Σₖ B₂ₖ / x²ᵏ⁺¹
+/// ```
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::digamma::trigamma;
+/// use std::f64::consts::PI;
+///
+/// assert!((trigamma(1.0) - PI * PI / 6.0).abs() < 1e-15);
+/// assert!((trigamma(0.5) - PI * PI / 2.0).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if x is a non-positive integer, where ψ' has a pole.
+pub fn trigamma(x: f64) -> f64 {
+    require_not_pole(x, "trigamma");
+
+    if x < 0.0 {
+        // ψ'(x) = π²/sin²(πx) - ψ'(1 - x)
+        let sin = (PI * x).sin();
+        return PI * PI / (sin * sin) - trigamma(1.0 - x);
+    }
+
+    let (mut x, mut shift) = (x, 0.0);
+    while x < ASYMPTOTIC_THRESHOLD {
+        shift += 1.0 / (x * x);
+        x += 1.0;
+    }
+
+    let inv_x = 1.0 / x;
+    let inv_x2 = inv_x * inv_x;
+    let mut power = inv_x2 * inv_x;
+    let mut series = 0.0;
+    for k in 1..=ASYMPTOTIC_TERMS {
+        series += bernoulli(2 * k) * power;
+        power *= inv_x2;
+    }
+
+    inv_x + 0.5 * inv_x2 + series + shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digamma_reference_values() {
+        // Computed with mpmath at 30 digits
+        let cases = [
+            (0.5, -1.963_510_026_021_423_5),
+            (2.5, 0.703_156_640_645_243_2),
+            (10.0, 2.251_752_589_066_721),
+            (-0.5, 0.036_489_973_978_576_52),
+            (-2.3, 3.317_323_157_561_822_7),
+            (1e-3, -1_000.575_571_931_810_3),
+        ];
+        for (x, expected) in cases {
+            let value = digamma(x);
+            assert!(
+                (value - expected).abs() < 1e-14 * expected.abs().max(1.0),
+                "ψ({x}) = {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_trigamma_reference_values() {
+        // Computed with mpmath at 30 digits
+        let cases = [
+            (0.5, 4.934_802_200_544_679),
+            (2.5, 0.490_357_756_100_234_9),
+            (10.0, 0.105_166_335_681_685_75),
+            (-0.5, 8.934_802_200_544_679),
+            (-2.3, 14.725_912_160_961_291),
+            (1e-3, 1_000_001.642_533_195_8),
+        ];
+        for (x, expected) in cases {
+            let value = trigamma(x);
+            assert!(
+                (value - expected).abs() < 1e-13 * expected,
+                "ψ'({x}) = {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_recurrences() {
+        for x in [0.3, 1.7, 4.2, 15.0, -1.6] {
+            assert!((digamma(x + 1.0) - digamma(x) - 1.0 / x).abs() < 1e-13);
+            assert!((trigamma(x) - trigamma(x + 1.0) - 1.0 / (x * x)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "digamma: argument must not be a non-positive integer, got -3")]
+    fn test_digamma_pole() {
+        digamma(-3.0);
+    }
+}
@@ -0,0 +1,342 @@
+//! Cumulative distribution functions of common continuous distributions
+//!
+//! The normal, Student's t and chi-squared distributions, expressed through the
+//! regularized incomplete gamma and beta functions of [`crate::specials::incomplete`],
+//! and the Rayleigh distribution, which has closed forms.
+
+use crate::specials::incomplete::{betai, gammp, gammq};
+
+/// Beyond |x| = 40 the normal tail e^(-x²/2) underflows to zero, so Φ(x) is
+/// exactly 0 or 1 in double precision
+const NORMAL_SATURATION: f64 = 40.0;
+
+/// Standard normal cumulative distribution function Φ(x)
+///
+/// ## Mathematical Definition
+///
+/// Φ(x) = (1/√(2π)) ∫₋∞ˣ e^(-t²/2) dt = (1 + erf(x/√2))/2
+///
+/// ## Implementation
+///
+/// Evaluated through the upper incomplete gamma function, Φ(x) = Q(1/2, x²/2)/2
+/// for x < 0 and 1 - Q(1/2, x²/2)/2 otherwise, so the lower tail keeps its full
+/// relative accuracy.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::dist::normal_cdf;
+///
+/// assert_eq!(normal_cdf(0.0), 0.5);
+/// assert!((normal_cdf(1.96) - 0.975).abs() < 1e-4);
+/// ```
+pub fn normal_cdf(x: f64) -> f64 {
+    // Clamped so that x² cannot overflow; NaN passes through
+    let x = x.clamp(-NORMAL_SATURATION, NORMAL_SATURATION);
+    let tail = 0.5 * gammq(0.5, 0.5 * x * x);
+    if x < 0.0 { tail } else { 1.0 - tail }
+}
+
+/// Cumulative distribution function of Student's t distribution with `df`
+/// degrees of freedom
+///
+/// ## Mathematical Definition
+///
+/// For t ≤ 0, F(t) = I_{df/(df + t²)}(df/2, 1/2)/2, and F(t) = 1 - F(-t) for
+/// t > 0, where I is the regularized incomplete beta function [`betai`].
+///
+/// As `df` → ∞ the distribution approaches the standard normal; `df = 1` is the
+/// Cauchy distribution.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::dist::students_t_cdf;
+///
+/// assert_eq!(students_t_cdf(0.0, 5.0), 0.5);
+///
+/// // Two-sided 95% critical value for 10 degrees of freedom
+/// assert!((students_t_cdf(2.228_138_851_986_274, 10.0) - 0.975).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `df <= 0`.
+pub fn students_t_cdf(t: f64, df: f64) -> f64 {
+    if df <= 0.0 {
+        panic!("Student's t distribution requires df > 0");
+    }
+
+    let tail = 0.5 * betai(0.5 * df, 0.5, df / (df + t * t));
+    if t > 0.0 { 1.0 - tail } else { tail }
+}
+
+/// Cumulative distribution function of the chi-squared distribution with `k`
+/// degrees of freedom
+///
+/// ## Mathematical Definition
+///
+/// F(x) = P(k/2, x/2) for x ≥ 0 and F(x) = 0 for x < 0, where P is the regularized
+/// lower incomplete gamma function [`gammp`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::dist::chi_squared_cdf;
+///
+/// assert_eq!(chi_squared_cdf(0.0, 3.0), 0.0);
+///
+/// // 95% critical value for 2 degrees of freedom
+/// assert!((chi_squared_cdf(5.991_464_547_107_98, 2.0) - 0.95).abs() < 1e-14);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `k <= 0`.
+pub fn chi_squared_cdf(x: f64, k: f64) -> f64 {
+    if k <= 0.0 {
+        panic!("Chi-squared distribution requires k > 0");
+    }
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    gammp(0.5 * k, 0.5 * x)
+}
+
+/// Checks the scale parameter shared by the Rayleigh functions
+fn check_rayleigh_sigma(sigma: f64) {
+    if sigma <= 0.0 {
+        panic!("Rayleigh distribution requires sigma > 0");
+    }
+}
+
+/// Probability density of the Rayleigh distribution with scale `sigma`
+///
+/// The Rayleigh distribution is that of the magnitude √(X² + Y²) of two
+/// independent zero-mean normal components with standard deviation σ, e.g. the
+/// envelope of a fading radio signal without a line-of-sight path.
+///
+/// ## Mathematical Definition
+///
+/// f(x) = (x/σ²)·e^(-x²/(2σ²)) for x ≥ 0 and f(x) = 0 for x < 0.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::dist::rayleigh_pdf;
+///
+/// assert_eq!(rayleigh_pdf(0.0, 1.0), 0.0);
+/// // The mode is at x = σ
+/// assert!((rayleigh_pdf(2.0, 2.0) - 0.5 * (-0.5_f64).exp()).abs() < 1e-16);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `sigma <= 0`.
+pub fn rayleigh_pdf(x: f64, sigma: f64) -> f64 {
+    check_rayleigh_sigma(sigma);
+    if x < 0.0 {
+        return 0.0;
+    }
+
+    let s2 = sigma * sigma;
+    x / s2 * (-0.5 * x * x / s2).exp()
+}
+
+/// Cumulative distribution function of the Rayleigh distribution with scale
+/// `sigma`
+///
+/// ## Mathematical Definition
+///
+/// F(x) = 1 - e^(-x²/(2σ²)) for x ≥ 0 and F(x) = 0 for x < 0.
+///
+/// Computed with `exp_m1`, so small x keep their full relative accuracy.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::dist::rayleigh_cdf;
+///
+/// assert_eq!(rayleigh_cdf(0.0, 1.5), 0.0);
+/// assert!((rayleigh_cdf(1.0, 1.0) - 0.393_469_340_287_366_6).abs() < 1e-16);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `sigma <= 0`.
+pub fn rayleigh_cdf(x: f64, sigma: f64) -> f64 {
+    check_rayleigh_sigma(sigma);
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let z = x / sigma;
+    -(-0.5 * z * z).exp_m1()
+}
+
+/// Quantile (inverse CDF) of the Rayleigh distribution with scale `sigma`
+///
+/// ## Mathematical Definition
+///
+/// Q(p) = σ·√(-2·ln(1 - p)), so the median is σ·√(2 ln 2) and Q(1) = ∞.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::dist::{rayleigh_cdf, rayleigh_quantile};
+///
+/// let median = rayleigh_quantile(0.5, 2.0);
+/// assert!((median - 2.0 * (2.0 * 2.0_f64.ln()).sqrt()).abs() < 1e-15);
+/// assert!((rayleigh_cdf(median, 2.0) - 0.5).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `sigma <= 0` or `p` lies outside [0, 1].
+pub fn rayleigh_quantile(p: f64, sigma: f64) -> f64 {
+    check_rayleigh_sigma(sigma);
+    if !(0.0..=1.0).contains(&p) {
+        panic!("Rayleigh quantile requires 0 <= p <= 1");
+    }
+
+    sigma * (-2.0 * (-p).ln_1p()).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::numbers::PI;
+
+    #[test]
+    fn test_cdfs_at_infinite_and_huge_arguments() {
+        assert_eq!(normal_cdf(f64::INFINITY), 1.0);
+        assert_eq!(normal_cdf(f64::NEG_INFINITY), 0.0);
+        assert_eq!(normal_cdf(1e200), 1.0);
+        assert_eq!(normal_cdf(-1e200), 0.0);
+        assert!(normal_cdf(f64::NAN).is_nan());
+
+        assert_eq!(chi_squared_cdf(f64::INFINITY, 2.0), 1.0);
+        assert_eq!(chi_squared_cdf(1e300, 7.0), 1.0);
+    }
+
+    #[test]
+    fn test_normal_cdf_reference_values() {
+        // Computed with mpmath at 40 digits
+        assert!((normal_cdf(1.0) - 0.841_344_746_068_542_9).abs() < 1e-15);
+        assert!((normal_cdf(-2.5) - 0.006_209_665_325_776_135).abs() < 1e-15);
+        let tail = normal_cdf(-8.0);
+        assert!((tail - 6.220_960_574_271_784e-16).abs() < 1e-14 * tail);
+        assert!((normal_cdf(0.7) + normal_cdf(-0.7) - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_students_t_at_zero_and_symmetry() {
+        for df in [0.5, 1.0, 3.0, 30.0] {
+            assert_eq!(students_t_cdf(0.0, df), 0.5);
+            let (lower, upper) = (students_t_cdf(-1.3, df), students_t_cdf(1.3, df));
+            assert!((lower + upper - 1.0).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_students_t_reference_values() {
+        // df = 1 is the Cauchy distribution
+        for t in [-4.0_f64, -0.5, 0.25, 2.0] {
+            let cauchy = 0.5 + t.atan() / PI;
+            assert!((students_t_cdf(t, 1.0) - cauchy).abs() < 1e-15);
+        }
+
+        assert!((students_t_cdf(1.5, 3.5) - 0.891_090_906_492_327_4).abs() < 1e-14);
+        assert!((students_t_cdf(2.228_138_851_986_274_4, 10.0) - 0.975).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_students_t_approaches_normal() {
+        for t in [-2.0, -0.5, 1.0, 2.5] {
+            assert!((students_t_cdf(t, 1e6) - normal_cdf(t)).abs() < 1e-6);
+        }
+        // Heavier tails for few degrees of freedom
+        assert!(students_t_cdf(-3.0, 3.0) > normal_cdf(-3.0));
+    }
+
+    #[test]
+    fn test_chi_squared_cdf() {
+        assert_eq!(chi_squared_cdf(0.0, 4.0), 0.0);
+        assert_eq!(chi_squared_cdf(-1.0, 4.0), 0.0);
+
+        // Tabulated critical values
+        assert!((chi_squared_cdf(5.991_464_547_107_98, 2.0) - 0.95).abs() < 1e-14);
+        assert!((chi_squared_cdf(15.086_272_469_388_987, 5.0) - 0.99).abs() < 1e-14);
+
+        // With two degrees of freedom the distribution is exponential
+        assert!((chi_squared_cdf(3.0, 2.0) - (1.0 - (-1.5_f64).exp())).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_rayleigh_cdf_limits() {
+        for sigma in [0.1, 1.0, 7.5] {
+            assert_eq!(rayleigh_cdf(0.0, sigma), 0.0);
+            assert_eq!(rayleigh_cdf(-1.0, sigma), 0.0);
+            assert!(1.0 - rayleigh_cdf(10.0 * sigma, sigma) < 1e-15);
+            assert_eq!(rayleigh_cdf(100.0 * sigma, sigma), 1.0);
+        }
+
+        // Small x: F(x) ≈ x²/(2σ²) to full relative accuracy
+        let x = 1e-9;
+        assert!((rayleigh_cdf(x, 1.0) / (0.5 * x * x) - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_rayleigh_median_and_round_trip() {
+        let sigma = 3.0;
+        let median = rayleigh_quantile(0.5, sigma);
+        assert!((median - sigma * (2.0 * 2.0_f64.ln()).sqrt()).abs() < 1e-14);
+
+        for p in [1e-12, 0.01, 0.25, 0.5, 0.9, 0.999_999] {
+            let x = rayleigh_quantile(p, sigma);
+            assert!((rayleigh_cdf(x, sigma) - p).abs() <= 1e-14 * p, "p = {p}");
+        }
+        assert_eq!(rayleigh_quantile(0.0, sigma), 0.0);
+        assert_eq!(rayleigh_quantile(1.0, sigma), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_rayleigh_pdf_integrates_to_cdf() {
+        // Trapezoid rule on [0, 2]
+        let (sigma, n) = (0.8, 20_000);
+        let h = 2.0 / n as f64;
+        let integral: f64 = (0..n)
+            .map(|i| {
+                0.5 * h
+                    * (rayleigh_pdf(i as f64 * h, sigma) + rayleigh_pdf((i + 1) as f64 * h, sigma))
+            })
+            .sum();
+        assert!((integral - rayleigh_cdf(2.0, sigma)).abs() < 1e-8);
+        assert_eq!(rayleigh_pdf(-0.5, sigma), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rayleigh distribution requires sigma > 0")]
+    fn test_rayleigh_invalid_sigma() {
+        rayleigh_cdf(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rayleigh quantile requires 0 <= p <= 1")]
+    fn test_rayleigh_quantile_invalid_p() {
+        rayleigh_quantile(1.5, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Student's t distribution requires df > 0")]
+    fn test_students_t_invalid_df() {
+        students_t_cdf(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Chi-squared distribution requires k > 0")]
+    fn test_chi_squared_invalid_k() {
+        chi_squared_cdf(1.0, -2.0);
+    }
+}
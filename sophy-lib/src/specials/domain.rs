@@ -0,0 +1,173 @@
+//! Domain validation shared by the special functions
+//!
+//! Each validator panics with a message of the same shape,
+//!
+//! ```text
+//! <function>: argument must be <condition>, got <value>
+//! ```
+//!
+//! so that out-of-domain calls read uniformly whichever function rejected them.
+//! The validators are `#[track_caller]`, so the reported panic location is the
+//! call site inside the special function rather than this module.
+//!
+//! NaN arguments are let through, as the special functions propagate NaN rather
+//! than panicking on it; [`crate::specials::safe`] rejects NaN separately.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use sophy::specials::domain::require_gt;
+//!
+//! let result = std::panic::catch_unwind(|| require_gt(0.5, 1.0, "zeta"));
+//! let message = *result.unwrap_err().downcast::<String>().unwrap();
+//! assert_eq!(message, "zeta: argument must be greater than 1, got 0.5");
+//! ```
+
+/// Panics unless `x > 0`
+///
+/// ## Panics
+///
+/// Panics with `"<name>: argument must be positive, got <x>"` if x ≤ 0.
+#[track_caller]
+pub fn require_positive(x: f64, name: &str) {
+    if x <= 0.0 {
+        panic!("{name}: argument must be positive, got {x}");
+    }
+}
+
+/// Panics unless `x > bound`
+///
+/// ## Panics
+///
+/// Panics with `"<name>: argument must be greater than <bound>, got <x>"` if
+/// x ≤ bound.
+#[track_caller]
+pub fn require_gt(x: f64, bound: f64, name: &str) {
+    if x <= bound {
+        panic!("{name}: argument must be greater than {bound}, got {x}");
+    }
+}
+
+/// Panics if `x` is a pole of the gamma family, a non-positive integer
+///
+/// ## Panics
+///
+/// Panics with `"<name>: argument must not be a non-positive integer, got <x>"`
+/// if x ∈ {0, -1, -2, ...}.
+#[track_caller]
+pub fn require_not_pole(x: f64, name: &str) {
+    if x <= 0.0 && x.fract() == 0.0 {
+        panic!("{name}: argument must not be a non-positive integer, got {x}");
+    }
+}
+
+/// Panics if `x` is an odd positive integer, a pole of the zeta
+/// functional-equation factor χ
+///
+/// ## Panics
+///
+/// Panics with `"<name>: argument must not be an odd positive integer, got <x>"`
+/// if x ∈ {1, 3, 5, ...}.
+#[track_caller]
+pub fn require_not_odd_positive(x: f64, name: &str) {
+    if x > 0.0 && x % 2.0 == 1.0 {
+        panic!("{name}: argument must not be an odd positive integer, got {x}");
+    }
+}
+
+/// Panics unless the integer argument `n` is non-zero
+///
+/// ## Panics
+///
+/// Panics with `"<name>: argument must be a positive integer, got 0"` if n = 0.
+#[track_caller]
+pub fn require_nonzero(n: u64, name: &str) {
+    if n == 0 {
+        panic!("{name}: argument must be a positive integer, got {n}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specials::{divisors, eta, gamma, sigma, sigma_k, sigma_k_checked, zeta};
+    use std::panic::{self, UnwindSafe};
+
+    /// The panic message of `f`, which must panic with a formatted message
+    fn panic_message<F: FnOnce() -> R + UnwindSafe, R: std::fmt::Debug>(f: F) -> String {
+        let payload = panic::catch_unwind(f).expect_err("function did not panic");
+        *payload.downcast::<String>().unwrap()
+    }
+
+    #[test]
+    fn test_validators_accept_domain_and_nan() {
+        require_positive(1e-300, "gamma");
+        require_gt(1.000_001, 1.0, "zeta");
+        require_nonzero(1, "sigma");
+        require_positive(f64::NAN, "gamma");
+        require_gt(f64::NAN, 1.0, "zeta");
+    }
+
+    #[test]
+    fn test_consistent_messages_across_functions() {
+        assert_eq!(
+            panic_message(|| gamma(-1.0)),
+            "gamma: argument must be positive, got -1"
+        );
+        assert_eq!(
+            panic_message(|| gamma(0.0)),
+            "gamma: argument must be positive, got 0"
+        );
+        assert_eq!(
+            panic_message(|| eta(-0.5)),
+            "eta: argument must be positive, got -0.5"
+        );
+        assert_eq!(
+            panic_message(|| zeta(0.5)),
+            "zeta: argument must be greater than 1, got 0.5"
+        );
+        assert_eq!(
+            panic_message(|| sigma(0)),
+            "sigma: argument must be a positive integer, got 0"
+        );
+        assert_eq!(
+            panic_message(|| sigma_k(0, 2)),
+            "sigma_k: argument must be a positive integer, got 0"
+        );
+        assert_eq!(
+            panic_message(|| sigma_k_checked(0, 2)),
+            "sigma_k_checked: argument must be a positive integer, got 0"
+        );
+        assert_eq!(
+            panic_message(|| divisors(0)),
+            "divisors: argument must be a positive integer, got 0"
+        );
+    }
+
+    #[test]
+    fn test_require_not_pole() {
+        require_not_pole(-0.5, "digamma");
+        require_not_pole(3.0, "digamma");
+        assert_eq!(
+            panic_message(|| require_not_pole(-2.0, "digamma")),
+            "digamma: argument must not be a non-positive integer, got -2"
+        );
+    }
+
+    #[test]
+    fn test_require_not_odd_positive() {
+        require_not_odd_positive(2.0, "chi");
+        require_not_odd_positive(-1.0, "chi");
+        require_not_odd_positive(1.5, "chi");
+        assert_eq!(
+            panic_message(|| require_not_odd_positive(5.0, "chi")),
+            "chi: argument must not be an odd positive integer, got 5"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lgamma: argument must be greater than -1, got -2")]
+    fn test_require_gt_message() {
+        require_gt(-2.0, -1.0, "lgamma");
+    }
+}
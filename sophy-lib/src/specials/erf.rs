@@ -3,6 +3,9 @@
 //! The error function is a special function that appears frequently in
 //! probability theory, statistics, and partial differential equations.
 
+use crate::base::numbers::PI;
+use crate::specials::incomplete::{gammp, gammq};
+
 /// Error function erf(x) - fundamental in probability and statistics
 ///
 /// The error function is defined as:
@@ -24,7 +27,9 @@
 ///
 /// ## Implementation
 ///
-/// Uses Abramowitz and Stegun approximation with maximum error of 1.5 × 10^(-7).
+/// Evaluated as P(1/2, x²) through the regularized incomplete gamma function,
+/// accurate to a few units in the last place. See [`erf_config`] for the faster
+/// Abramowitz and Stegun approximation and the plain Maclaurin series.
 ///
 /// ## Examples
 ///
@@ -43,6 +48,156 @@
 /// assert!(erf(1.0) < erf(2.0));
 /// ```
 pub fn erf(x: f64) -> f64 {
+    erf_config(x, ErfMethod::Accurate)
+}
+
+/// Evaluation strategy for [`erf_config`], trading accuracy against cost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErfMethod {
+    /// Abramowitz and Stegun rational approximation, absolute error below 1.5 × 10⁻⁷
+    Fast,
+    /// Series for small |x| and continued fraction for large |x|, accurate to a few
+    /// units in the last place
+    Accurate,
+    /// The first `n` terms of the Maclaurin series; suitable for moderate |x| only,
+    /// since the alternating terms grow like e^(x²) before they decay
+    Series(usize),
+}
+
+/// Error function erf(x) with an explicit choice of evaluation method
+///
+/// ## Methods
+///
+/// - [`ErfMethod::Fast`]: a fixed five-term rational approximation (A&S 7.1.26)
+/// - [`ErfMethod::Accurate`]: erf(x) = P(1/2, x²) through the regularized
+///   incomplete gamma function [`gammp`], which sums a
+///   positive power series for x² < 3/2 and a continued fraction beyond
+/// - [`ErfMethod::Series`]: the truncated Maclaurin series
+///   erf(x) = (2/√π) Σ (-1)ⁿ x²ⁿ⁺¹ / (n!·(2n + 1))
+///
+/// [`erf`] uses [`ErfMethod::Accurate`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::erf::{erf_config, ErfMethod};
+///
+/// let exact = 0.842_700_792_949_714_9; // erf(1)
+/// assert!((erf_config(1.0, ErfMethod::Fast) - exact).abs() < 1.5e-7);
+/// assert!((erf_config(1.0, ErfMethod::Accurate) - exact).abs() < 1e-15);
+/// assert!((erf_config(1.0, ErfMethod::Series(20)) - exact).abs() < 1e-15);
+/// ```
+pub fn erf_config(x: f64, method: ErfMethod) -> f64 {
+    match method {
+        ErfMethod::Fast => erf_fast(x),
+        ErfMethod::Accurate => erf_accurate(x),
+        ErfMethod::Series(terms) => erf_series(x, terms),
+    }
+}
+
+/// erf(x) = sign(x)·P(1/2, x²)
+fn erf_accurate(x: f64) -> f64 {
+    if x.is_nan() || x == 0.0 {
+        return x;
+    }
+    // erfc(6) ≈ 2e-17 is below half an ulp of 1; this also keeps x² finite
+    if x.abs() > 6.0 {
+        return x.signum();
+    }
+
+    x.signum() * gammp(0.5, x * x)
+}
+
+/// Below this argument [`erfcx`] scales erfc directly; above it the continued
+/// fraction converges in at most about 60 terms
+const ERFCX_FRACTION_THRESHOLD: f64 = 2.0;
+
+/// Term limit for the continued fraction of [`erfcx`]
+const ERFCX_MAX_TERMS: usize = 200;
+
+/// Scaled complementary error function erfcx(x) = e^(x²)·erfc(x)
+///
+/// For large positive x, erfc(x) underflows (below 10⁻³⁰⁸ from x ≈ 26.5) while
+/// e^(x²) overflows, but their product stays moderate, decaying like 1/(x√π).
+/// Working with erfcx keeps deep Gaussian tails, such as ratios of normal tail
+/// probabilities, representable.
+///
+/// ## Implementation
+///
+/// - x < 2: e^(x²)·erfc(x), with erfc(x) = Q(1/2, x²) from [`gammq`], where
+///   neither factor can overflow
+/// - x ≥ 2: the continued fraction
+///   erfcx(x) = (1/√π)·1/(x + (1/2)/(x + 1/(x + (3/2)/(x + ...)))),
+///   evaluated by the modified Lentz method without forming e^(x²)
+/// - x < 0: the reflection erfcx(x) = 2e^(x²) - erfcx(-x), which overflows to
+///   infinity below x ≈ -26.6
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::erf::erfcx;
+///
+/// assert_eq!(erfcx(0.0), 1.0);
+///
+/// // erfc(30) ≈ 2.6e-393 underflows, erfcx does not
+/// let x = 30.0;
+/// assert!((erfcx(x) * x * std::f64::consts::PI.sqrt() - 1.0).abs() < 1e-3);
+/// ```
+pub fn erfcx(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x == f64::INFINITY {
+        return 0.0;
+    }
+    if x < 0.0 {
+        return 2.0 * (x * x).exp() - erfcx(-x);
+    }
+    if x < ERFCX_FRACTION_THRESHOLD {
+        return (x * x).exp() * gammq(0.5, x * x);
+    }
+
+    // Modified Lentz evaluation of x + (1/2)/(x + (2/2)/(x + (3/2)/(x + ...)))
+    const TINY: f64 = 1e-300;
+    let mut value = x;
+    let (mut c, mut d) = (x, 0.0);
+    for k in 1..ERFCX_MAX_TERMS {
+        let a = 0.5 * k as f64;
+        d = x + a * d;
+        if d == 0.0 {
+            d = TINY;
+        }
+        c = x + a / c;
+        if c == 0.0 {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = c * d;
+        value *= delta;
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+
+    1.0 / (PI.sqrt() * value)
+}
+
+/// Sum of the first `terms` terms of the Maclaurin series
+fn erf_series(x: f64, terms: usize) -> f64 {
+    let x2 = x * x;
+    let mut power = x;
+    let mut sum = 0.0;
+
+    for n in 0..terms {
+        sum += power / (2 * n + 1) as f64;
+        power *= -x2 / (n + 1) as f64;
+    }
+
+    2.0 / PI.sqrt() * sum
+}
+
+/// Abramowitz and Stegun 7.1.26, with maximum error 1.5 × 10⁻⁷
+fn erf_fast(x: f64) -> f64 {
     // erf(0) = 0
     if x == 0.0 {
         return 0.0;
@@ -50,7 +205,7 @@ pub fn erf(x: f64) -> f64 {
 
     // Use odd function property: erf(-x) = -erf(x)
     if x < 0.0 {
-        return -erf(-x);
+        return -erf_fast(-x);
     }
 
     // For large x, erf(x) approaches 1
@@ -113,4 +268,96 @@ mod tests {
         assert!((erf(1.0) - 0.8427).abs() < 1e-3);
         assert!((erf(2.0) - 0.9953).abs() < 1e-3);
     }
+
+    #[test]
+    fn test_erf_methods_accuracy() {
+        // Computed with mpmath at 30 digits
+        let cases = [
+            (0.1, 0.112_462_916_018_284_9),
+            (0.5, 0.520_499_877_813_046_5),
+            (1.0, 0.842_700_792_949_714_9),
+            (2.0, 0.995_322_265_018_952_7),
+            (3.5, 0.999_999_256_901_627_6),
+        ];
+
+        for (x, expected) in cases {
+            assert!((erf_config(x, ErfMethod::Fast) - expected).abs() < 1.5e-7);
+            assert!((erf_config(x, ErfMethod::Accurate) - expected).abs() < 1e-15);
+            assert!((erf_config(-x, ErfMethod::Accurate) + expected).abs() < 1e-15);
+            assert_eq!(erf(x), erf_config(x, ErfMethod::Accurate));
+        }
+    }
+
+    #[test]
+    fn test_erf_series_converges() {
+        for x in [0.1, 0.5, 1.0, 2.0] {
+            let accurate = erf_config(x, ErfMethod::Accurate);
+            assert!((erf_config(x, ErfMethod::Series(50)) - accurate).abs() < 1e-14);
+        }
+
+        // Too few terms for the argument leave a visible truncation error
+        let truncated = erf_config(2.0, ErfMethod::Series(5));
+        assert!((truncated - erf(2.0)).abs() > 1e-2);
+        assert_eq!(erf_config(1.0, ErfMethod::Series(0)), 0.0);
+    }
+
+    #[test]
+    fn test_erfcx_known_values() {
+        assert_eq!(erfcx(0.0), 1.0);
+
+        // Reference values of e^(x²)·erfc(x)
+        let cases = [
+            (0.5, 0.615_690_344_192_925_9),
+            (1.0, 0.427_583_576_155_807),
+            (2.0, 0.255_395_676_310_505_7),
+            (5.0, 0.110_704_637_733_069_2),
+            (-1.0, 5.008_980_080_762_283),
+        ];
+        for (x, expected) in cases {
+            assert!((erfcx(x) - expected).abs() < 1e-14 * expected, "x = {x}");
+        }
+
+        // Continuous across the switch to the continued fraction
+        let below = erfcx(ERFCX_FRACTION_THRESHOLD - 1e-12);
+        let above = erfcx(ERFCX_FRACTION_THRESHOLD);
+        assert!((below - above).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_erfcx_asymptotic() {
+        // erfcx(x) ~ (1/(x√π))·(1 - 1/(2x²) + 3/(4x⁴) - 15/(8x⁶))
+        for x in [30.0, 100.0, 1e4, 1e10, 1e200] {
+            let leading = 1.0 / (x * PI.sqrt());
+            let series = leading * (1.0 - 0.5 / (x * x) + 0.75 / x.powi(4) - 1.875 / x.powi(6));
+            assert!((erfcx(x) - series).abs() < 2e-11 * leading, "x = {x}");
+        }
+        assert_eq!(erfcx(f64::INFINITY), 0.0);
+        assert_eq!(erfcx(-30.0), f64::INFINITY);
+        assert!(erfcx(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erfcx_reconstructs_erfc() {
+        for x in [0.1, 0.8, 1.7, 2.5, 4.0, 8.0, 20.0] {
+            let erfc = gammq(0.5, x * x);
+            let reconstructed = erfcx(x) * (-x * x).exp();
+            assert!((reconstructed - erfc).abs() < 1e-13 * erfc, "x = {x}");
+        }
+        for x in [-0.5, -2.0] {
+            let reconstructed = erfcx(x) * (-x * x).exp();
+            assert!((reconstructed - (1.0 - erf(x))).abs() < 1e-14, "x = {x}");
+        }
+    }
+
+    #[test]
+    fn test_erf_accurate_extremes() {
+        assert_eq!(erf(10.0), 1.0);
+        assert_eq!(erf(-1e300), -1.0);
+        assert!(erf(f64::NAN).is_nan());
+        assert!(erf(-0.0).is_sign_negative());
+
+        // Small arguments keep full relative accuracy: erf(x) ≈ 2x/√π
+        let x = 1e-10;
+        assert!((erf(x) - 2.0 * x / PI.sqrt()).abs() < 1e-15 * x);
+    }
 }
@@ -3,7 +3,7 @@
 //! The Dirichlet eta function η(s) is related to the Riemann zeta function
 //! and appears in analytic number theory and mathematical analysis.
 
-use crate::specials::zeta::zeta;
+use crate::specials::domain::require_positive;
 
 /// Dirichlet eta function η(s) - alternating series variant of zeta function
 ///
@@ -27,8 +27,9 @@ use crate::specials::zeta::zeta;
 /// ## Implementation
 ///
 /// - For s = 1: returns ln(2) exactly
-/// - For s > 1: uses relationship with zeta function
-/// - For 0 < s ≤ 1: uses direct alternating series
+/// - Otherwise: sums the alternating series with the Cohen-Rodriguez
+///   Villegas-Zagier acceleration, which reaches machine precision in 24 terms
+///   for every s > 0, including the slowly converging range 0 < s < 1
 ///
 /// ## Examples
 ///
@@ -49,41 +50,52 @@ use crate::specials::zeta::zeta;
 ///
 /// Panics if s ≤ 0, as the implementation is not defined for non-positive values.
 pub fn eta(s: f64) -> f64 {
-    if s <= 0.0 {
-        panic!("Eta function implementation requires s > 0");
-    }
+    require_positive(s, "eta");
 
     // Special case: η(1) = ln(2)
     if (s - 1.0).abs() < 1e-15 {
         return 2.0_f64.ln();
     }
 
-    // For s != 1, use relation: η(s) = (1 - 2^(1-s)) * ζ(s)
-    if s > 1.0 {
-        let factor = 1.0 - 2.0_f64.powf(1.0 - s);
-        return factor * zeta(s);
-    }
+    alternating_series(|k| 1.0 / ((k + 1) as f64).powf(s))
+}
 
-    // Direct series calculation for 0 < s <= 1
+/// Number of terms used by [`alternating_series`]; the relative error is
+/// about 5.8⁻ⁿ, below machine precision for n = 24
+const ALTERNATING_TERMS: usize = 24;
+
+/// Sums Σ (-1)ᵏ·aₖ for k ≥ 0 with the Cohen-Rodriguez Villegas-Zagier acceleration
+///
+/// The partial sums are weighted by the coefficients of a shifted Chebyshev
+/// polynomial, so the error decays like (3 + √8)⁻ⁿ for completely monotone
+/// sequences such as aₖ = (k + 1)⁻ˢ, however slowly the plain series converges.
+fn alternating_series<A>(a: A) -> f64
+where
+    A: Fn(usize) -> f64,
+{
+    let n = ALTERNATING_TERMS as f64;
+    let d = (3.0 + 8.0_f64.sqrt()).powf(n);
+    let d = 0.5 * (d + 1.0 / d);
+
+    let mut b = -1.0;
+    let mut c = -d;
     let mut sum = 0.0;
-    let mut sign = 1.0;
-    let tolerance = 1e-15;
 
-    for n in 1..=1000000 {
-        let term = sign / (n as f64).powf(s);
-        if term.abs() < tolerance {
-            break;
-        }
-        sum += term;
-        sign *= -1.0;
+    for k in 0..ALTERNATING_TERMS {
+        let kf = k as f64;
+        c = b - c;
+        sum += c * a(k);
+        b *= (kf + n) * (kf - n) / ((kf + 0.5) * (kf + 1.0));
     }
 
-    sum
+    sum / d
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::base::numbers::PI;
+    use crate::specials::zeta;
 
     #[test]
     fn test_eta_one() {
@@ -124,13 +136,46 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Eta function implementation requires s > 0")]
+    fn test_eta_critical_strip() {
+        // Computed with mpmath at 30 digits
+        let cases = [
+            (0.1, 0.522_270_282_464_570_5),
+            (0.2, 0.543_909_624_270_302_9),
+            (0.5, 0.604_898_643_421_630_4),
+            (0.75, 0.651_115_679_964_928_3),
+            (0.9, 0.676_831_935_284_540_6),
+            (0.999, 0.692_987_278_968_338_3),
+        ];
+
+        for (s, expected) in cases {
+            assert!((eta(s) - expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_eta_matches_zeta_relation() {
+        // η(s) = (1 - 2^(1-s))·ζ(s), limited by the accuracy of zeta
+        for s in [1.5, 2.0, 3.0, 5.0] {
+            let relation = (1.0 - 2.0_f64.powf(1.0 - s)) * zeta(s);
+            assert!((eta(s) - relation).abs() < 1e-3);
+        }
+        assert!((eta(2.0) - PI * PI / 12.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_alternating_series_log2() {
+        let ln2 = alternating_series(|k| 1.0 / (k + 1) as f64);
+        assert!((ln2 - 2.0_f64.ln()).abs() < 1e-15);
+    }
+
+    #[test]
+    #[should_panic(expected = "eta: argument must be positive, got")]
     fn test_eta_zero() {
         eta(0.0);
     }
 
     #[test]
-    #[should_panic(expected = "Eta function implementation requires s > 0")]
+    #[should_panic(expected = "eta: argument must be positive, got")]
     fn test_eta_negative() {
         eta(-1.0);
     }
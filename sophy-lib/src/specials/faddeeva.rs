@@ -0,0 +1,262 @@
+//! Faddeeva function implementation
+//!
+//! The Faddeeva function w(z) is the scaled complex complementary error function.
+//! It is the basis for evaluating the error function family anywhere in the
+//! complex plane and appears directly as the plasma dispersion function and in
+//! the Voigt line profile of spectroscopy.
+
+use crate::base::complex::Complex64;
+use crate::base::numbers::PI;
+use std::sync::OnceLock;
+
+/// Number of terms in Weideman's rational expansion
+const N: usize = 40;
+
+/// Faddeeva function w(z) = e^(-z²)·erfc(-iz)
+///
+/// ## Mathematical Definition
+///
+/// ```text
+/// w(z) = e^(-z²)·erfc(-iz) = (i/π) ∫ e^(-t²)/(z - t) dt   (Im z > 0)
+/// ```
+///
+/// ## Properties
+///
+/// - w(0) = 1
+/// - w(-z) = 2e^(-z²) - w(z)
+/// - For real x: w(x) = e^(-x²) + (2i/√π)·D(x), with D Dawson's integral
+///
+/// ## Implementation
+///
+/// Uses Weideman's algorithm (SIAM J. Numer. Anal. 31, 1994): in the upper
+/// half-plane w is expanded in powers of the Möbius variable Z = (L + iz)/(L - iz),
+///
+/// ```text
+/// w(z) = 2·p(Z)/(L - iz)² + 1/(√π·(L - iz))
+/// ```
+///
+/// where the 40 coefficients of the polynomial p are computed once from a
+/// discrete Fourier transform and cached. The lower half-plane follows from the
+/// reflection formula. Relative accuracy is about 1e-14 over the plane, except
+/// where the reflection formula makes w itself overflow (large |Im z| with Im z < 0).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::specials::faddeeva;
+///
+/// assert!((faddeeva(Complex64::ZERO) - Complex64::ONE).norm() < 1e-15);
+///
+/// // On the imaginary axis w is real: w(2i) = e⁴·erfc(2)
+/// let w = faddeeva(Complex64::new(0.0, 2.0));
+/// assert!((w.re - 0.255_395_676_310_505_7).abs() < 1e-13);
+/// assert!(w.im.abs() < 1e-15);
+/// ```
+pub fn faddeeva(z: Complex64) -> Complex64 {
+    if z.im < 0.0 {
+        // w(z) = 2e^(-z²) - w(-z), with -z in the upper half-plane
+        return Complex64::new(2.0, 0.0) * (-(z * z)).exp() - faddeeva(-z);
+    }
+
+    let (l, coeffs) = weideman_coefficients();
+    let l = Complex64::new(*l, 0.0);
+    let iz = Complex64::I * z;
+    let denom = l - iz;
+    let big_z = (l + iz) / denom;
+
+    let p = coeffs.iter().rev().fold(Complex64::ZERO, |acc, &c| {
+        acc * big_z + Complex64::new(c, 0.0)
+    });
+
+    Complex64::new(2.0, 0.0) * p / (denom * denom) + Complex64::new(1.0 / PI.sqrt(), 0.0) / denom
+}
+
+/// Complex complementary error function erfc(z) = 1 - erf(z)
+///
+/// Computed as erfc(z) = e^(-z²)·w(iz) for Re z ≥ 0 and through
+/// erfc(z) = 2 - erfc(-z) otherwise, which avoids evaluating w where it overflows.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::specials::erfc_complex;
+///
+/// let value = erfc_complex(Complex64::new(1.0, 1.0));
+/// assert!((value - Complex64::new(-0.316_151_281_697_947_6, -0.190_453_469_237_834_7)).norm() < 1e-13);
+/// ```
+pub fn erfc_complex(z: Complex64) -> Complex64 {
+    if z.re < 0.0 {
+        return Complex64::new(2.0, 0.0) - erfc_complex(-z);
+    }
+
+    (-(z * z)).exp() * faddeeva(Complex64::I * z)
+}
+
+/// Complex error function erf(z)
+///
+/// ## Mathematical Definition
+///
+/// erf(z) = (2/√π) ∫₀^z e^(-t²) dt, the analytic continuation of the real error function.
+///
+/// ## Implementation
+///
+/// For |z| < 0.5 the Maclaurin series is summed directly, keeping full relative
+/// accuracy near the origin; elsewhere erf(z) = 1 - erfc(z) using [`erfc_complex`].
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::specials::erf_complex;
+///
+/// // erf is odd
+/// let z = Complex64::new(0.8, -0.3);
+/// assert!((erf_complex(-z) + erf_complex(z)).norm() < 1e-15);
+///
+/// // Agrees with the real error function on the real axis
+/// let real = erf_complex(Complex64::new(1.0, 0.0));
+/// assert!((real.re - 0.842_700_792_949_714_9).abs() < 1e-14);
+/// ```
+pub fn erf_complex(z: Complex64) -> Complex64 {
+    if z.norm() < 0.5 {
+        // erf(z) = (2/√π) Σ (-1)ⁿ z^(2n+1) / (n!(2n+1))
+        let z2 = z * z;
+        let mut term = z;
+        let mut sum = z;
+        for n in 1..30 {
+            term = -(term * z2) / Complex64::new(n as f64, 0.0);
+            let contribution = term / Complex64::new((2 * n + 1) as f64, 0.0);
+            sum += contribution;
+            if contribution.norm() < 1e-17 * sum.norm() {
+                break;
+            }
+        }
+        return Complex64::new(2.0 / PI.sqrt(), 0.0) * sum;
+    }
+
+    Complex64::ONE - erfc_complex(z)
+}
+
+/// Scale parameter L and polynomial coefficients of Weideman's expansion
+fn weideman_coefficients() -> &'static (f64, Vec<f64>) {
+    static COEFFS: OnceLock<(f64, Vec<f64>)> = OnceLock::new();
+
+    COEFFS.get_or_init(|| {
+        let m = 2 * N;
+        let l = (N as f64 / 2.0_f64.sqrt()).sqrt();
+
+        // Samples of e^(-t²)(L² + t²) on t = L·tan(θ/2), θ = kπ/M
+        let samples: Vec<(f64, f64)> = (1 - m as i64..m as i64)
+            .map(|k| {
+                let theta = k as f64 * PI / m as f64;
+                let t = l * (theta / 2.0).tan();
+                (k as f64, (-t * t).exp() * (l * l + t * t))
+            })
+            .collect();
+
+        // The samples are even in k, so the DFT reduces to a cosine sum
+        let coeffs = (1..=N)
+            .map(|n| {
+                samples
+                    .iter()
+                    .map(|&(k, f)| f * (PI * k * n as f64 / m as f64).cos())
+                    .sum::<f64>()
+                    / (2 * m) as f64
+            })
+            .collect();
+
+        (l, coeffs)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::specials::erf;
+
+    fn assert_close(actual: Complex64, expected: Complex64, tol: f64) {
+        assert!(
+            (actual - expected).norm() <= tol * expected.norm(),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_faddeeva_origin() {
+        assert_close(faddeeva(Complex64::ZERO), Complex64::ONE, 1e-15);
+    }
+
+    #[test]
+    fn test_faddeeva_reference_values() {
+        // Reference values computed with mpmath at 30 digits
+        let cases = [
+            (
+                Complex64::new(1.0, 1.0),
+                Complex64::new(0.304_744_205_256_912_6, 0.208_218_938_202_831_6),
+            ),
+            (
+                Complex64::new(0.5, 2.0),
+                Complex64::new(0.245_275_990_226_358_5, 0.051_521_478_343_635_85),
+            ),
+            (
+                Complex64::new(3.0, -1.0),
+                Complex64::new(-0.064_673_574_793_859_69, 0.173_730_848_501_744),
+            ),
+            (
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.367_879_441_171_442_3, 0.607_157_705_841_393_7),
+            ),
+        ];
+
+        for (z, expected) in cases {
+            assert_close(faddeeva(z), expected, 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_faddeeva_reflection() {
+        let z = Complex64::new(0.7, 1.3);
+        let lhs = faddeeva(-z);
+        let rhs = Complex64::new(2.0, 0.0) * (-(z * z)).exp() - faddeeva(z);
+        assert_close(lhs, rhs, 1e-14);
+    }
+
+    #[test]
+    fn test_erf_complex_matches_real_erf() {
+        // Both are accurate to near machine precision on the real axis
+        for &x in &[-3.0, -1.2, -0.3, 0.1, 0.45, 0.9, 2.0, 4.0] {
+            let value = erf_complex(Complex64::new(x, 0.0));
+            assert!((value.re - erf(x)).abs() < 1e-14);
+            assert!(value.im.abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_erf_complex_reference_values() {
+        assert_close(
+            erf_complex(Complex64::new(1.0, 1.0)),
+            Complex64::new(1.316_151_281_697_947_6, 0.190_453_469_237_834_7),
+            1e-13,
+        );
+        assert_close(
+            erf_complex(Complex64::new(-1.0, 2.0)),
+            Complex64::new(0.536_643_565_778_565, -5.049_143_703_447_035),
+            1e-13,
+        );
+        assert_close(
+            erfc_complex(Complex64::new(0.5, -0.5)),
+            Complex64::new(0.357_387_085_145_179_5, 0.457_881_394_435_192_2),
+            1e-13,
+        );
+    }
+
+    #[test]
+    fn test_erf_complex_small_argument() {
+        // erf(z) ≈ 2z/√π near the origin, with full relative accuracy
+        let z = Complex64::new(1e-10, -2e-10);
+        let expected = Complex64::new(2.0 / PI.sqrt(), 0.0) * z;
+        assert_close(erf_complex(z), expected, 1e-15);
+    }
+}
@@ -3,7 +3,31 @@
 //! The gamma function Γ(x) extends the factorial function to real and complex numbers.
 //! For positive integers n, Γ(n) = (n-1)!
 
+use crate::base::complex::Complex64;
 use crate::base::numbers::PI;
+use crate::methods::raphson::raphson;
+use crate::specials::bernoulli::bernoulli;
+use crate::specials::digamma::{digamma, trigamma};
+use crate::specials::domain::{require_not_pole, require_positive};
+use crate::specials::lgamma::lgamma_sign;
+use std::sync::OnceLock;
+
+/// Parameter g of the Lanczos approximation shared by [`gamma`],
+/// [`gamma_complex`] and [`lgamma_sign`]
+pub(crate) const LANCZOS_G: f64 = 7.0;
+
+/// Lanczos coefficients for g = 7 and nine terms
+pub(crate) const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
 
 /// Gamma function Γ(x) - generalization of factorial to real numbers
 ///
@@ -43,9 +67,7 @@ use crate::base::numbers::PI;
 ///
 /// Panics if x ≤ 0, as gamma function is undefined for non-positive values.
 pub fn gamma(x: f64) -> f64 {
-    if x <= 0.0 {
-        panic!("Gamma function undefined for non-positive values");
-    }
+    require_positive(x, "gamma");
 
     // Use recurrence relation to shift x into range [1, 2)
     if x < 1.0 {
@@ -58,34 +80,371 @@ pub fn gamma(x: f64) -> f64 {
     }
 
     // Lanczos approximation for x in [1, 2)
-    const G: f64 = 7.0;
-    const COEFFICIENTS: [f64; 9] = [
-        0.999_999_999_999_809_9,
-        676.520_368_121_885_1,
-        -1_259.139_216_722_402_8,
-        771.323_428_777_653_1,
-        -176.615_029_162_140_6,
-        12.507_343_278_686_905,
-        -0.138_571_095_265_720_12,
-        9.984_369_578_019_572e-6,
-        1.505_632_735_149_311_6e-7,
-    ];
-
     let z = x - 1.0;
-    let mut a = COEFFICIENTS[0];
+    let mut a = LANCZOS_COEFFICIENTS[0];
 
-    for (i, &coeff) in COEFFICIENTS.iter().enumerate().skip(1) {
+    for (i, &coeff) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
         a += coeff / (z + i as f64);
     }
 
-    let t = z + G + 0.5;
+    let t = z + LANCZOS_G + 0.5;
     (2.0 * PI).sqrt() * t.powf(z + 0.5) * (-t).exp() * a
 }
 
+/// Gamma function Γ(z) of a complex argument
+///
+/// ## Implementation
+///
+/// For Re(z) ≥ 1/2 the Lanczos approximation of [`gamma`] is evaluated directly
+/// in complex arithmetic,
+///
+/// ```text
+/// Γ(z) = √(2π) · t^(z - 1/2) · e^(-t) · A(z - 1),   t = z + g - 1/2
+/// ```
+///
+/// with the power formed as exp((z - 1/2)·ln t), so that no intermediate
+/// overflows before Γ itself does. For Re(z) < 1/2 the reflection formula
+/// Γ(z) = π / (sin(πz)·Γ(1 - z)) is used. Away from the poles the relative
+/// error is about 10⁻¹⁴ for moderate |z|, growing in proportion to |z·ln z| as
+/// the exponent loses absolute accuracy.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::complex::Complex64;
+/// use sophy::specials::gamma::gamma_complex;
+///
+/// // Γ(1 + i)
+/// let g = gamma_complex(Complex64::new(1.0, 1.0));
+/// assert!((g - Complex64::new(0.498_015_668_118_356, -0.154_949_828_301_810_7)).norm() < 1e-14);
+///
+/// // Real arguments agree with the real gamma function
+/// let g = gamma_complex(Complex64::new(4.5, 0.0));
+/// assert!((g.re - sophy::specials::gamma(4.5)).abs() < 1e-13);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if z is a non-positive integer, where Γ has a pole.
+pub fn gamma_complex(z: Complex64) -> Complex64 {
+    if z.im == 0.0 {
+        require_not_pole(z.re, "gamma_complex");
+    }
+
+    if z.re < 0.5 {
+        let pi = Complex64::new(PI, 0.0);
+        return pi / ((pi * z).sin() * gamma_complex(Complex64::ONE - z));
+    }
+
+    let z = z - Complex64::ONE;
+    let mut a = Complex64::new(LANCZOS_COEFFICIENTS[0], 0.0);
+    for (i, &coeff) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        a += Complex64::new(coeff, 0.0) / (z + Complex64::new(i as f64, 0.0));
+    }
+
+    let t = z + Complex64::new(LANCZOS_G + 0.5, 0.0);
+    let log_power = (z + Complex64::new(0.5, 0.0)) * t.ln() - t;
+    Complex64::new((2.0 * PI).sqrt(), 0.0) * log_power.exp() * a
+}
+
+/// Ratio of gamma functions Γ(a)/Γ(b), without overflow
+///
+/// Γ(a) and Γ(b) overflow `f64` beyond about 171, even when their ratio is
+/// modest, e.g. Γ(200)/Γ(199) = 199. This ratio appears in hypergeometric series
+/// terms and Pochhammer symbols (a)ₙ = Γ(a + n)/Γ(a).
+///
+/// ## Implementation
+///
+/// - When a - b is an integer of magnitude at most 64, the ratio is the finite
+///   product b·(b + 1)···(a - 1) (or its reciprocal), which is accurate to a few
+///   rounding errors
+/// - Otherwise it is sign(a)·sign(b)·exp(ln|Γ(a)| - ln|Γ(b)|) through
+///   [`lgamma_sign`], which handles negative arguments. The relative error grows
+///   with the size of the logarithms, to about 10⁻¹³ near 1000
+///
+/// ## Poles
+///
+/// If only a is a pole (a non-positive integer) the result is `f64::INFINITY`;
+/// if only b is, it is 0. If both are, the finite limit
+/// Γ(-m)/Γ(-n) = (-1)ⁿ⁻ᵐ·n!/m! is returned.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::gamma::gamma_ratio;
+///
+/// assert_eq!(gamma_ratio(10.0, 8.0), 72.0);
+///
+/// // Γ(200) alone overflows
+/// assert!((gamma_ratio(200.0, 199.0) - 199.0).abs() < 1e-12);
+///
+/// // Γ(200.5)/Γ(200) ≈ √200
+/// assert!((gamma_ratio(200.5, 200.0) - 14.133_299_559_727_925).abs() < 1e-11);
+/// ```
+pub fn gamma_ratio(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+
+    let is_pole = |x: f64| x <= 0.0 && x.fract() == 0.0;
+    match (is_pole(a), is_pole(b)) {
+        (true, true) => {
+            // Ratio of the residues (-1)ᵐ/m! and (-1)ⁿ/n! at a = -m, b = -n
+            let (m, n) = (-a, -b);
+            let sign = if (m - n).rem_euclid(2.0) == 0.0 {
+                1.0
+            } else {
+                -1.0
+            };
+            return sign * (lgamma_sign(n + 1.0).0 - lgamma_sign(m + 1.0).0).exp();
+        }
+        (true, false) => return f64::INFINITY,
+        (false, true) => return 0.0,
+        (false, false) => {}
+    }
+
+    let difference = a - b;
+    if difference.fract() == 0.0 && difference.abs() <= 64.0 {
+        let (low, count) = if difference >= 0.0 {
+            (b, difference as usize)
+        } else {
+            (a, -difference as usize)
+        };
+        let product: f64 = (0..count).map(|k| low + k as f64).product();
+        return if difference >= 0.0 {
+            product
+        } else {
+            1.0 / product
+        };
+    }
+
+    let (ln_a, sign_a) = lgamma_sign(a);
+    let (ln_b, sign_b) = lgamma_sign(b);
+    sign_a * sign_b * (ln_a - ln_b).exp()
+}
+
+/// Number of Stirling correction terms B₂ₖ/(2k(2k - 1)z²ᵏ⁻¹) used by [`gamma_series`]
+const STIRLING_CORRECTIONS: u32 = 10;
+
+/// Gamma function by Euler's product and Stirling's series, with a tunable
+/// number of terms
+///
+/// An implementation independent of the Lanczos coefficients in [`gamma`],
+/// intended as a cross-check for it.
+///
+/// ## Implementation
+///
+/// The argument is shifted up by `terms` with Euler's finite product
+///
+/// ```text
+/// Γ(x) = Γ(x + N) / (x·(x + 1)···(x + N - 1))
+/// ```
+///
+/// and ln Γ(x + N) is evaluated from Stirling's series
+///
+/// ```text
+/// ln Γ(z) = (z - 1/2)·ln z - z + ln(2π)/2 + Σₖ B₂ₖ / (2k·(2k - 1)·z²ᵏ⁻¹)
+/// ```
+///
+/// with the first ten Bernoulli numbers B₂ₖ from
+/// [`bernoulli`](crate::specials::bernoulli::bernoulli). The truncation error
+/// falls like (x + N)⁻²¹, so `terms = 30` is accurate to rounding for every
+/// x > 0, while small `terms` show how the error shrinks as N grows. The result
+/// is exponentiated from a logarithm, so it overflows only when Γ(x) itself
+/// does.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::gamma::{gamma, gamma_series};
+///
+/// assert!((gamma_series(5.0, 30) - 24.0).abs() < 1e-12);
+///
+/// let x = 3.7;
+/// assert!((gamma_series(x, 30) - gamma(x)).abs() < 1e-12 * gamma(x));
+///
+/// // Fewer terms, less accuracy
+/// let coarse = (gamma_series(0.5, 2) - gamma(0.5)).abs();
+/// let fine = (gamma_series(0.5, 10) - gamma(0.5)).abs();
+/// assert!(fine < coarse);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if x ≤ 0.
+pub fn gamma_series(x: f64, terms: usize) -> f64 {
+    require_positive(x, "gamma_series");
+
+    // ln(x·(x + 1)···(x + N - 1)), summed as logarithms so that it cannot overflow
+    let log_product: f64 = (0..terms).map(|k| (x + k as f64).ln()).sum();
+
+    let z = x + terms as f64;
+    let z_squared = z * z;
+    let mut power = z;
+    let mut correction = 0.0;
+    for k in 1..=STIRLING_CORRECTIONS {
+        let n = 2 * k;
+        correction += bernoulli(n) / ((n * (n - 1)) as f64 * power);
+        power *= z_squared;
+    }
+
+    let log_gamma_z = (z - 0.5) * z.ln() - z + 0.5 * (2.0 * PI).ln() + correction;
+    (log_gamma_z - log_product).exp()
+}
+
+/// Location and value (x₀, Γ(x₀)) of the minimum of Γ on the positive reals
+///
+/// Γ is convex on x > 0, so it has a single minimum, at the root of the
+/// digamma function: x₀ ≈ 1.461 632 144 968 362, where Γ(x₀) ≈ 0.885 603 194 410 889.
+/// It separates the two branches of the inverse of Γ.
+///
+/// ## Implementation
+///
+/// Rather than hardcoding the constants, the root of [`digamma`] is found once by
+/// Newton's method with [`trigamma`] as the derivative, and cached for later
+/// calls.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::gamma::gamma_min;
+///
+/// let (x_min, gamma_at_min) = gamma_min();
+/// assert!((x_min - 1.461_632_144_968_362).abs() < 1e-14);
+/// assert!((gamma_at_min - 0.885_603_194_410_888_7).abs() < 1e-14);
+/// ```
+pub fn gamma_min() -> (f64, f64) {
+    static MINIMUM: OnceLock<(f64, f64)> = OnceLock::new();
+
+    *MINIMUM.get_or_init(|| {
+        let x_min = raphson(1.5, digamma, trigamma, 1e-15, 50);
+        (x_min, gamma(x_min))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_gamma_complex_matches_real_gamma() {
+        for x in [0.1, 0.5, 1.0, 1.7, 3.0, 7.25, 20.0, 40.5] {
+            let g = gamma_complex(Complex64::new(x, 0.0));
+            let expected = gamma(x);
+            assert!((g.re - expected).abs() < 1e-13 * expected, "x = {x}");
+            assert_eq!(g.im, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_gamma_complex_reference_values() {
+        // Computed with mpmath at 30 digits
+        let cases = [
+            (
+                (1.0, 1.0),
+                (0.498_015_668_118_356, -0.154_949_828_301_810_7),
+            ),
+            (
+                (0.5, 2.0),
+                (0.089_855_176_706_431_64, -0.060_493_760_292_887_57),
+            ),
+            (
+                (-2.5, 0.3),
+                (-0.613_822_997_437_741_5, -0.211_232_614_937_041_8),
+            ),
+            (
+                (3.0, -4.0),
+                (0.005_225_538_471_369_214, 0.172_547_079_294_300_2),
+            ),
+            (
+                (20.0, 10.0),
+                (2.741_188_744_832_832_5e15, -1.000_685_306_214_608_8e16),
+            ),
+        ];
+        for ((re, im), (expected_re, expected_im)) in cases {
+            let expected = Complex64::new(expected_re, expected_im);
+            let g = gamma_complex(Complex64::new(re, im));
+            assert!(
+                (g - expected).norm() < 1e-13 * expected.norm(),
+                "Γ({re} + {im}i) = {g}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_gamma_complex_reflection_and_conjugate() {
+        // Γ(z)Γ(1 - z) = π/sin(πz)
+        let pi = Complex64::new(PI, 0.0);
+        for z in [Complex64::new(0.3, 0.8), Complex64::new(2.2, -1.5)] {
+            let product = gamma_complex(z) * gamma_complex(Complex64::ONE - z);
+            let expected = pi / (pi * z).sin();
+            assert!((product - expected).norm() < 1e-13 * expected.norm());
+        }
+
+        // Γ(z̄) = conj Γ(z), and the recurrence Γ(z + 1) = z·Γ(z)
+        let z = Complex64::new(1.3, 2.7);
+        assert!((gamma_complex(z.conj()) - gamma_complex(z).conj()).norm() < 1e-15);
+        let ratio = gamma_complex(z + Complex64::ONE) / gamma_complex(z);
+        assert!((ratio - z).norm() < 1e-13);
+    }
+
+    #[test]
+    #[should_panic(expected = "gamma_complex: argument must not be a non-positive integer, got -2")]
+    fn test_gamma_complex_pole() {
+        gamma_complex(Complex64::new(-2.0, 0.0));
+    }
+
+    #[test]
+    fn test_gamma_min() {
+        let (x_min, gamma_at_min) = gamma_min();
+        assert!(digamma(x_min).abs() < 1e-15);
+        assert!((x_min - 1.461_632_144_968_362_3).abs() < 1e-10);
+        assert!((gamma_at_min - 0.885_603_194_410_888_7).abs() < 1e-10);
+
+        // A minimum: Γ is larger on either side
+        for dx in [1e-3, 0.1, 0.4] {
+            assert!(gamma(x_min - dx) > gamma_at_min);
+            assert!(gamma(x_min + dx) > gamma_at_min);
+        }
+        assert_eq!(gamma_min(), (x_min, gamma_at_min));
+    }
+
+    #[test]
+    fn test_gamma_series_agrees_with_lanczos() {
+        let mut x = 0.01;
+        while x < 170.0 {
+            let lanczos = gamma(x);
+            let series = gamma_series(x, 30);
+            assert!(
+                (series - lanczos).abs() <= 1e-12 * lanczos,
+                "x = {x}: series {series}, Lanczos {lanczos}"
+            );
+            x *= 1.07;
+        }
+
+        for n in 1..=20 {
+            let factorial: f64 = (1..n).map(|k| k as f64).product();
+            assert!((gamma_series(n as f64, 30) - factorial).abs() <= 1e-13 * factorial);
+        }
+        assert!((gamma_series(0.5, 30) - PI.sqrt()).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_gamma_series_converges_with_terms() {
+        let exact = gamma(1.3);
+        let errors: Vec<f64> = [0, 2, 4, 8]
+            .iter()
+            .map(|&terms| (gamma_series(1.3, terms) - exact).abs())
+            .collect();
+        assert!(errors.windows(2).all(|w| w[1] < w[0]), "{errors:?}");
+    }
+
+    #[test]
+    fn test_gamma_series_overflow() {
+        assert!(gamma_series(171.5, 30).is_finite());
+        assert_eq!(gamma_series(172.0, 30), f64::INFINITY);
+    }
+
     #[test]
     fn test_gamma_factorial() {
         // Test factorial relationship: Γ(n) = (n-1)!
@@ -104,14 +463,59 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Gamma function undefined for non-positive values")]
+    #[should_panic(expected = "gamma: argument must be positive, got")]
     fn test_gamma_negative() {
         gamma(-1.0);
     }
 
     #[test]
-    #[should_panic(expected = "Gamma function undefined for non-positive values")]
+    #[should_panic(expected = "gamma: argument must be positive, got")]
     fn test_gamma_zero() {
         gamma(0.0);
     }
+
+    #[test]
+    fn test_gamma_ratio_integer_steps() {
+        assert_eq!(gamma_ratio(10.0, 8.0), 72.0);
+        assert_eq!(gamma_ratio(8.0, 10.0), 1.0 / 72.0);
+        assert_eq!(gamma_ratio(200.0, 199.0), 199.0);
+        for a in [0.3, 2.5, 17.0, -3.7, 1e5] {
+            assert_eq!(gamma_ratio(a, a), 1.0);
+        }
+
+        // Pochhammer symbol (1/2)₃ = 1/2 · 3/2 · 5/2
+        assert_eq!(gamma_ratio(3.5, 0.5), 1.875);
+    }
+
+    #[test]
+    fn test_gamma_ratio_reference_values() {
+        // Computed with mpmath at 40 digits
+        let cases = [
+            (200.5, 200.0, 14.133_299_559_727_925),
+            (-2.5, 3.7, -0.226_657_311_491_589_88),
+            (0.3, -1.6, 1.294_724_825_480_729_3),
+            (1_000.25, 1_000.0, 5.622_886_037_710_301),
+        ];
+        for (a, b, expected) in cases {
+            let value = gamma_ratio(a, b);
+            assert!(
+                (value - expected).abs() < 1e-12 * expected.abs(),
+                "Γ({a})/Γ({b}) = {value}"
+            );
+        }
+
+        // Matches the direct quotient where both gammas are finite
+        assert!((gamma_ratio(4.3, 2.1) - gamma(4.3) / gamma(2.1)).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_gamma_ratio_poles() {
+        assert_eq!(gamma_ratio(-3.0, 2.5), f64::INFINITY);
+        assert_eq!(gamma_ratio(2.5, 0.0), 0.0);
+
+        // Γ(-2)/Γ(-1) → (1/2!)/(-1/1!) = -1/2
+        assert!((gamma_ratio(-2.0, -1.0) + 0.5).abs() < 1e-15);
+        assert!((gamma_ratio(-1.0, -3.0) - 6.0).abs() < 1e-13);
+        assert!(gamma_ratio(f64::NAN, 1.0).is_nan());
+    }
 }
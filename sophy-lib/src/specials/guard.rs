@@ -0,0 +1,169 @@
+//! Saturating evaluation of special functions
+//!
+//! In batch pipelines a single out-of-range input should not abort the whole
+//! computation. The functions here never panic: inputs are clamped into the
+//! range where the underlying function is well-behaved, and poles and overflow
+//! saturate to infinity. Unlike [`crate::specials::safe`], no error is reported,
+//! so a result can be used directly in further arithmetic.
+//!
+//! NaN inputs still give NaN, as there is no meaningful value to saturate to.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use sophy::specials::guard::{erf_saturating, gamma_saturating};
+//!
+//! let batch = [-1e300, -2.0, 0.0, 0.5, 200.0];
+//! let gammas: Vec<f64> = batch.iter().map(|&x| gamma_saturating(x)).collect();
+//! assert_eq!(gammas[2], f64::INFINITY);
+//! assert!(batch.iter().all(|&x| erf_saturating(x).abs() <= 1.0));
+//! ```
+
+use crate::specials::{erf, gamma, lgamma_sign};
+
+/// Beyond |x| = 6, erf(x) rounds to ±1 in double precision
+const ERF_SATURATION: f64 = 6.0;
+
+/// Γ(x) overflows `f64` for x above about 171.62
+pub(crate) const GAMMA_OVERFLOW: f64 = 171.7;
+
+/// Clamps `x` into [lo, hi], passing NaN through
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::guard::clamp_domain;
+///
+/// assert_eq!(clamp_domain(7.5, -1.0, 1.0), 1.0);
+/// assert_eq!(clamp_domain(0.25, -1.0, 1.0), 0.25);
+/// assert!(clamp_domain(f64::NAN, -1.0, 1.0).is_nan());
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `lo > hi` or either bound is NaN.
+pub fn clamp_domain(x: f64, lo: f64, hi: f64) -> f64 {
+    if lo.is_nan() || hi.is_nan() || lo > hi {
+        panic!("Domain clamp requires lo <= hi");
+    }
+    x.clamp(lo, hi)
+}
+
+/// Error function that saturates to ±1 for large |x|
+///
+/// The argument is clamped to [-6, 6], beyond which erf is ±1 to double
+/// precision, and the result is clamped to [-1, 1], so infinite inputs and
+/// rounding can never carry it out of range.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::guard::erf_saturating;
+///
+/// assert_eq!(erf_saturating(f64::INFINITY), 1.0);
+/// assert_eq!(erf_saturating(-1e308), -1.0);
+/// assert_eq!(erf_saturating(0.5), sophy::specials::erf(0.5));
+/// ```
+pub fn erf_saturating(x: f64) -> f64 {
+    let x = clamp_domain(x, -ERF_SATURATION, ERF_SATURATION);
+    clamp_domain(erf(x), -1.0, 1.0)
+}
+
+/// Gamma function that returns infinity at poles and on overflow instead of
+/// panicking
+///
+/// - At the poles x = 0, -1, -2, ... and at -∞ the result is `f64::INFINITY`
+/// - Other negative arguments, which [`gamma`] rejects, are evaluated through
+///   [`lgamma_sign`] as sign·exp(ln|Γ(x)|)
+/// - Above about 171.6, where Γ(x) exceeds `f64::MAX`, the result is
+///   `f64::INFINITY` without any recursion
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::guard::gamma_saturating;
+///
+/// assert_eq!(gamma_saturating(0.0), f64::INFINITY);
+/// assert_eq!(gamma_saturating(-2.0), f64::INFINITY);
+/// assert_eq!(gamma_saturating(1e6), f64::INFINITY);
+///
+/// // Γ(-1/2) = -2√π
+/// let expected = -2.0 * std::f64::consts::PI.sqrt();
+/// assert!((gamma_saturating(-0.5) - expected).abs() < 1e-13);
+/// ```
+pub fn gamma_saturating(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x > GAMMA_OVERFLOW {
+        return f64::INFINITY;
+    }
+    if x > 0.0 {
+        return gamma(x);
+    }
+    if x.fract() == 0.0 || x.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    let (log_abs, sign) = lgamma_sign(x);
+    sign * log_abs.exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gamma_saturating_poles_do_not_panic() {
+        assert_eq!(gamma_saturating(0.0), f64::INFINITY);
+        assert_eq!(gamma_saturating(-0.0), f64::INFINITY);
+        assert_eq!(gamma_saturating(-2.0), f64::INFINITY);
+        assert_eq!(gamma_saturating(-1e20), f64::INFINITY);
+        assert_eq!(gamma_saturating(f64::NEG_INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_gamma_saturating_values() {
+        assert_eq!(gamma_saturating(5.0), gamma(5.0));
+        assert_eq!(gamma_saturating(172.0), f64::INFINITY);
+        assert_eq!(gamma_saturating(f64::INFINITY), f64::INFINITY);
+        assert!(gamma_saturating(171.5).is_finite());
+        assert!(gamma_saturating(f64::NAN).is_nan());
+
+        // Γ(-2.5) = -8√π/15, negative between -3 and -2
+        let expected = -8.0 * crate::base::numbers::PI.sqrt() / 15.0;
+        assert!((gamma_saturating(-2.5) - expected).abs() < 1e-13);
+        // Tiny magnitudes far into the negative axis underflow to zero
+        assert_eq!(gamma_saturating(-200.5), 0.0);
+    }
+
+    #[test]
+    fn test_erf_saturating_stays_in_range() {
+        let inputs = [
+            f64::NEG_INFINITY,
+            -1e308,
+            -6.5,
+            -1.0,
+            0.0,
+            1e-300,
+            2.0,
+            5.99,
+            1e10,
+            f64::INFINITY,
+        ];
+        for x in inputs {
+            let value = erf_saturating(x);
+            assert!((-1.0..=1.0).contains(&value), "erf({x}) = {value}");
+        }
+        assert_eq!(erf_saturating(1e10), 1.0);
+        assert_eq!(erf_saturating(-7.0), -1.0);
+        assert_eq!(erf_saturating(1.3), erf(1.3));
+        assert!(erf_saturating(f64::NAN).is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "Domain clamp requires lo <= hi")]
+    fn test_clamp_domain_inverted_bounds() {
+        clamp_domain(0.0, 1.0, -1.0);
+    }
+}
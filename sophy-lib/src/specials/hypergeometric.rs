@@ -0,0 +1,248 @@
+//! Gauss hypergeometric function implementation
+//!
+//! The hypergeometric function ₂F₁(a, b; c; z) contains many elementary and special
+//! functions as special cases, e.g. ln(1 + z) = z·₂F₁(1, 1; 2; -z) and the Legendre
+//! polynomials Pₙ(x) = ₂F₁(-n, n + 1; 1; (1 - x)/2).
+//!
+//! The confluent function ₁F₁(a; b; z) (Kummer's function) is the limit of
+//! ₂F₁(a, b'; b; z/b') as b' → ∞ and underlies the incomplete gamma function, the
+//! error function and the Bessel functions.
+
+/// Gauss hypergeometric function ₂F₁(a, b; c; z)
+///
+/// ## Mathematical Definition
+///
+/// ₂F₁(a, b; c; z) = Σ (a)ₙ(b)ₙ/(c)ₙ · zⁿ/n!,   n = 0, 1, 2, ...
+///
+/// where (x)ₙ is the rising factorial
+/// [`rising_factorial`](crate::base::numbers::rising_factorial).
+///
+/// ## Implementation
+///
+/// The series is summed with the term ratio
+///
+/// tₙ₊₁ / tₙ = (a + n)(b + n) / ((c + n)(n + 1)) · z
+///
+/// which avoids forming the Pochhammer symbols separately, until the terms fall
+/// below 1e-16 relative to the sum. Convergence slows down as |z| → 1.
+///
+/// If `a` or `b` is a non-positive integer the series terminates and the result is
+/// a polynomial in `z`, which is evaluated for any `z`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::hyp2f1;
+///
+/// // ₂F₁(1, 1; 2; z) = -ln(1 - z)/z
+/// let z: f64 = 0.5;
+/// assert!((hyp2f1(1.0, 1.0, 2.0, z) + (1.0 - z).ln() / z).abs() < 1e-15);
+///
+/// // Terminating series: ₂F₁(-2, 1; 1; z) = (1 - z)²
+/// assert_eq!(hyp2f1(-2.0, 1.0, 1.0, 3.0), 4.0);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `c` is a non-positive integer, where the series is undefined, or if
+/// |z| ≥ 1 and the series does not terminate.
+pub fn hyp2f1(a: f64, b: f64, c: f64, z: f64) -> f64 {
+    if c <= 0.0 && c.fract() == 0.0 {
+        panic!("Hypergeometric function undefined for non-positive integer c");
+    }
+
+    let terminates = [a, b].iter().any(|&p| p <= 0.0 && p.fract() == 0.0);
+    if !terminates && z.abs() >= 1.0 {
+        panic!("Hypergeometric series requires |z| < 1");
+    }
+
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut n = 0.0;
+
+    while n < 100_000.0 {
+        term *= (a + n) * (b + n) / ((c + n) * (n + 1.0)) * z;
+        sum += term;
+        n += 1.0;
+
+        if term == 0.0 || term.abs() < 1e-16 * sum.abs() {
+            break;
+        }
+    }
+
+    sum
+}
+
+/// Confluent hypergeometric function ₁F₁(a; b; z) (Kummer's function M(a, b, z))
+///
+/// ## Mathematical Definition
+///
+/// ₁F₁(a; b; z) = Σ (a)ₙ/(b)ₙ · zⁿ/n!,   n = 0, 1, 2, ...
+///
+/// The series converges for every z.
+///
+/// ## Implementation
+///
+/// For z ≥ 0 the series is summed directly with the term ratio
+/// (a + n) / ((b + n)(n + 1)) · z. For z < 0 the terms alternate and cancel
+/// catastrophically, so Kummer's transformation is applied first:
+///
+/// ₁F₁(a; b; z) = eᶻ · ₁F₁(b - a; b; -z)
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::hyp1f1;
+///
+/// // ₁F₁(a; a; z) = eᶻ
+/// assert!((hyp1f1(1.0, 1.0, 2.0) - 2.0_f64.exp()).abs() < 1e-14);
+///
+/// // Terminating series: ₁F₁(-1; b; z) = 1 - z/b
+/// assert_eq!(hyp1f1(-1.0, 4.0, 2.0), 0.5);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `b` is a non-positive integer, where the series is undefined.
+pub fn hyp1f1(a: f64, b: f64, z: f64) -> f64 {
+    if b <= 0.0 && b.fract() == 0.0 {
+        panic!("Confluent hypergeometric function undefined for non-positive integer b");
+    }
+
+    let terminates = a <= 0.0 && a.fract() == 0.0;
+    if z < 0.0 && !terminates {
+        return z.exp() * hyp1f1(b - a, b, -z);
+    }
+
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut n = 0.0;
+
+    while n < 100_000.0 {
+        term *= (a + n) / ((b + n) * (n + 1.0)) * z;
+        sum += term;
+        n += 1.0;
+
+        if term == 0.0 || term.abs() < 1e-16 * sum.abs() {
+            break;
+        }
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::complex::Complex64;
+    use crate::base::numbers::{PI, rising_factorial};
+    use crate::specials::{erf, erf_complex};
+
+    #[test]
+    fn test_hyp2f1_logarithm() {
+        // ₂F₁(1, 1; 2; z) = -ln(1 - z)/z
+        for z in [-0.9, -0.3, 0.1, 0.5, 0.9] {
+            let expected = -(1.0_f64 - z).ln() / z;
+            assert!((hyp2f1(1.0, 1.0, 2.0, z) - expected).abs() < 1e-14 * expected.abs());
+        }
+    }
+
+    #[test]
+    fn test_hyp2f1_binomial_series() {
+        // ₂F₁(a, b; b; z) = (1 - z)^(-a)
+        for (a, b, z) in [(0.5, 2.0, 0.3), (-1.7, 3.2, -0.6), (2.5, 0.7, 0.8)] {
+            let expected = (1.0_f64 - z).powf(-a);
+            assert!((hyp2f1(a, b, b, z) - expected).abs() < 1e-13 * expected);
+        }
+    }
+
+    #[test]
+    fn test_hyp2f1_at_zero() {
+        for (a, b, c) in [(1.0, 2.0, 3.0), (-0.5, 7.2, 0.3), (4.0, -3.5, -2.5)] {
+            assert_eq!(hyp2f1(a, b, c, 0.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_hyp2f1_matches_pochhammer_series() {
+        let (a, b, c, z): (f64, f64, f64, f64) = (0.3, 1.7, 2.2, 0.4);
+        let mut expected = 0.0;
+        let mut n_factorial = 1.0;
+        for n in 0..60u32 {
+            if n > 0 {
+                n_factorial *= n as f64;
+            }
+            expected += rising_factorial(a, n) * rising_factorial(b, n) / rising_factorial(c, n)
+                * z.powi(n as i32)
+                / n_factorial;
+        }
+        assert!((hyp2f1(a, b, c, z) - expected).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_hyp2f1_terminating_legendre() {
+        // P₃(x) = ₂F₁(-3, 4; 1; (1 - x)/2)
+        for x in [-1.0, -0.4, 0.25, 1.0] {
+            let p3 = 0.5 * (5.0 * x * x * x - 3.0 * x);
+            assert!((hyp2f1(-3.0, 4.0, 1.0, (1.0 - x) / 2.0) - p3).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Hypergeometric function undefined for non-positive integer c")]
+    fn test_hyp2f1_nonpositive_integer_c() {
+        hyp2f1(1.0, 1.0, -2.0, 0.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Hypergeometric series requires |z| < 1")]
+    fn test_hyp2f1_outside_unit_disk() {
+        hyp2f1(1.0, 1.0, 2.0, 1.0);
+    }
+
+    #[test]
+    fn test_hyp1f1_exponential() {
+        // ₁F₁(1; 1; z) = eᶻ, including large negative z via Kummer's transformation
+        for z in [-30.0, -5.0, -0.5, 0.0, 0.7, 10.0, 40.0] {
+            let expected = f64::exp(z);
+            assert!((hyp1f1(1.0, 1.0, z) - expected).abs() < 1e-13 * expected);
+        }
+    }
+
+    #[test]
+    fn test_hyp1f1_at_zero() {
+        for (a, b) in [(1.0, 2.0), (-0.5, 7.2), (3.0, -2.5)] {
+            assert_eq!(hyp1f1(a, b, 0.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_hyp1f1_error_function() {
+        // erf(x) = 2x/√π · ₁F₁(1/2; 3/2; -x²)
+        for x in [0.1, 0.5, 1.0, 2.0, 4.0] {
+            let via_kummer = 2.0 * x / PI.sqrt() * hyp1f1(0.5, 1.5, -x * x);
+            let reference = erf_complex(Complex64::new(x, 0.0)).re;
+            assert!((via_kummer - reference).abs() < 1e-14);
+
+            // The real erf is accurate to near machine precision
+            assert!((via_kummer - erf(x)).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_hyp1f1_kummer_transformation() {
+        // Both sides computed independently on opposite sides of the origin
+        let (a, b, z): (f64, f64, f64) = (0.7, 2.3, 3.1);
+        let lhs = hyp1f1(a, b, z);
+        let rhs = z.exp() * hyp1f1(b - a, b, -z);
+        assert!((lhs - rhs).abs() < 1e-13 * lhs);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Confluent hypergeometric function undefined for non-positive integer b"
+    )]
+    fn test_hyp1f1_nonpositive_integer_b() {
+        hyp1f1(1.0, 0.0, 0.5);
+    }
+}
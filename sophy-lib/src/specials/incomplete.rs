@@ -0,0 +1,325 @@
+//! Regularized incomplete gamma and beta function implementations
+//!
+//! The incomplete gamma function P(a, x) and incomplete beta function Iₓ(a, b) are
+//! the cumulative distribution functions of the gamma and beta distributions, and
+//! through them of the chi-squared, Poisson, Student's t, F and binomial
+//! distributions.
+
+use crate::specials::lgamma::lgamma_sign;
+
+/// Relative accuracy at which the series and continued fractions stop
+const TOLERANCE: f64 = 1e-16;
+
+/// Iteration cap for the series and continued fractions; convergence takes
+/// O(√a) steps, so this is only reached for enormous parameters
+const MAX_ITERATIONS: usize = 10_000;
+
+/// Guard against division by zero in the modified Lentz algorithm
+const TINY: f64 = 1e-300;
+
+/// Regularized lower incomplete gamma function P(a, x)
+///
+/// ## Mathematical Definition
+///
+/// P(a, x) = γ(a, x)/Γ(a) = (1/Γ(a)) ∫₀ˣ tᵃ⁻¹ e⁻ᵗ dt
+///
+/// P rises from P(a, 0) = 0 to 1 as x → ∞.
+///
+/// ## Implementation
+///
+/// For x < a + 1 the power series
+///
+/// γ(a, x) = xᵃ e⁻ˣ Σ xⁿ / (a·(a + 1)···(a + n))
+///
+/// converges quickly. Otherwise the continued fraction for the complement
+/// Q(a, x) = 1 - P(a, x) is evaluated with the modified Lentz algorithm, as in
+/// Numerical Recipes' `gammp`.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::gammp;
+///
+/// // P(1, x) = 1 - e⁻ˣ
+/// assert!((gammp(1.0, 2.0) - (1.0 - (-2.0_f64).exp())).abs() < 1e-15);
+/// assert_eq!(gammp(3.0, 0.0), 0.0);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a <= 0` or `x < 0`.
+pub fn gammp(a: f64, x: f64) -> f64 {
+    let (p, q) = gamma_pq(a, x);
+    if x < a + 1.0 { p } else { 1.0 - q }
+}
+
+/// Regularized upper incomplete gamma function Q(a, x) = 1 - P(a, x)
+///
+/// Computed directly rather than as 1 - [`gammp`], so small upper tails such as
+/// Q(1/2, 30) ≈ 1.3e-14 keep their full relative accuracy.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::gammq;
+///
+/// // Q(1, x) = e⁻ˣ
+/// let q = gammq(1.0, 40.0);
+/// assert!((q - (-40.0_f64).exp()).abs() < 1e-13 * q);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a <= 0` or `x < 0`.
+pub fn gammq(a: f64, x: f64) -> f64 {
+    let (p, q) = gamma_pq(a, x);
+    if x < a + 1.0 { 1.0 - p } else { q }
+}
+
+/// P(a, x) from the series when x < a + 1, otherwise Q(a, x) from the continued
+/// fraction; the other component is left at zero
+fn gamma_pq(a: f64, x: f64) -> (f64, f64) {
+    if a <= 0.0 {
+        panic!("Incomplete gamma function requires a > 0");
+    }
+    if x < 0.0 {
+        panic!("Incomplete gamma function requires x >= 0");
+    }
+    if x == 0.0 || x == f64::INFINITY {
+        // P = 0 at zero; at infinity Q = 0, where the prefactor would be ∞ - ∞
+        return (0.0, 0.0);
+    }
+
+    // xᵃ e⁻ˣ / Γ(a), in log form to avoid overflow
+    let prefactor = (a * x.ln() - x - lgamma_sign(a).0).exp();
+
+    if x < a + 1.0 {
+        (prefactor * gamma_series(a, x), 0.0)
+    } else {
+        (0.0, prefactor * gamma_continued_fraction(a, x))
+    }
+}
+
+/// Σ xⁿ / (a·(a + 1)···(a + n)), so that γ(a, x) = xᵃ e⁻ˣ times this sum
+fn gamma_series(a: f64, x: f64) -> f64 {
+    let mut denom = a;
+    let mut term = 1.0 / a;
+    let mut sum = term;
+
+    for _ in 0..MAX_ITERATIONS {
+        denom += 1.0;
+        term *= x / denom;
+        sum += term;
+        if term.abs() < sum.abs() * TOLERANCE {
+            break;
+        }
+    }
+
+    sum
+}
+
+/// Continued fraction 1/(x + 1 - a - 1·(1 - a)/(x + 3 - a - ...)), so that
+/// Γ(a, x) = xᵃ e⁻ˣ times this value
+fn gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = an * d + b;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < TOLERANCE {
+            break;
+        }
+    }
+
+    h
+}
+
+/// Regularized incomplete beta function Iₓ(a, b)
+///
+/// ## Mathematical Definition
+///
+/// Iₓ(a, b) = (1/B(a, b)) ∫₀ˣ tᵃ⁻¹ (1 - t)ᵇ⁻¹ dt
+///
+/// where B(a, b) = Γ(a)Γ(b)/Γ(a + b). It rises from 0 at x = 0 to 1 at x = 1 and
+/// satisfies the symmetry Iₓ(a, b) = 1 - I₁₋ₓ(b, a).
+///
+/// ## Implementation
+///
+/// The continued fraction of Numerical Recipes' `betai` is evaluated with the
+/// modified Lentz algorithm. It converges rapidly for x < (a + 1)/(a + b + 2);
+/// above that point the symmetry relation is used instead.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::betai;
+///
+/// // I₀.₅(a, a) = 1/2 by symmetry
+/// assert!((betai(3.5, 3.5, 0.5) - 0.5).abs() < 1e-15);
+///
+/// // Iₓ(1, 1) = x
+/// assert!((betai(1.0, 1.0, 0.3) - 0.3).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if `a <= 0`, `b <= 0`, or `x` lies outside [0, 1].
+pub fn betai(a: f64, b: f64, x: f64) -> f64 {
+    if a <= 0.0 || b <= 0.0 {
+        panic!("Incomplete beta function requires a > 0 and b > 0");
+    }
+    if !(0.0..=1.0).contains(&x) {
+        panic!("Incomplete beta function requires 0 <= x <= 1");
+    }
+    if x == 0.0 || x == 1.0 {
+        return x;
+    }
+
+    // xᵃ (1 - x)ᵇ / B(a, b), in log form to avoid overflow
+    let ln_beta = lgamma_sign(a).0 + lgamma_sign(b).0 - lgamma_sign(a + b).0;
+    let prefactor = (a * x.ln() + b * (-x).ln_1p() - ln_beta).exp();
+
+    if x < (a + 1.0) / (a + b + 2.0) {
+        prefactor * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - prefactor * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Continued fraction for Iₓ(a, b), evaluated with the modified Lentz algorithm
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let clamp = |v: f64| if v.abs() < TINY { TINY } else { v };
+
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+
+    for m in 1..MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // Even step
+        let aa = m * (b - m) * x / ((a - 1.0 + m2) * (a + m2));
+        d = 1.0 / clamp(1.0 + aa * d);
+        c = clamp(1.0 + aa / c);
+        h *= d * c;
+
+        // Odd step
+        let aa = -(a + m) * (a + b + m) * x / ((a + m2) * (a + 1.0 + m2));
+        d = 1.0 / clamp(1.0 + aa * d);
+        c = clamp(1.0 + aa / c);
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < TOLERANCE {
+            break;
+        }
+    }
+
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_incomplete_gamma_infinite_and_huge_x() {
+        for a in [0.5, 1.0, 2.5, 100.0] {
+            assert_eq!(gammp(a, f64::INFINITY), 1.0);
+            assert_eq!(gammq(a, f64::INFINITY), 0.0);
+            assert_eq!(gammp(a, 1e300), 1.0);
+            assert_eq!(gammq(a, 1e300), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_gammp_reference_values() {
+        // Computed with mpmath at 40 digits
+        let cases = [
+            (2.5, 1.7, 0.361_430_076_896_204_93),
+            (10.0, 3.0, 0.001_102_488_130_115_479_8),
+            (0.5, 20.0, 0.999_999_999_746_037_1),
+            (3.0, 30.0, 0.999_999_999_954_989_8),
+            (100.0, 95.0, 0.317_356_811_169_8),
+        ];
+
+        for (a, x, expected) in cases {
+            let value = gammp(a, x);
+            assert!((value - expected).abs() < 1e-13, "P({a}, {x}) = {value}");
+        }
+    }
+
+    #[test]
+    fn test_gammp_exponential_case() {
+        for x in [0.1_f64, 0.5, 1.0, 2.0, 5.0, 10.0] {
+            let expected = 1.0 - (-x).exp();
+            assert!((gammp(1.0, x) - expected).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_gammq_complements_gammp() {
+        for (a, x) in [(0.5, 0.1), (2.5, 1.7), (3.0, 30.0), (100.0, 95.0)] {
+            assert!((gammp(a, x) + gammq(a, x) - 1.0).abs() < 1e-15);
+        }
+        assert_eq!(gammq(2.0, 0.0), 1.0);
+
+        // Upper tail far beyond where 1 - P cancels to zero
+        let q = gammq(0.5, 50.0);
+        assert!((q - 1.523_970_604_832_105e-23).abs() < 1e-14 * q);
+    }
+
+    #[test]
+    fn test_betai_reference_values() {
+        // Computed with mpmath at 40 digits
+        let cases = [
+            (2.0, 3.0, 0.4, 0.5248),
+            (0.5, 5.0, 0.1, 0.683_357_084_979_987_7),
+            (30.0, 40.0, 0.45, 0.644_748_008_558_568_1),
+        ];
+
+        for (a, b, x, expected) in cases {
+            let value = betai(a, b, x);
+            assert!(
+                (value - expected).abs() < 1e-13,
+                "I({x}; {a}, {b}) = {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_betai_symmetry_and_endpoints() {
+        for x in [0.05, 0.3, 0.5, 0.8, 0.99] {
+            assert!((betai(2.5, 4.0, x) + betai(4.0, 2.5, 1.0 - x) - 1.0).abs() < 1e-14);
+        }
+        assert_eq!(betai(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(betai(2.0, 3.0, 1.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incomplete gamma function requires a > 0")]
+    fn test_gammp_invalid_a() {
+        gammp(0.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Incomplete beta function requires 0 <= x <= 1")]
+    fn test_betai_invalid_x() {
+        betai(1.0, 1.0, 1.5);
+    }
+}
@@ -0,0 +1,125 @@
+//! Legendre polynomials implementation
+//!
+//! The Legendre polynomials Pₙ(x) are orthogonal on [-1, 1] and appear in
+//! potential theory, spherical harmonics and Gaussian quadrature.
+
+/// Legendre polynomial Pₙ(x)
+///
+/// Evaluates the Legendre polynomial of degree `n` at `x`.
+///
+/// ## Mathematical Definition
+///
+/// The polynomials are generated by Bonnet's recurrence:
+///
+/// ```text
+/// P₀(x) = 1
+/// P₁(x) = x
+/// (k+1)·Pₖ₊₁(x) = (2k+1)·x·Pₖ(x) - k·Pₖ₋₁(x)
+/// ```
+///
+/// ## Properties
+///
+/// - Pₙ(1) = 1 and Pₙ(-1) = (-1)ⁿ
+/// - Pₙ has exactly `n` simple roots, all inside (-1, 1)
+/// - ∫₋₁¹ Pₘ(x)·Pₙ(x) dx = 0 for m ≠ n
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::legendre_p;
+///
+/// assert_eq!(legendre_p(0, 0.3), 1.0);
+/// assert_eq!(legendre_p(1, 0.3), 0.3);
+///
+/// // P₂(x) = (3x² - 1)/2
+/// assert!((legendre_p(2, 0.5) - (-0.125)).abs() < 1e-15);
+/// ```
+pub fn legendre_p(n: usize, x: f64) -> f64 {
+    legendre_pair(n, x).0
+}
+
+/// Derivative of the Legendre polynomial P'ₙ(x)
+///
+/// Uses the identity (x² - 1)·P'ₙ(x) = n·(x·Pₙ(x) - Pₙ₋₁(x)), falling back to
+/// the closed-form endpoint values P'ₙ(±1) = (±1)ⁿ⁺¹·n(n+1)/2.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::legendre_p_derivative;
+///
+/// // P₂'(x) = 3x
+/// assert!((legendre_p_derivative(2, 0.5) - 1.5).abs() < 1e-15);
+/// assert!((legendre_p_derivative(3, 1.0) - 6.0).abs() < 1e-15);
+/// ```
+pub fn legendre_p_derivative(n: usize, x: f64) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+
+    let endpoint = (n * (n + 1)) as f64 / 2.0;
+    if x == 1.0 {
+        return endpoint;
+    }
+    if x == -1.0 {
+        return if n.is_multiple_of(2) {
+            -endpoint
+        } else {
+            endpoint
+        };
+    }
+
+    let (p_n, p_prev) = legendre_pair(n, x);
+    n as f64 * (x * p_n - p_prev) / (x * x - 1.0)
+}
+
+/// Returns (Pₙ(x), Pₙ₋₁(x)) using Bonnet's recurrence
+fn legendre_pair(n: usize, x: f64) -> (f64, f64) {
+    if n == 0 {
+        return (1.0, 0.0);
+    }
+
+    let mut p_prev = 1.0;
+    let mut p = x;
+
+    for k in 1..n {
+        let k = k as f64;
+        let p_next = ((2.0 * k + 1.0) * x * p - k * p_prev) / (k + 1.0);
+        p_prev = p;
+        p = p_next;
+    }
+
+    (p, p_prev)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legendre_low_orders() {
+        let x: f64 = 0.7;
+        assert!((legendre_p(2, x) - (3.0 * x * x - 1.0) / 2.0).abs() < 1e-15);
+        assert!((legendre_p(3, x) - (5.0 * x.powi(3) - 3.0 * x) / 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_legendre_endpoints() {
+        for n in 0..10 {
+            assert!((legendre_p(n, 1.0) - 1.0).abs() < 1e-14);
+            let expected = if n.is_multiple_of(2) { 1.0 } else { -1.0 };
+            assert!((legendre_p(n, -1.0) - expected).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_legendre_derivative() {
+        // P₃'(x) = (15x² - 3)/2
+        let x: f64 = -0.4;
+        let expected = (15.0 * x * x - 3.0) / 2.0;
+        assert!((legendre_p_derivative(3, x) - expected).abs() < 1e-14);
+
+        // Endpoint values match the interior formula's limit
+        assert!((legendre_p_derivative(4, -1.0) - (-10.0)).abs() < 1e-14);
+    }
+}
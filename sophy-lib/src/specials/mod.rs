@@ -7,20 +7,70 @@
 //!
 //! ### Special Functions
 //! - [`gamma`]: Gamma function Γ(x) - extends factorials to real numbers
+//! - [`gamma_complex`]: Gamma function Γ(z) of a complex argument
+//! - [`gamma_ratio`]: Ratio Γ(a)/Γ(b) without overflow
+//! - [`gamma_min`]: Location and value of the minimum of Γ on the positive reals
+//! - [`gamma_series`]: Independent Γ(x) from Euler's product and Stirling's series, for cross-checks
+//! - [`lgamma_sign`]: Logarithm of |Γ(x)| with the sign of Γ(x), for all real x
+//! - [`digamma`], [`trigamma`]: Logarithmic derivatives ψ(x) and ψ'(x) of the gamma function
 //! - [`zeta`]: Riemann zeta function ζ(s) - fundamental in number theory  
-//! - [`erf`]: Error function erf(x) - critical for probability and statistics
+//! - [`erf`]: Error function erf(x) - critical for probability and statistics, with
+//!   [`erf_config`](erf::erf_config) to choose between fast and accurate methods
+//! - [`erfcx`]: Scaled complementary error function e^(x²)·erfc(x) for deep tails
+//! - [`faddeeva`]: Faddeeva function w(z), with [`erf_complex`] and [`erfc_complex`]
 //! - [`eta`]: Dirichlet eta function η(s) - alternating series variant of zeta
 //! - [`sigma`]: Sum of divisors function σ(n) - number theory and perfect numbers
+//! - [`sigma_k`]: Divisor function σₖ(n) - sum of k-th powers of divisors
+//! - [`divisors`]: All divisors of n in ascending order
 //! - [`is_perfect`]: Check if a number is perfect (σ(n) = 2n)
+//! - [`is_k_perfect`]: Check if σ(n) = k·n, with the [`abundancy_index`] σ(n)/n
+//! - [`sigma_range`]: σ(k) for a whole range by sieve, with [`perfect_numbers_up_to`] and
+//!   [`amicable_pairs_up_to`] searches built on it
+//! - [`sph_j0`], [`sph_j1`], [`sph_y0`]: Spherical Bessel functions - scattering and spherical waves
+//! - [`ai`], [`bi`]: Airy functions - solutions of y'' = x·y near turning points
+//! - [`hyp2f1`]: Gauss hypergeometric function ₂F₁(a, b; c; z) for |z| < 1
+//! - [`hyp1f1`]: Confluent hypergeometric function ₁F₁(a; b; z) (Kummer's function)
+//! - [`legendre_p`]: Legendre polynomial Pₙ(x) - orthogonal polynomials on [-1, 1]
+//! - [`ellf`], [`ellk`], [`ellpi`]: Elliptic integrals of the first and third kind
+//! - [`gammp`], [`gammq`], [`betai`]: Regularized incomplete gamma and beta functions
+//! - [`bernoulli`](bernoulli::bernoulli), [`bernoulli_poly`]: Bernoulli numbers Bₙ and polynomials Bₙ(x)
+//!
+//! ### Probability Distributions
+//! - [`normal_cdf`]: Standard normal cumulative distribution function
+//! - [`students_t_cdf`]: Student's t cumulative distribution function
+//! - [`chi_squared_cdf`]: Chi-squared cumulative distribution function
+//! - [`rayleigh_pdf`], [`rayleigh_cdf`], [`rayleigh_quantile`]: Rayleigh distribution
+//!
+//! ### Domain Validation
+//! - [`domain`]: Validators that give every special function the same panic message format
+//!
+//! ### Non-Panicking Variants
+//! - [`safe`]: The same functions returning `Result<_, DomainError>` instead of panicking
+//! - [`guard`]: Saturating [`erf_saturating`](guard::erf_saturating) and
+//!   [`gamma_saturating`](guard::gamma_saturating) for batch processing
 //!
 //! ## Module Organization
 //!
 //! Each function is implemented in its own module for better organization:
 //! - `gamma.rs` - Gamma function implementation
+//! - `lgamma.rs` - Log-gamma function with sign
+//! - `digamma.rs` - Digamma and trigamma functions
 //! - `zeta.rs` - Riemann zeta function implementation  
 //! - `erf.rs` - Error function implementation
+//! - `faddeeva.rs` - Faddeeva function and complex error functions
 //! - `eta.rs` - Dirichlet eta function implementation
 //! - `sigma.rs` - Sum of divisors function implementation
+//! - `hypergeometric.rs` - Gauss and confluent hypergeometric functions
+//! - `legendre.rs` - Legendre polynomials implementation
+//! - `bernoulli.rs` - Bernoulli numbers and polynomials
+//! - `elliptic.rs` - Elliptic integrals via Carlson's symmetric forms
+//! - `incomplete.rs` - Regularized incomplete gamma and beta functions
+//! - `dist.rs` - Cumulative distribution functions built on the incomplete functions
+//! - `airy.rs` - Airy functions implementation
+//! - `bessel.rs` - Spherical Bessel functions implementation
+//! - `domain.rs` - Shared argument validation and panic messages
+//! - `guard.rs` - Saturating wrappers that never panic
+//! - `safe.rs` - Result-returning wrappers over all of the above
 //!
 //! ## Usage Examples
 //!
@@ -62,15 +112,45 @@
 //! - **Zero external dependencies** - pure Rust implementations
 
 // Import individual function modules
+pub mod airy;
+pub mod bernoulli;
+pub mod bessel;
+pub mod digamma;
+pub mod dist;
+pub mod domain;
+pub mod elliptic;
 pub mod erf;
 pub mod eta;
+pub mod faddeeva;
 pub mod gamma;
+pub mod guard;
+pub mod hypergeometric;
+pub mod incomplete;
+pub mod legendre;
+pub mod lgamma;
+pub mod safe;
 pub mod sigma;
 pub mod zeta;
 
 // Re-export all public functions for convenient access
-pub use erf::erf;
+pub use airy::{ai, bi};
+pub use bernoulli::bernoulli_poly;
+pub use bessel::{sph_j0, sph_j1, sph_y0};
+pub use digamma::{digamma, trigamma};
+pub use dist::{
+    chi_squared_cdf, normal_cdf, rayleigh_cdf, rayleigh_pdf, rayleigh_quantile, students_t_cdf,
+};
+pub use elliptic::{ellf, ellk, ellpi};
+pub use erf::{erf, erfcx};
 pub use eta::eta;
-pub use gamma::gamma;
-pub use sigma::{is_perfect, sigma};
-pub use zeta::zeta;
+pub use faddeeva::{erf_complex, erfc_complex, faddeeva};
+pub use gamma::{gamma, gamma_complex, gamma_min, gamma_ratio, gamma_series};
+pub use hypergeometric::{hyp1f1, hyp2f1};
+pub use incomplete::{betai, gammp, gammq};
+pub use legendre::{legendre_p, legendre_p_derivative};
+pub use lgamma::lgamma_sign;
+pub use sigma::{
+    abundancy_index, amicable_pairs_up_to, divisors, is_k_perfect, is_perfect,
+    perfect_numbers_up_to, sigma, sigma_checked, sigma_k, sigma_k_checked, sigma_range,
+};
+pub use zeta::{zeta, zeta_with_progress};
@@ -0,0 +1,617 @@
+//! Non-panicking variants of the special functions
+//!
+//! Every function here mirrors the function of the same name in [`crate::specials`],
+//! but reports invalid input as a [`DomainError`] instead of panicking. This is
+//! useful in servers and WebAssembly modules, where a panic takes down the whole
+//! process rather than a single request.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use sophy::specials::safe::{self, DomainError};
+//!
+//! assert_eq!(safe::sigma(28), Ok(56));
+//! assert_eq!(safe::sigma(0), Err(DomainError::ZeroInput));
+//! assert_eq!(safe::zeta(1.0), Err(DomainError::Divergent));
+//! ```
+
+use crate::base::complex::Complex64;
+use crate::specials::guard::GAMMA_OVERFLOW;
+use std::fmt;
+
+/// The reason a special function cannot be evaluated at the given input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DomainError {
+    /// The function has a pole at the input, e.g. Γ(0)
+    Pole,
+    /// The defining series diverges at the input, e.g. ζ(1)
+    Divergent,
+    /// The input is zero where a positive integer is required, e.g. σ(0)
+    ZeroInput,
+    /// The input is valid mathematically but outside the range supported by the
+    /// implementation, e.g. Γ(-0.5)
+    OutOfRange,
+    /// The result does not fit in the return type, e.g. σ(n) beyond `u64` or Γ(200)
+    Overflow,
+    /// The input is NaN
+    NotANumber,
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DomainError::Pole => "function has a pole at this input",
+            DomainError::Divergent => "defining series diverges at this input",
+            DomainError::ZeroInput => "input must be a positive integer",
+            DomainError::OutOfRange => "input is outside the supported range",
+            DomainError::Overflow => "result overflows the return type",
+            DomainError::NotANumber => "input is NaN",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for DomainError {}
+
+/// Rejects NaN arguments, which the panicking functions silently propagate
+fn check_nan(x: f64) -> Result<(), DomainError> {
+    if x.is_nan() {
+        Err(DomainError::NotANumber)
+    } else {
+        Ok(())
+    }
+}
+
+/// Gamma function Γ(x), see [`crate::specials::gamma`]
+///
+/// Returns [`DomainError::Pole`] at the non-positive integers,
+/// [`DomainError::OutOfRange`] for other negative arguments and
+/// [`DomainError::Overflow`] where Γ(x) exceeds `f64::MAX`, above about 171.62
+/// and at +∞.
+pub fn gamma(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    if x <= 0.0 {
+        return Err(if x.fract() == 0.0 {
+            DomainError::Pole
+        } else {
+            DomainError::OutOfRange
+        });
+    }
+    // Rejected before delegating, since the recurrence would take one step per
+    // unit of x
+    if x > GAMMA_OVERFLOW {
+        return Err(DomainError::Overflow);
+    }
+
+    let value = super::gamma(x);
+    if value.is_infinite() {
+        return Err(DomainError::Overflow);
+    }
+    Ok(value)
+}
+
+/// Gamma function by Euler's product and Stirling's series, see
+/// [`crate::specials::gamma_series`]
+///
+/// Returns the same errors as [`gamma`].
+pub fn gamma_series(x: f64, terms: usize) -> Result<f64, DomainError> {
+    gamma(x)?;
+    Ok(super::gamma_series(x, terms))
+}
+
+/// Complex gamma function Γ(z), see [`crate::specials::gamma_complex`]
+///
+/// Returns [`DomainError::Pole`] at the non-positive integers.
+pub fn gamma_complex(z: Complex64) -> Result<Complex64, DomainError> {
+    check_nan(z.re)?;
+    check_nan(z.im)?;
+    if z.im == 0.0 && z.re <= 0.0 && z.re.fract() == 0.0 {
+        return Err(DomainError::Pole);
+    }
+    Ok(super::gamma_complex(z))
+}
+
+/// Gamma ratio Γ(a)/Γ(b), see [`crate::specials::gamma_ratio`]
+///
+/// Returns [`DomainError::Pole`] when a is a pole and b is not.
+pub fn gamma_ratio(a: f64, b: f64) -> Result<f64, DomainError> {
+    check_nan(a)?;
+    check_nan(b)?;
+    let is_pole = |x: f64| x <= 0.0 && x.fract() == 0.0;
+    if is_pole(a) && !is_pole(b) {
+        return Err(DomainError::Pole);
+    }
+    Ok(super::gamma_ratio(a, b))
+}
+
+/// Log-gamma with sign `(ln|Γ(x)|, sign)`, see [`crate::specials::lgamma_sign`]
+///
+/// Returns [`DomainError::Pole`] at the non-positive integers.
+pub fn lgamma_sign(x: f64) -> Result<(f64, f64), DomainError> {
+    check_nan(x)?;
+    if x <= 0.0 && x.fract() == 0.0 {
+        return Err(DomainError::Pole);
+    }
+    Ok(super::lgamma_sign(x))
+}
+
+/// Digamma function ψ(x), see [`crate::specials::digamma`]
+///
+/// Returns [`DomainError::Pole`] at the non-positive integers.
+pub fn digamma(x: f64) -> Result<f64, DomainError> {
+    lgamma_sign(x)?;
+    Ok(super::digamma(x))
+}
+
+/// Trigamma function ψ'(x), see [`crate::specials::trigamma`]
+///
+/// Returns [`DomainError::Pole`] at the non-positive integers.
+pub fn trigamma(x: f64) -> Result<f64, DomainError> {
+    lgamma_sign(x)?;
+    Ok(super::trigamma(x))
+}
+
+/// Riemann zeta function ζ(s), see [`crate::specials::zeta`]
+///
+/// Returns [`DomainError::Divergent`] for s ≤ 1.
+pub fn zeta(s: f64) -> Result<f64, DomainError> {
+    check_nan(s)?;
+    if s <= 1.0 {
+        return Err(DomainError::Divergent);
+    }
+    Ok(super::zeta(s))
+}
+
+/// Zeta functional-equation factor χ(s), see [`crate::specials::zeta::chi`]
+///
+/// Returns [`DomainError::Pole`] at the odd positive integers.
+pub fn chi(s: f64) -> Result<f64, DomainError> {
+    check_nan(s)?;
+    if s > 0.0 && s.fract() == 0.0 && s % 2.0 == 1.0 {
+        return Err(DomainError::Pole);
+    }
+    Ok(super::zeta::chi(s))
+}
+
+/// Dirichlet eta function η(s), see [`crate::specials::eta`]
+///
+/// Returns [`DomainError::Divergent`] for s ≤ 0.
+pub fn eta(s: f64) -> Result<f64, DomainError> {
+    check_nan(s)?;
+    if s <= 0.0 {
+        return Err(DomainError::Divergent);
+    }
+    Ok(super::eta(s))
+}
+
+/// Error function erf(x), see [`crate::specials::erf`]
+pub fn erf(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::erf(x))
+}
+
+/// Airy function Ai(x), see [`crate::specials::ai`]
+pub fn ai(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::ai(x))
+}
+
+/// Airy function Bi(x), see [`crate::specials::bi`]
+pub fn bi(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::bi(x))
+}
+
+/// Gauss hypergeometric function ₂F₁(a, b; c; z), see [`crate::specials::hyp2f1`]
+///
+/// Returns [`DomainError::Pole`] if `c` is a non-positive integer and
+/// [`DomainError::Divergent`] if |z| ≥ 1 and the series does not terminate.
+pub fn hyp2f1(a: f64, b: f64, c: f64, z: f64) -> Result<f64, DomainError> {
+    for x in [a, b, c, z] {
+        check_nan(x)?;
+    }
+    if c <= 0.0 && c.fract() == 0.0 {
+        return Err(DomainError::Pole);
+    }
+    let terminates = [a, b].iter().any(|&p| p <= 0.0 && p.fract() == 0.0);
+    if !terminates && z.abs() >= 1.0 {
+        return Err(DomainError::Divergent);
+    }
+    Ok(super::hyp2f1(a, b, c, z))
+}
+
+/// Confluent hypergeometric function ₁F₁(a; b; z), see [`crate::specials::hyp1f1`]
+///
+/// Returns [`DomainError::Pole`] if `b` is a non-positive integer.
+pub fn hyp1f1(a: f64, b: f64, z: f64) -> Result<f64, DomainError> {
+    for x in [a, b, z] {
+        check_nan(x)?;
+    }
+    if b <= 0.0 && b.fract() == 0.0 {
+        return Err(DomainError::Pole);
+    }
+    Ok(super::hyp1f1(a, b, z))
+}
+
+/// Spherical Bessel function j₀(x), see [`crate::specials::sph_j0`]
+pub fn sph_j0(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::sph_j0(x))
+}
+
+/// Spherical Bessel function j₁(x), see [`crate::specials::sph_j1`]
+pub fn sph_j1(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::sph_j1(x))
+}
+
+/// Spherical Bessel function y₀(x), see [`crate::specials::sph_y0`]
+///
+/// Returns [`DomainError::Pole`] at x = 0.
+pub fn sph_y0(x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    if x == 0.0 {
+        return Err(DomainError::Pole);
+    }
+    Ok(super::sph_y0(x))
+}
+
+/// Legendre polynomial Pₙ(x), see [`crate::specials::legendre_p`]
+pub fn legendre_p(n: usize, x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::legendre_p(n, x))
+}
+
+/// Derivative P'ₙ(x), see [`crate::specials::legendre_p_derivative`]
+pub fn legendre_p_derivative(n: usize, x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::legendre_p_derivative(n, x))
+}
+
+/// Bernoulli polynomial Bₙ(x), see [`crate::specials::bernoulli_poly`]
+pub fn bernoulli_poly(n: u32, x: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    Ok(super::bernoulli_poly(n, x))
+}
+
+/// Faddeeva function w(z), see [`crate::specials::faddeeva`]
+pub fn faddeeva(z: Complex64) -> Result<Complex64, DomainError> {
+    check_nan(z.re)?;
+    check_nan(z.im)?;
+    Ok(super::faddeeva(z))
+}
+
+/// Complex error function erf(z), see [`crate::specials::erf_complex`]
+pub fn erf_complex(z: Complex64) -> Result<Complex64, DomainError> {
+    check_nan(z.re)?;
+    check_nan(z.im)?;
+    Ok(super::erf_complex(z))
+}
+
+/// Complex complementary error function erfc(z), see [`crate::specials::erfc_complex`]
+pub fn erfc_complex(z: Complex64) -> Result<Complex64, DomainError> {
+    check_nan(z.re)?;
+    check_nan(z.im)?;
+    Ok(super::erfc_complex(z))
+}
+
+/// Regularized lower incomplete gamma function P(a, x), see [`crate::specials::gammp`]
+///
+/// Returns [`DomainError::OutOfRange`] if `a <= 0` or `x < 0`.
+pub fn gammp(a: f64, x: f64) -> Result<f64, DomainError> {
+    check_nan(a)?;
+    check_nan(x)?;
+    if a <= 0.0 || x < 0.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::gammp(a, x))
+}
+
+/// Regularized upper incomplete gamma function Q(a, x), see [`crate::specials::gammq`]
+///
+/// Returns [`DomainError::OutOfRange`] if `a <= 0` or `x < 0`.
+pub fn gammq(a: f64, x: f64) -> Result<f64, DomainError> {
+    check_nan(a)?;
+    check_nan(x)?;
+    if a <= 0.0 || x < 0.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::gammq(a, x))
+}
+
+/// Regularized incomplete beta function Iₓ(a, b), see [`crate::specials::betai`]
+///
+/// Returns [`DomainError::OutOfRange`] if `a <= 0`, `b <= 0` or `x` lies outside [0, 1].
+pub fn betai(a: f64, b: f64, x: f64) -> Result<f64, DomainError> {
+    for v in [a, b, x] {
+        check_nan(v)?;
+    }
+    if a <= 0.0 || b <= 0.0 || !(0.0..=1.0).contains(&x) {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::betai(a, b, x))
+}
+
+/// Student's t CDF, see [`crate::specials::students_t_cdf`]
+///
+/// Returns [`DomainError::OutOfRange`] if `df <= 0`.
+pub fn students_t_cdf(t: f64, df: f64) -> Result<f64, DomainError> {
+    check_nan(t)?;
+    check_nan(df)?;
+    if df <= 0.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::students_t_cdf(t, df))
+}
+
+/// Chi-squared CDF, see [`crate::specials::chi_squared_cdf`]
+///
+/// Returns [`DomainError::OutOfRange`] if `k <= 0`.
+pub fn chi_squared_cdf(x: f64, k: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    check_nan(k)?;
+    if k <= 0.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::chi_squared_cdf(x, k))
+}
+
+/// Incomplete elliptic integral of the first kind F(φ, k), see [`crate::specials::ellf`]
+///
+/// Returns [`DomainError::OutOfRange`] if |φ| > π/2 or k² sin²φ > 1.
+pub fn ellf(phi: f64, k: f64) -> Result<f64, DomainError> {
+    check_nan(phi)?;
+    check_nan(k)?;
+    let s = phi.sin();
+    if phi.abs() > 0.5 * crate::base::numbers::PI || (k * s).abs() > 1.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::ellf(phi, k))
+}
+
+/// Complete elliptic integral of the first kind K(k), see [`crate::specials::ellk`]
+///
+/// Returns [`DomainError::Pole`] for |k| = 1 and [`DomainError::OutOfRange`] for
+/// |k| > 1.
+pub fn ellk(k: f64) -> Result<f64, DomainError> {
+    check_nan(k)?;
+    if k.abs() == 1.0 {
+        return Err(DomainError::Pole);
+    }
+    if k.abs() > 1.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::ellk(k))
+}
+
+/// Incomplete elliptic integral of the third kind Π(n, φ, k), see
+/// [`crate::specials::ellpi`]
+///
+/// Returns [`DomainError::OutOfRange`] if |φ| > π/2 or k² sin²φ > 1, and
+/// [`DomainError::Divergent`] if n sin²φ ≥ 1.
+pub fn ellpi(n: f64, phi: f64, k: f64) -> Result<f64, DomainError> {
+    for v in [n, phi, k] {
+        check_nan(v)?;
+    }
+    ellf(phi, k)?;
+    let s = phi.sin();
+    if n * s * s >= 1.0 {
+        return Err(DomainError::Divergent);
+    }
+    Ok(super::ellpi(n, phi, k))
+}
+
+/// Rayleigh probability density, see [`crate::specials::rayleigh_pdf`]
+///
+/// Returns [`DomainError::OutOfRange`] if `sigma <= 0`.
+pub fn rayleigh_pdf(x: f64, sigma: f64) -> Result<f64, DomainError> {
+    check_nan(x)?;
+    check_nan(sigma)?;
+    if sigma <= 0.0 {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::rayleigh_pdf(x, sigma))
+}
+
+/// Rayleigh CDF, see [`crate::specials::rayleigh_cdf`]
+///
+/// Returns [`DomainError::OutOfRange`] if `sigma <= 0`.
+pub fn rayleigh_cdf(x: f64, sigma: f64) -> Result<f64, DomainError> {
+    rayleigh_pdf(x, sigma)?;
+    Ok(super::rayleigh_cdf(x, sigma))
+}
+
+/// Rayleigh quantile, see [`crate::specials::rayleigh_quantile`]
+///
+/// Returns [`DomainError::OutOfRange`] if `sigma <= 0` or `p` lies outside [0, 1].
+pub fn rayleigh_quantile(p: f64, sigma: f64) -> Result<f64, DomainError> {
+    check_nan(p)?;
+    check_nan(sigma)?;
+    if sigma <= 0.0 || !(0.0..=1.0).contains(&p) {
+        return Err(DomainError::OutOfRange);
+    }
+    Ok(super::rayleigh_quantile(p, sigma))
+}
+
+/// Sum of divisors σ(n), see [`crate::specials::sigma`]
+///
+/// Returns [`DomainError::ZeroInput`] for n = 0 and [`DomainError::Overflow`] if
+/// the sum does not fit in a `u64`.
+pub fn sigma(n: u64) -> Result<u64, DomainError> {
+    sigma_k(n, 1)
+}
+
+/// Divisor function σₖ(n), see [`crate::specials::sigma_k`]
+///
+/// Returns [`DomainError::ZeroInput`] for n = 0 and [`DomainError::Overflow`] if
+/// the sum does not fit in a `u64`.
+pub fn sigma_k(n: u64, k: u32) -> Result<u64, DomainError> {
+    if n == 0 {
+        return Err(DomainError::ZeroInput);
+    }
+    super::sigma_k_checked(n, k).ok_or(DomainError::Overflow)
+}
+
+/// Divisors of n in ascending order, see [`crate::specials::divisors`]
+///
+/// Returns [`DomainError::ZeroInput`] for n = 0.
+pub fn divisors(n: u64) -> Result<Vec<u64>, DomainError> {
+    if n == 0 {
+        return Err(DomainError::ZeroInput);
+    }
+    Ok(super::divisors(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_gamma() {
+        assert_eq!(gamma(5.0), Ok(crate::specials::gamma(5.0)));
+        assert_eq!(gamma(0.0), Err(DomainError::Pole));
+        assert_eq!(gamma(-3.0), Err(DomainError::Pole));
+        assert_eq!(gamma(-0.5), Err(DomainError::OutOfRange));
+        assert_eq!(gamma(f64::NAN), Err(DomainError::NotANumber));
+        assert!(gamma(171.5).unwrap().is_finite());
+        assert_eq!(gamma(171.65), Err(DomainError::Overflow));
+        assert_eq!(gamma(1e7), Err(DomainError::Overflow));
+        assert_eq!(gamma(f64::INFINITY), Err(DomainError::Overflow));
+        assert_eq!(gamma(f64::NEG_INFINITY), Err(DomainError::OutOfRange));
+
+        assert_eq!(lgamma_sign(-0.5).map(|(_, sign)| sign), Ok(-1.0));
+        assert_eq!(lgamma_sign(-2.0), Err(DomainError::Pole));
+
+        assert_eq!(gamma_series(0.0, 30), Err(DomainError::Pole));
+        assert_eq!(gamma_series(-0.5, 30), Err(DomainError::OutOfRange));
+        assert!(gamma_series(4.0, 30).is_ok());
+        assert_eq!(gamma_series(1e7, 30), Err(DomainError::Overflow));
+        assert_eq!(gamma_series(f64::INFINITY, 30), Err(DomainError::Overflow));
+
+        assert_eq!(digamma(1.0), Ok(crate::specials::digamma(1.0)));
+        assert_eq!(digamma(-1.0), Err(DomainError::Pole));
+        assert_eq!(trigamma(0.0), Err(DomainError::Pole));
+        assert_eq!(trigamma(f64::NAN), Err(DomainError::NotANumber));
+
+        assert!((gamma_complex(Complex64::ONE).unwrap() - Complex64::ONE).norm() < 1e-15);
+        assert_eq!(
+            gamma_complex(Complex64::new(-1.0, 0.0)),
+            Err(DomainError::Pole)
+        );
+        assert!(gamma_complex(Complex64::new(-1.0, 0.5)).is_ok());
+
+        assert_eq!(gamma_ratio(10.0, 8.0), Ok(72.0));
+        assert_eq!(gamma_ratio(-1.0, 2.0), Err(DomainError::Pole));
+        assert_eq!(gamma_ratio(2.0, -1.0), Ok(0.0));
+        assert_eq!(gamma_ratio(1.0, f64::NAN), Err(DomainError::NotANumber));
+    }
+
+    #[test]
+    fn test_safe_incomplete_and_distributions() {
+        assert_eq!(gammp(1.0, 0.0), Ok(0.0));
+        assert_eq!(gammp(1.0, f64::INFINITY), Ok(1.0));
+        assert_eq!(gammq(1.0, f64::INFINITY), Ok(0.0));
+        assert_eq!(gammp(0.0, 1.0), Err(DomainError::OutOfRange));
+        assert_eq!(gammp(1.0, -1.0), Err(DomainError::OutOfRange));
+        assert_eq!(gammq(1.0, 0.0), Ok(1.0));
+        assert_eq!(gammq(-1.0, 1.0), Err(DomainError::OutOfRange));
+        assert_eq!(betai(2.0, 3.0, 1.0), Ok(1.0));
+        assert_eq!(betai(2.0, 3.0, 1.5), Err(DomainError::OutOfRange));
+        assert_eq!(betai(f64::NAN, 3.0, 0.5), Err(DomainError::NotANumber));
+
+        assert_eq!(students_t_cdf(0.0, 4.0), Ok(0.5));
+        assert_eq!(students_t_cdf(0.0, 0.0), Err(DomainError::OutOfRange));
+        assert_eq!(chi_squared_cdf(0.0, 4.0), Ok(0.0));
+        assert_eq!(chi_squared_cdf(1.0, -1.0), Err(DomainError::OutOfRange));
+
+        assert_eq!(rayleigh_cdf(0.0, 1.0), Ok(0.0));
+        assert_eq!(rayleigh_pdf(1.0, -1.0), Err(DomainError::OutOfRange));
+        assert_eq!(rayleigh_quantile(1.0, 2.0), Ok(f64::INFINITY));
+        assert_eq!(rayleigh_quantile(-0.1, 2.0), Err(DomainError::OutOfRange));
+    }
+
+    #[test]
+    fn test_safe_zeta_and_eta() {
+        assert_eq!(zeta(2.0), Ok(crate::specials::zeta(2.0)));
+        assert_eq!(zeta(1.0), Err(DomainError::Divergent));
+        assert_eq!(zeta(0.5), Err(DomainError::Divergent));
+        assert_eq!(chi(0.0), Ok(0.0));
+        assert_eq!(chi(5.0), Err(DomainError::Pole));
+
+        assert_eq!(eta(1.0), Ok(2.0_f64.ln()));
+        assert_eq!(eta(0.0), Err(DomainError::Divergent));
+        assert_eq!(eta(f64::NAN), Err(DomainError::NotANumber));
+    }
+
+    #[test]
+    fn test_safe_hyp2f1() {
+        assert_eq!(hyp2f1(1.0, 2.0, 3.0, 0.0), Ok(1.0));
+        assert_eq!(hyp2f1(1.0, 1.0, -1.0, 0.5), Err(DomainError::Pole));
+        assert_eq!(hyp2f1(1.0, 1.0, 2.0, -1.5), Err(DomainError::Divergent));
+        assert_eq!(hyp2f1(-1.0, 1.0, 1.0, 2.0), Ok(-1.0));
+
+        assert_eq!(hyp1f1(1.0, 2.0, 0.0), Ok(1.0));
+        assert_eq!(hyp1f1(1.0, -3.0, 0.5), Err(DomainError::Pole));
+    }
+
+    #[test]
+    fn test_safe_elliptic() {
+        assert_eq!(ellf(0.8, 0.0), Ok(crate::specials::ellf(0.8, 0.0)));
+        assert_eq!(ellf(2.0, 0.5), Err(DomainError::OutOfRange));
+        assert_eq!(ellf(1.0, 1.5), Err(DomainError::OutOfRange));
+        assert_eq!(ellk(0.5), Ok(crate::specials::ellk(0.5)));
+        assert_eq!(ellk(-1.0), Err(DomainError::Pole));
+        assert_eq!(ellk(1.2), Err(DomainError::OutOfRange));
+        assert_eq!(ellpi(0.0, 0.9, 0.4), ellf(0.9, 0.4));
+        assert_eq!(ellpi(2.0, 1.2, 0.3), Err(DomainError::Divergent));
+        assert_eq!(ellpi(f64::NAN, 0.5, 0.3), Err(DomainError::NotANumber));
+    }
+
+    #[test]
+    fn test_safe_sigma() {
+        assert_eq!(sigma(28), Ok(56));
+        assert_eq!(sigma(0), Err(DomainError::ZeroInput));
+        assert_eq!(sigma_k(6, 2), Ok(50));
+        assert_eq!(sigma_k(0, 2), Err(DomainError::ZeroInput));
+        assert_eq!(sigma_k(1 << 32, 2), Err(DomainError::Overflow));
+        assert_eq!(divisors(6), Ok(vec![1, 2, 3, 6]));
+        assert_eq!(divisors(0), Err(DomainError::ZeroInput));
+    }
+
+    #[test]
+    fn test_safe_total_functions_reject_nan() {
+        assert_eq!(erf(0.0), Ok(0.0));
+        assert_eq!(erf(f64::NAN), Err(DomainError::NotANumber));
+        assert_eq!(ai(f64::NAN), Err(DomainError::NotANumber));
+        assert_eq!(bi(f64::NAN), Err(DomainError::NotANumber));
+        assert_eq!(sph_j0(0.0), Ok(1.0));
+        assert_eq!(sph_j1(f64::NAN), Err(DomainError::NotANumber));
+        assert_eq!(sph_y0(0.0), Err(DomainError::Pole));
+        assert_eq!(legendre_p(2, 1.0), Ok(1.0));
+        assert_eq!(legendre_p(2, f64::NAN), Err(DomainError::NotANumber));
+        assert_eq!(bernoulli_poly(1, 0.75), Ok(0.25));
+        assert_eq!(bernoulli_poly(4, f64::NAN), Err(DomainError::NotANumber));
+        assert_eq!(
+            legendre_p_derivative(3, f64::NAN),
+            Err(DomainError::NotANumber)
+        );
+
+        let nan = Complex64::new(0.0, f64::NAN);
+        assert_eq!(faddeeva(nan), Err(DomainError::NotANumber));
+        assert_eq!(erf_complex(nan), Err(DomainError::NotANumber));
+        assert_eq!(erfc_complex(nan), Err(DomainError::NotANumber));
+        assert_eq!(erf_complex(Complex64::ZERO), Ok(Complex64::ZERO));
+    }
+
+    #[test]
+    fn test_domain_error_display() {
+        assert_eq!(
+            DomainError::Pole.to_string(),
+            "function has a pole at this input"
+        );
+        assert_eq!(
+            DomainError::ZeroInput.to_string(),
+            "input must be a positive integer"
+        );
+    }
+}
@@ -3,6 +3,9 @@
 //! The sum of divisors function σ(n) is fundamental in number theory
 //! and appears in the study of perfect numbers and arithmetic functions.
 
+use crate::base::rational::Rational;
+use crate::specials::domain::require_nonzero;
+
 /// Sum of divisors function σ(n) - sum of all positive divisors of n
 ///
 /// The sum of divisors function returns the sum of all positive divisors of n,
@@ -29,6 +32,12 @@
 ///
 /// Uses efficient algorithm that only checks divisors up to √n, with O(√n) complexity.
 ///
+/// ## Overflow
+///
+/// σ(n) exceeds n, so for n close to `u64::MAX` the sum overflows: debug builds
+/// panic and release builds silently wrap to a wrong result. Use [`sigma_checked`]
+/// when the input may be that large.
+///
 /// ## Examples
 ///
 /// ```rust
@@ -48,9 +57,7 @@
 ///
 /// Panics if n = 0, as the sum of divisors is undefined for zero.
 pub fn sigma(n: u64) -> u64 {
-    if n == 0 {
-        panic!("Sum of divisors undefined for n = 0");
-    }
+    require_nonzero(n, "sigma");
 
     if n == 1 {
         return 1;
@@ -72,6 +79,147 @@ pub fn sigma(n: u64) -> u64 {
     sum
 }
 
+/// Checked sum of divisors, returning `None` if σ(n) does not fit in a `u64`
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sigma_checked;
+///
+/// assert_eq!(sigma_checked(28), Some(56));
+/// assert_eq!(sigma_checked(u64::MAX), None);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if n = 0, as the sum of divisors is undefined for zero.
+pub fn sigma_checked(n: u64) -> Option<u64> {
+    require_nonzero(n, "sigma_checked");
+    sigma_k_checked(n, 1)
+}
+
+/// Divisor function σₖ(n) - sum of the k-th powers of the divisors of n
+///
+/// ## Mathematical Definition
+///
+/// σₖ(n) = Σ dᵏ, where the sum is over all positive divisors d of n
+///
+/// - σ₀(n) counts the divisors of n
+/// - σ₁(n) is the ordinary sum of divisors [`sigma`]
+///
+/// ## Overflow
+///
+/// The powers dᵏ grow very quickly (σ₂ already overflows for n around 4·10⁹).
+/// On overflow debug builds panic and release builds silently wrap to a wrong
+/// result. Use [`sigma_k_checked`] unless the inputs are known to be small.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sigma_k;
+///
+/// assert_eq!(sigma_k(12, 0), 6);    // 1, 2, 3, 4, 6, 12
+/// assert_eq!(sigma_k(12, 1), 28);   // σ(12)
+/// assert_eq!(sigma_k(6, 2), 50);    // 1 + 4 + 9 + 36
+/// ```
+///
+/// ## Panics
+///
+/// Panics if n = 0, as the sum of divisors is undefined for zero.
+pub fn sigma_k(n: u64, k: u32) -> u64 {
+    require_nonzero(n, "sigma_k");
+
+    let mut sum = 0;
+    let sqrt_n = (n as f64).sqrt() as u64;
+
+    for i in 1..=sqrt_n {
+        if n.is_multiple_of(i) {
+            sum += i.pow(k);
+            if i != n / i {
+                sum += (n / i).pow(k);
+            }
+        }
+    }
+
+    sum
+}
+
+/// Checked divisor function, returning `None` if σₖ(n) does not fit in a `u64`
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sigma_k_checked;
+///
+/// assert_eq!(sigma_k_checked(6, 2), Some(50));
+/// assert_eq!(sigma_k_checked(1 << 32, 2), None);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if n = 0, as the sum of divisors is undefined for zero.
+pub fn sigma_k_checked(n: u64, k: u32) -> Option<u64> {
+    require_nonzero(n, "sigma_k_checked");
+
+    let mut sum: u64 = 0;
+    let sqrt_n = (n as f64).sqrt() as u64;
+
+    for i in 1..=sqrt_n {
+        if n.is_multiple_of(i) {
+            sum = sum.checked_add(i.checked_pow(k)?)?;
+            if i != n / i {
+                sum = sum.checked_add((n / i).checked_pow(k)?)?;
+            }
+        }
+    }
+
+    Some(sum)
+}
+
+/// All positive divisors of n in ascending order
+///
+/// ## Implementation
+///
+/// Divisors come in pairs (d, n/d) with d ≤ √n. The loop runs over d ≤ √n only,
+/// collecting the small members of each pair in ascending order and the large
+/// ones in descending order, so the two lists join into a sorted result without
+/// a final sort. A perfect square contributes its root once.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::divisors;
+///
+/// assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+/// assert_eq!(divisors(1), [1]);
+/// assert_eq!(divisors(36).len(), 9);  // perfect squares have an odd count
+/// ```
+///
+/// ## Panics
+///
+/// Panics if n = 0, as every positive integer divides zero.
+pub fn divisors(n: u64) -> Vec<u64> {
+    require_nonzero(n, "divisors");
+
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+
+    // d <= n / d is d² <= n without overflowing
+    let mut d = 1;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            small.push(d);
+            if d != n / d {
+                large.push(n / d);
+            }
+        }
+        d += 1;
+    }
+
+    small.extend(large.into_iter().rev());
+    small
+}
+
 /// Check if a number is perfect (σ(n) = 2n)
 ///
 /// A perfect number is a positive integer that is equal to the sum of its
@@ -94,6 +242,127 @@ pub fn is_perfect(n: u64) -> bool {
     sigma(n) == 2 * n
 }
 
+/// Check if a number is multiply perfect of order k (σ(n) = k·n)
+///
+/// Perfect numbers are the case k = 2. For k = 1 only n = 1 qualifies, since
+/// σ(n) ≥ n + 1 for every n > 1.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::is_k_perfect;
+///
+/// assert!(is_k_perfect(28, 2));
+/// assert!(is_k_perfect(120, 3));  // σ(120) = 360
+/// assert!(!is_k_perfect(120, 2));
+/// ```
+///
+/// ## Panics
+///
+/// Panics if n = 0, as the sum of divisors is undefined for zero.
+pub fn is_k_perfect(n: u64, k: u64) -> bool {
+    sigma(n) as u128 == k as u128 * n as u128
+}
+
+/// Abundancy index σ(n)/n as a fraction in lowest terms
+///
+/// The index is 2 for perfect numbers, above 2 for abundant numbers and below
+/// 2 for deficient ones; it is an integer k exactly when n is k-perfect.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::base::rational::Rational;
+/// use sophy::specials::abundancy_index;
+///
+/// assert_eq!(abundancy_index(6), Rational::new(2, 1));
+/// assert_eq!(abundancy_index(12), Rational::new(7, 3));  // σ(12) = 28
+/// ```
+///
+/// ## Panics
+///
+/// Panics if n = 0, or if σ(n) does not fit in an `i64`.
+pub fn abundancy_index(n: u64) -> Rational {
+    let sum = i64::try_from(sigma(n)).expect("abundancy_index: σ(n) overflows i64");
+    Rational::new(sum, n)
+}
+
+/// Sums of divisors σ(k) for every k in 0..=n, by a divisor sieve
+///
+/// Entry k of the result is σ(k), with σ(0) reported as 0. Every d ≤ n is added
+/// to each of its multiples, which costs O(n log n) additions in total — far
+/// cheaper than calling [`sigma`] n times when a whole range is needed.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sigma::sigma_range;
+///
+/// assert_eq!(sigma_range(6), [0, 1, 3, 4, 7, 6, 12]);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if the n + 1 entries cannot be allocated.
+pub fn sigma_range(n: u64) -> Vec<u64> {
+    let n = n as usize;
+    let mut sums = vec![0; n + 1];
+    for d in 1..=n {
+        for multiple in (d..=n).step_by(d) {
+            sums[multiple] += d as u64;
+        }
+    }
+    sums
+}
+
+/// All perfect numbers up to and including n, in ascending order
+///
+/// Uses the [`sigma_range`] sieve, so the search costs O(n log n).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sigma::perfect_numbers_up_to;
+///
+/// assert_eq!(perfect_numbers_up_to(10_000), [6, 28, 496, 8128]);
+/// ```
+pub fn perfect_numbers_up_to(n: u64) -> Vec<u64> {
+    sigma_range(n)
+        .into_iter()
+        .zip(0..)
+        .filter(|&(sum, k)| k > 1 && sum == 2 * k)
+        .map(|(_, k)| k)
+        .collect()
+}
+
+/// All amicable pairs (a, b) with a < b ≤ n, ordered by a
+///
+/// Two distinct numbers are amicable when each is the sum of the proper
+/// divisors of the other: σ(a) - a = b and σ(b) - b = a. A pair is only
+/// reported if both members are at most n. Perfect numbers, which pair with
+/// themselves, are excluded.
+///
+/// Uses the [`sigma_range`] sieve, so the search costs O(n log n).
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::sigma::amicable_pairs_up_to;
+///
+/// assert_eq!(amicable_pairs_up_to(1500), [(220, 284), (1184, 1210)]);
+/// ```
+pub fn amicable_pairs_up_to(n: u64) -> Vec<(u64, u64)> {
+    let sums = sigma_range(n);
+    let aliquot = |k: u64| sums[k as usize] - k;
+
+    (2..=n)
+        .filter_map(|a| {
+            let b = aliquot(a);
+            (a < b && b <= n && aliquot(b) == a).then_some((a, b))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,8 +408,151 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Sum of divisors undefined for n = 0")]
+    fn test_is_k_perfect() {
+        assert!(is_k_perfect(6, 2));
+        assert!(is_k_perfect(28, 2));
+        assert!(is_k_perfect(120, 3));
+        assert!(is_k_perfect(30_240, 4));
+        assert!(!is_k_perfect(120, 2));
+        assert!(!is_k_perfect(12, 2));
+
+        // k = 1 only for n = 1, and k = 2 agrees with is_perfect
+        assert!((1..=1000).filter(|&n| is_k_perfect(n, 1)).eq([1]));
+        assert!((2..=1000).all(|n| is_k_perfect(n, 2) == is_perfect(n)));
+    }
+
+    #[test]
+    fn test_abundancy_index() {
+        assert_eq!(abundancy_index(1), Rational::new(1, 1));
+        assert_eq!(abundancy_index(6), Rational::new(2, 1));
+        assert_eq!(abundancy_index(120), Rational::new(3, 1));
+        assert_eq!(abundancy_index(12), Rational::new(7, 3));
+        // Prime p: (p + 1)/p
+        assert_eq!(abundancy_index(13), Rational::new(14, 13));
+
+        for n in 1..=500 {
+            let index = abundancy_index(n);
+            assert_eq!(index.denom() == 1, is_k_perfect(n, index.numer() as u64));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "sigma: argument must be a positive integer, got 0")]
     fn test_sigma_zero() {
         sigma(0);
     }
+
+    #[test]
+    fn test_sigma_checked() {
+        assert_eq!(sigma_checked(1), Some(1));
+        assert_eq!(sigma_checked(496), Some(992));
+
+        // σ(n) ≥ n + 1, which cannot be represented for n = u64::MAX
+        assert_eq!(sigma_checked(u64::MAX), None);
+        assert_eq!(sigma_checked(u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_sigma_k() {
+        // σ₁ agrees with sigma
+        for n in 1..100 {
+            assert_eq!(sigma_k(n, 1), sigma(n));
+        }
+
+        // For prime p: σₖ(p) = 1 + pᵏ
+        assert_eq!(sigma_k(7, 3), 1 + 343);
+        assert_eq!(sigma_k(1, 5), 1);
+        assert_eq!(sigma_k(16, 0), 5);
+    }
+
+    #[test]
+    fn test_sigma_k_checked() {
+        assert_eq!(sigma_k_checked(12, 2), Some(sigma_k(12, 2)));
+
+        // (2³²)² alone is 2⁶⁴, one past u64::MAX
+        assert_eq!(sigma_k_checked(1 << 32, 2), None);
+        assert_eq!(sigma_k_checked(10, 64), None);
+    }
+
+    #[test]
+    fn test_divisors_basic() {
+        assert_eq!(divisors(1), [1]);
+        assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+        assert_eq!(divisors(28), [1, 2, 4, 7, 14, 28]);
+    }
+
+    #[test]
+    fn test_divisors_perfect_square_and_prime() {
+        // The square root appears exactly once
+        assert_eq!(divisors(49), [1, 7, 49]);
+        assert_eq!(divisors(144).len(), 15);
+
+        for p in [2, 97, 7919, 1_000_000_007] {
+            assert_eq!(divisors(p), [1, p]);
+        }
+    }
+
+    #[test]
+    fn test_divisors_sum_to_sigma() {
+        for n in 1..500 {
+            let list = divisors(n);
+            assert!(list.windows(2).all(|w| w[0] < w[1]), "n = {n}");
+            assert_eq!(list.iter().sum::<u64>(), sigma(n));
+            assert_eq!(list.len() as u64, sigma_k(n, 0));
+        }
+    }
+
+    #[test]
+    fn test_sigma_range_matches_sigma() {
+        let sums = sigma_range(1000);
+        assert_eq!(sums.len(), 1001);
+        assert_eq!(sums[0], 0);
+        for n in 1..=1000 {
+            assert_eq!(sums[n as usize], sigma(n), "n = {n}");
+        }
+        assert_eq!(sigma_range(0), [0]);
+    }
+
+    #[test]
+    fn test_perfect_numbers_up_to() {
+        assert_eq!(perfect_numbers_up_to(10_000), [6, 28, 496, 8128]);
+        assert_eq!(perfect_numbers_up_to(28), [6, 28]);
+        assert!(perfect_numbers_up_to(5).is_empty());
+
+        let found = perfect_numbers_up_to(10_000);
+        assert!((1..=10_000).filter(|&n| is_perfect(n)).eq(found));
+    }
+
+    #[test]
+    fn test_amicable_pairs_up_to() {
+        let pairs = amicable_pairs_up_to(1500);
+        assert!(pairs.contains(&(220, 284)));
+        assert!(pairs.contains(&(1184, 1210)));
+        assert_eq!(pairs.len(), 2);
+
+        // The first eight pairs
+        assert_eq!(
+            amicable_pairs_up_to(20_000),
+            [
+                (220, 284),
+                (1184, 1210),
+                (2620, 2924),
+                (5020, 5564),
+                (6232, 6368),
+                (10_744, 10_856),
+                (12_285, 14_595),
+                (17_296, 18_416),
+            ]
+        );
+
+        // Only pairs with both members in range
+        assert!(amicable_pairs_up_to(250).is_empty());
+        assert_eq!(amicable_pairs_up_to(284), [(220, 284)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "divisors: argument must be a positive integer, got 0")]
+    fn test_divisors_zero() {
+        divisors(0);
+    }
 }
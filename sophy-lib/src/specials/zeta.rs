@@ -4,6 +4,8 @@
 //! in many areas of mathematics including the famous Riemann Hypothesis.
 
 use crate::base::numbers::PI;
+use crate::specials::domain::{require_gt, require_not_odd_positive};
+use crate::specials::lgamma::lgamma_sign;
 
 /// Riemann zeta function ζ(s) - fundamental function in number theory
 ///
@@ -45,9 +47,36 @@ use crate::base::numbers::PI;
 ///
 /// Panics if s ≤ 1, as the series diverges for s ≤ 1.
 pub fn zeta(s: f64) -> f64 {
-    if s <= 1.0 {
-        panic!("Zeta function implementation requires s > 1");
-    }
+    zeta_with_progress(s, |_, _| {})
+}
+
+/// Riemann zeta function with a progress callback
+///
+/// Identical to [`zeta`], but calls `on_iter(n, partial_sum)` after adding each
+/// term 1/nˢ of the series. Close to s = 1 the series needs up to a million terms,
+/// so the callback can report progress or abort the computation by panicking.
+/// The exactly known values ζ(2) and ζ(4) are returned without iterating.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::zeta::zeta_with_progress;
+///
+/// let mut terms = 0;
+/// let value = zeta_with_progress(3.0, |n, _| terms = n);
+///
+/// assert!((value - 1.202_056_903_159_594).abs() < 1e-9);
+/// assert!(terms > 1000);
+/// ```
+///
+/// ## Panics
+///
+/// Panics if s ≤ 1, as the series diverges for s ≤ 1.
+pub fn zeta_with_progress<P>(s: f64, mut on_iter: P) -> f64
+where
+    P: FnMut(usize, f64),
+{
+    require_gt(s, 1.0, "zeta");
 
     // For known values, return exact results
     if (s - 2.0).abs() < 1e-15 {
@@ -68,6 +97,7 @@ pub fn zeta(s: f64) -> f64 {
             break;
         }
         sum += term;
+        on_iter(n as usize, sum);
         n += 1.0;
 
         // Prevent infinite loops
@@ -79,6 +109,58 @@ pub fn zeta(s: f64) -> f64 {
     sum
 }
 
+/// Factor χ(s) of the functional equation ζ(s) = χ(s)·ζ(1 - s)
+///
+/// ## Mathematical Definition
+///
+/// χ(s) = 2ˢ πˢ⁻¹ sin(πs/2) Γ(1 - s) = (2π)ˢ / (2 cos(πs/2) Γ(s))
+///
+/// The two forms agree by the reflection formula for Γ. χ(s)·χ(1 - s) = 1, and
+/// χ vanishes at s = 0, -2, -4, ..., which produces the trivial zeros of ζ.
+///
+/// ## Implementation
+///
+/// For s ≥ 1/2 the second form is used, so χ stays finite at the even positive
+/// integers where sin(πs/2) and Γ(1 - s) would give 0·∞. Smaller arguments use
+/// the first form. The gamma factor is evaluated in log form with
+/// [`lgamma_sign`], and the trigonometric factors are exact at the integers.
+///
+/// ## Examples
+///
+/// ```rust
+/// use sophy::specials::zeta::{chi, zeta};
+///
+/// // ζ(-1) = χ(-1)·ζ(2) = -1/12
+/// assert!((chi(-1.0) * zeta(2.0) + 1.0 / 12.0).abs() < 1e-15);
+///
+/// // χ(1/2) = 1 on the critical line
+/// assert!((chi(0.5) - 1.0).abs() < 1e-15);
+/// ```
+///
+/// ## Panics
+///
+/// Panics at the poles s = 1, 3, 5, ..., where cos(πs/2) = 0.
+pub fn chi(s: f64) -> f64 {
+    require_not_odd_positive(s, "chi");
+
+    // sin(πs/2) and cos(πs/2), exact at the integers
+    let (sin, cos) = if s.fract() == 0.0 {
+        [(0.0, 1.0), (1.0, 0.0), (0.0, -1.0), (-1.0, 0.0)][s.rem_euclid(4.0) as usize]
+    } else {
+        (0.5 * PI * s.rem_euclid(4.0)).sin_cos()
+    };
+
+    if s >= 0.5 {
+        let (ln_gamma, sign) = lgamma_sign(s);
+        let magnitude = (s * (2.0 * PI).ln() - ln_gamma).exp();
+        sign * magnitude / (2.0 * cos)
+    } else {
+        let (ln_gamma, _) = lgamma_sign(1.0 - s);
+        let magnitude = (s * 2.0_f64.ln() + (s - 1.0) * PI.ln() + ln_gamma).exp();
+        magnitude * sin
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,14 +189,85 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Zeta function implementation requires s > 1")]
+    #[should_panic(expected = "zeta: argument must be greater than 1, got")]
     fn test_zeta_invalid_s() {
         zeta(1.0);
     }
 
     #[test]
-    #[should_panic(expected = "Zeta function implementation requires s > 1")]
+    #[should_panic(expected = "zeta: argument must be greater than 1, got")]
     fn test_zeta_negative_s() {
         zeta(-1.0);
     }
+
+    #[test]
+    fn test_zeta_with_progress_steps() {
+        let mut steps = Vec::new();
+        let mut last = 0.0;
+        let value = zeta_with_progress(3.0, |n, partial| {
+            steps.push(n);
+            last = partial;
+        });
+
+        // One callback per term, with consecutive indices
+        assert!(steps.iter().enumerate().all(|(i, &n)| n == i + 1));
+        assert_eq!(last, value);
+        assert_eq!(value, zeta(3.0));
+
+        // Terms are summed while 1/n³ ≥ 1e-15, i.e. for n ≤ 10⁵
+        assert_eq!(steps.len(), 100_000);
+    }
+
+    #[test]
+    fn test_zeta_with_progress_exact_values_skip_iteration() {
+        let mut calls = 0;
+        zeta_with_progress(2.0, |_, _| calls += 1);
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn test_chi_known_values() {
+        assert!((chi(0.5) - 1.0).abs() < 1e-15);
+        assert!((chi(-1.0) + 1.0 / (2.0 * PI * PI)).abs() < 1e-15);
+        assert!((chi(2.0) + 2.0 * PI * PI).abs() < 1e-13);
+        assert_eq!(chi(0.0), 0.0);
+        assert_eq!(chi(-2.0), 0.0);
+
+        // Computed with mpmath at 30 digits
+        for (s, expected) in [
+            (0.3, 0.325_569_759_225_556_14),
+            (-2.5, 0.007_558_953_382_781_131),
+            (4.5, 237.492_640_997_186_92),
+        ] {
+            assert!((chi(s) - expected).abs() < 1e-14 * expected);
+        }
+    }
+
+    #[test]
+    fn test_chi_reflection() {
+        for s in [-3.7, -0.4, 0.1, 0.5, 0.9, 2.0, 2.6, 6.3] {
+            assert!((chi(s) * chi(1.0 - s) - 1.0).abs() < 1e-13, "s = {s}");
+        }
+    }
+
+    #[test]
+    fn test_chi_functional_equation() {
+        // ζ at non-positive integers from ζ(s) for s > 1
+        assert!((chi(-1.0) * zeta(2.0) + 1.0 / 12.0).abs() < 1e-15);
+        assert!((chi(-3.0) * zeta(4.0) - 1.0 / 120.0).abs() < 1e-15);
+
+        // On the critical strip ζ(s) = η(s)/(1 - 2¹⁻ˢ), which both sides can use
+        let zeta_strip = |s: f64| crate::specials::eta(s) / (1.0 - 2.0_f64.powf(1.0 - s));
+        for s in [0.2, 0.35, 0.6, 0.85] {
+            let lhs = zeta_strip(s);
+            let rhs = chi(s) * zeta_strip(1.0 - s);
+            assert!((lhs - rhs).abs() < 1e-13 * lhs.abs(), "s = {s}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "chi: argument must not be an odd positive integer, got 3")]
+    fn test_chi_pole() {
+        chi(3.0);
+    }
 }